@@ -0,0 +1,158 @@
+// Times the boundary functions over every day of a few decades, without a benchmarking
+// framework so it builds with the crate's existing dependencies. Run with `cargo bench`.
+
+use chrono::prelude::*;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ROUNDS: u32 = 20;
+
+fn main() {
+    let dates: Vec<NaiveDate> = NaiveDate::from_ymd_opt(2000, 1, 1)
+        .unwrap()
+        .iter_days()
+        .take(365 * 40)
+        .collect();
+
+    bench("beginning_of_week", &dates, |date| {
+        date_calculations::beginning_of_week(date)
+    });
+    bench(
+        "beginning_of_week via ISO weeks",
+        &dates,
+        iso_beginning_of_week,
+    );
+
+    bench("next_month", &dates, date_calculations::next_month);
+    bench("next_month via with_month", &dates, chained_next_month);
+    bench("previous_month", &dates, date_calculations::previous_month);
+    bench(
+        "previous_month via with_month",
+        &dates,
+        chained_previous_month,
+    );
+    bench("next_quarter", &dates, date_calculations::next_quarter);
+    bench("next_quarter via with_month", &dates, chained_next_quarter);
+    bench(
+        "previous_quarter",
+        &dates,
+        date_calculations::previous_quarter,
+    );
+    bench(
+        "previous_quarter via with_month",
+        &dates,
+        chained_previous_quarter,
+    );
+
+    let start = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    report("50 years of month starts via starts", 600, || {
+        for month in date_calculations::Period::Month
+            .starts(black_box(&start))
+            .take(600)
+        {
+            black_box(month);
+        }
+    });
+    report("50 years of month starts via next_month", 600, || {
+        let mut month = Some(*black_box(&start));
+        for _ in 0..600 {
+            month = month.and_then(|m| date_calculations::next_month(&m));
+            black_box(month);
+        }
+    });
+
+    bench("end_of_month", &dates, date_calculations::end_of_month);
+    bench_batch("end_of_month over a batch", &dates, |dates| {
+        dates
+            .iter()
+            .map(date_calculations::end_of_month)
+            .collect::<Vec<_>>()
+    });
+    bench_batch(
+        "end_of_month_batch",
+        &dates,
+        date_calculations::batch::end_of_month_batch,
+    );
+    bench_batch("previous_quarter over a batch", &dates, |dates| {
+        dates
+            .iter()
+            .map(date_calculations::previous_quarter)
+            .collect::<Option<Vec<_>>>()
+    });
+    bench_batch(
+        "previous_quarter_batch",
+        &dates,
+        date_calculations::batch::previous_quarter_batch,
+    );
+}
+
+// The former implementations, kept for comparison
+fn iso_beginning_of_week(date: &NaiveDate) -> Option<NaiveDate> {
+    if date.weekday() == Weekday::Sun {
+        Some(*date)
+    } else {
+        NaiveDate::from_isoywd_opt(date.iso_week().year(), date.iso_week().week(), Weekday::Sun)
+            .map(|d| d - chrono::Duration::weeks(1))
+    }
+}
+
+fn chained_next_month(date: &NaiveDate) -> Option<NaiveDate> {
+    if date.month() == 12 {
+        date.with_day(1)?.with_month(1)?.with_year(date.year() + 1)
+    } else {
+        date.with_day(1)?.with_month(date.month() + 1)
+    }
+}
+
+fn chained_previous_month(date: &NaiveDate) -> Option<NaiveDate> {
+    if date.month() == 1 {
+        date.with_day(1)?.with_month(12)?.with_year(date.year() - 1)
+    } else {
+        date.with_day(1)?.with_month(date.month() - 1)
+    }
+}
+
+fn chained_next_quarter(date: &NaiveDate) -> Option<NaiveDate> {
+    if date.month() >= 10 {
+        date.with_day(1)?.with_month(1)?.with_year(date.year() + 1)
+    } else {
+        date.with_day(1)?
+            .with_month(1 + 3 * (date.month0() / 3) + 3)
+    }
+}
+
+fn chained_previous_quarter(date: &NaiveDate) -> Option<NaiveDate> {
+    if date.month() < 4 {
+        date.with_day(1)?.with_year(date.year() - 1)?.with_month(10)
+    } else {
+        date.with_day(1)?
+            .with_month(1 + 3 * (date.month0() / 3) - 3)
+    }
+}
+
+fn bench_batch<T>(name: &str, dates: &[NaiveDate], f: impl Fn(&[NaiveDate]) -> T) {
+    report(name, dates.len(), || {
+        black_box(f(black_box(dates)));
+    });
+}
+
+fn bench<T>(name: &str, dates: &[NaiveDate], f: impl Fn(&NaiveDate) -> T) {
+    report(name, dates.len(), || {
+        for date in dates {
+            black_box(f(black_box(date)));
+        }
+    });
+}
+
+fn report(name: &str, calls: usize, round: impl Fn()) {
+    let mut elapsed = Duration::default();
+
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        round();
+        elapsed += start.elapsed();
+    }
+
+    let per_call = elapsed.as_nanos() as f64 / f64::from(ROUNDS) / calls as f64;
+    println!("{:<40} {:>8.2} ns/call", name, per_call);
+}
@@ -0,0 +1,264 @@
+//! Academic calendars built from explicit term dates.
+//!
+//! Institutions set their semesters, trimesters, or quarters by decree rather than by rule, so an
+//! [`AcademicCalendar`] is simply a list of named terms; dates between terms fall in a break and
+//! belong to no term.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::academic::{AcademicCalendar, Term};
+//!
+//! let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+//! let calendar = AcademicCalendar::new(vec![
+//!     Term::new("Fall 2024", ymd(2024, 8, 26), ymd(2024, 12, 13)).unwrap(),
+//!     Term::new("Spring 2025", ymd(2025, 1, 13), ymd(2025, 5, 2)).unwrap(),
+//! ])
+//! .unwrap();
+//!
+//! let date = ymd(2024, 9, 18);
+//! assert_eq!(calendar.current_term(&date).unwrap().name(), "Fall 2024");
+//! assert_eq!(calendar.week_of_term(&date), Some(4));
+//! assert_eq!(calendar.next_term(&date).unwrap().start(), ymd(2025, 1, 13));
+//! ```
+//!
+//! [`meeting_dates`] lists the days a class meets within a term, skipping holidays and breaks.
+
+use crate::business::HolidayCalendar;
+use crate::{beginning_of_week, DateRange};
+use alloc::{string::String, vec::Vec};
+use chrono::prelude::*;
+
+/// A named term running from its first through its last day of instruction.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Term {
+    name: String,
+    range: DateRange,
+}
+
+impl Term {
+    /// Builds a term, returning `None` if `end` precedes `start`.
+    pub fn new(name: impl Into<String>, start: NaiveDate, end: NaiveDate) -> Option<Self> {
+        Some(Term {
+            name: name.into(),
+            range: DateRange::new(start, end)?,
+        })
+    }
+
+    /// Returns the term's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the first day of the term.
+    pub fn start(&self) -> NaiveDate {
+        self.range.start()
+    }
+
+    /// Returns the last day of the term.
+    pub fn end(&self) -> NaiveDate {
+        self.range.end()
+    }
+
+    /// Returns the range of dates within the term.
+    pub fn range(&self) -> DateRange {
+        self.range
+    }
+}
+
+/// A sequence of non-overlapping terms.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AcademicCalendar {
+    terms: Vec<Term>,
+}
+
+impl AcademicCalendar {
+    /// Builds a calendar from terms in any order, returning `None` if any two terms overlap.
+    pub fn new(mut terms: Vec<Term>) -> Option<Self> {
+        terms.sort_by_key(|term| term.start());
+
+        if terms.windows(2).all(|pair| pair[0].end() < pair[1].start()) {
+            Some(AcademicCalendar { terms })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the terms in chronological order.
+    pub fn terms(&self) -> &[Term] {
+        &self.terms
+    }
+
+    /// Returns the term containing the provided date, or `None` during a break.
+    pub fn current_term(&self, date: &NaiveDate) -> Option<&Term> {
+        self.terms.iter().find(|term| term.range.contains(date))
+    }
+
+    /// Returns the first term beginning after the provided date.
+    pub fn next_term(&self, date: &NaiveDate) -> Option<&Term> {
+        self.terms.iter().find(|term| term.start() > *date)
+    }
+
+    /// Returns the last term ending before the provided date.
+    pub fn previous_term(&self, date: &NaiveDate) -> Option<&Term> {
+        self.terms.iter().rev().find(|term| term.end() < *date)
+    }
+
+    /// Returns the first day of the term containing the provided date.
+    pub fn beginning_of_term(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        Some(self.current_term(date)?.start())
+    }
+
+    /// Returns the last day of the term containing the provided date.
+    pub fn end_of_term(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        Some(self.current_term(date)?.end())
+    }
+
+    /// Returns the week of the term containing the provided date, counting the week in which the
+    /// term begins as week 1.
+    ///
+    /// Weeks begin on Sunday, matching [`crate::beginning_of_week`], so a term starting midweek
+    /// has a short first week.
+    pub fn week_of_term(&self, date: &NaiveDate) -> Option<u32> {
+        let first_week = beginning_of_week(&self.current_term(date)?.start())?;
+        let days = date.signed_duration_since(first_week).num_days();
+
+        Some(1 + (days / 7) as u32)
+    }
+}
+
+/// Returns the dates within the term a class meets, falling on one of the meeting weekdays and
+/// not on a holiday of the calendar.
+///
+/// Only the calendar's holidays are skipped, not its weekend, so a class may meet on Saturdays.
+/// Name breaks like a reading week as holidays of the calendar.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::academic::meeting_dates;
+/// use date_calculations::business::UsFederalHolidays;
+/// use date_calculations::DateRange;
+///
+/// let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// let term = DateRange::new(ymd(2024, 9, 1), ymd(2024, 9, 14)).unwrap();
+/// let meetings = meeting_dates(&term, &[Weekday::Mon, Weekday::Wed, Weekday::Fri], &UsFederalHolidays);
+///
+/// // Labor Day falls on Monday, September 2
+/// assert_eq!(meetings[0], ymd(2024, 9, 4));
+/// assert_eq!(meetings.len(), 5);
+/// ```
+pub fn meeting_dates<C: HolidayCalendar + ?Sized>(
+    term: &DateRange,
+    weekdays: &[Weekday],
+    calendar: &C,
+) -> Vec<NaiveDate> {
+    meetings(term, weekdays, calendar).collect()
+}
+
+/// Counts the days of instruction within the term, the dates [`meeting_dates`] returns, without
+/// collecting them.
+pub fn count_meetings<C: HolidayCalendar + ?Sized>(
+    term: &DateRange,
+    weekdays: &[Weekday],
+    calendar: &C,
+) -> usize {
+    meetings(term, weekdays, calendar).count()
+}
+
+fn meetings<'a, C: HolidayCalendar + ?Sized>(
+    term: &DateRange,
+    weekdays: &'a [Weekday],
+    calendar: &'a C,
+) -> impl Iterator<Item = NaiveDate> + 'a {
+    term.iter()
+        .filter(move |date| weekdays.contains(&date.weekday()) && !calendar.is_holiday(date))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn quarters() -> AcademicCalendar {
+        AcademicCalendar::new(vec![
+            Term::new("Winter", ymd(2025, 1, 6), ymd(2025, 3, 21)).unwrap(),
+            Term::new("Fall", ymd(2024, 9, 25), ymd(2024, 12, 13)).unwrap(),
+            Term::new("Spring", ymd(2025, 3, 31), ymd(2025, 6, 13)).unwrap(),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn finds_terms_and_breaks() {
+        let calendar = quarters();
+        let names: Vec<&str> = calendar.terms().iter().map(Term::name).collect();
+        assert_eq!(names, ["Fall", "Winter", "Spring"]);
+
+        let break_day = ymd(2024, 12, 25);
+        assert_eq!(calendar.current_term(&break_day), None);
+        assert_eq!(calendar.beginning_of_term(&break_day), None);
+        assert_eq!(calendar.next_term(&break_day).unwrap().name(), "Winter");
+        assert_eq!(calendar.previous_term(&break_day).unwrap().name(), "Fall");
+
+        let date = ymd(2025, 3, 21);
+        assert_eq!(calendar.beginning_of_term(&date), Some(ymd(2025, 1, 6)));
+        assert_eq!(calendar.end_of_term(&date), Some(date));
+        assert_eq!(calendar.next_term(&date).unwrap().name(), "Spring");
+        assert_eq!(calendar.next_term(&ymd(2025, 6, 1)), None);
+    }
+
+    #[test]
+    fn numbers_weeks_of_term() {
+        let calendar = quarters();
+
+        // Fall begins on a Wednesday
+        assert_eq!(calendar.week_of_term(&ymd(2024, 9, 25)), Some(1));
+        assert_eq!(calendar.week_of_term(&ymd(2024, 9, 28)), Some(1));
+        assert_eq!(calendar.week_of_term(&ymd(2024, 9, 29)), Some(2));
+        assert_eq!(calendar.week_of_term(&ymd(2024, 12, 13)), Some(12));
+        assert_eq!(calendar.week_of_term(&ymd(2024, 12, 20)), None);
+    }
+
+    #[test]
+    fn enumerates_meetings_around_breaks() {
+        struct ThanksgivingBreak;
+
+        impl HolidayCalendar for ThanksgivingBreak {
+            fn is_holiday(&self, date: &NaiveDate) -> bool {
+                DateRange::new(ymd(2024, 11, 25), ymd(2024, 11, 29))
+                    .unwrap()
+                    .contains(date)
+            }
+        }
+
+        let fall = quarters().terms()[0].range();
+        let tuesday_thursday = [Weekday::Tue, Weekday::Thu];
+        let meetings = meeting_dates(&fall, &tuesday_thursday, &ThanksgivingBreak);
+
+        assert_eq!(meetings.first(), Some(&ymd(2024, 9, 26)));
+        assert_eq!(meetings.last(), Some(&ymd(2024, 12, 12)));
+        assert!(!meetings.contains(&ymd(2024, 11, 26)));
+        assert_eq!(meetings.len(), 21);
+        assert_eq!(
+            count_meetings(&fall, &tuesday_thursday, &ThanksgivingBreak),
+            21
+        );
+        assert_eq!(count_meetings(&fall, &[], &ThanksgivingBreak), 0);
+    }
+
+    #[test]
+    fn rejects_overlapping_terms() {
+        let terms = vec![
+            Term::new("Fall", ymd(2024, 9, 1), ymd(2024, 12, 20)).unwrap(),
+            Term::new("Winter", ymd(2024, 12, 20), ymd(2025, 3, 21)).unwrap(),
+        ];
+
+        assert_eq!(AcademicCalendar::new(terms), None);
+        assert_eq!(
+            Term::new("Backwards", ymd(2024, 9, 1), ymd(2024, 8, 1)),
+            None
+        );
+    }
+}
@@ -0,0 +1,152 @@
+//! Ages in whole years, months, or days, counting only birthdays that have occurred.
+//!
+//! Someone born on February 29 has no birthday in common years, and jurisdictions disagree on
+//! whether they turn a year older on February 28 or March 1. The functions make that choice
+//! explicit with a [`MonthOverflow`]: [`Clamp`](MonthOverflow::Clamp) observes the birthday on
+//! February 28 and [`Spill`](MonthOverflow::Spill) on March 1. Monthly anniversaries of the 29th
+//! through 31st follow the same rule in shorter months.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::age::*;
+//! use date_calculations::MonthOverflow;
+//!
+//! let birth = NaiveDate::from_ymd_opt(2004, 2, 29).unwrap();
+//! let february_28 = NaiveDate::from_ymd_opt(2025, 2, 28).unwrap();
+//!
+//! assert_eq!(age_in_years(&birth, &february_28, MonthOverflow::Clamp), Some(21));
+//! assert_eq!(age_in_years(&birth, &february_28, MonthOverflow::Spill), Some(20));
+//! assert_eq!(age_in_months(&birth, &february_28, MonthOverflow::Spill), Some(251));
+//! assert_eq!(age_in_days(&birth, &february_28), Some(7670));
+//! ```
+
+use crate::duration::shift_months;
+use crate::MonthOverflow;
+use chrono::prelude::*;
+use core::convert::TryFrom;
+
+/// Returns the number of birthdays from `birth` that have occurred by `on`.
+///
+/// Returns `None` if `on` precedes `birth`.
+pub fn age_in_years(birth: &NaiveDate, on: &NaiveDate, leap_day: MonthOverflow) -> Option<u32> {
+    Some(age_in_months(birth, on, leap_day)? / 12)
+}
+
+/// Returns the number of monthly anniversaries of `birth` that have occurred by `on`.
+///
+/// Returns `None` if `on` precedes `birth`.
+pub fn age_in_months(birth: &NaiveDate, on: &NaiveDate, overflow: MonthOverflow) -> Option<u32> {
+    if on < birth {
+        return None;
+    }
+
+    let mut months = (i64::from(on.year()) - i64::from(birth.year())) * 12 + i64::from(on.month())
+        - i64::from(birth.month());
+
+    // a spilled anniversary can land past `on`, even from the month before
+    while !matches!(shift_months(birth, months, overflow), Some(anniversary) if anniversary <= *on)
+    {
+        months -= 1;
+    }
+
+    u32::try_from(months).ok()
+}
+
+/// Returns the number of days from `birth` to `on`.
+///
+/// Returns `None` if `on` precedes `birth`.
+pub fn age_in_days(birth: &NaiveDate, on: &NaiveDate) -> Option<u32> {
+    u32::try_from(on.signed_duration_since(*birth).num_days()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn counts_birthdays_that_have_occurred() {
+        let birth = ymd(1990, 6, 15);
+
+        assert_eq!(
+            age_in_years(&birth, &ymd(2024, 6, 14), MonthOverflow::Clamp),
+            Some(33)
+        );
+        assert_eq!(
+            age_in_years(&birth, &ymd(2024, 6, 15), MonthOverflow::Clamp),
+            Some(34)
+        );
+        assert_eq!(age_in_years(&birth, &birth, MonthOverflow::Clamp), Some(0));
+        assert_eq!(
+            age_in_years(&birth, &ymd(1990, 6, 14), MonthOverflow::Clamp),
+            None
+        );
+        assert_eq!(age_in_days(&birth, &ymd(1990, 6, 14)), None);
+    }
+
+    #[test]
+    fn observes_leap_day_birthdays_by_policy() {
+        let birth = ymd(2000, 2, 29);
+
+        assert_eq!(
+            age_in_years(&birth, &ymd(2001, 2, 28), MonthOverflow::Clamp),
+            Some(1)
+        );
+        assert_eq!(
+            age_in_years(&birth, &ymd(2001, 2, 28), MonthOverflow::Spill),
+            Some(0)
+        );
+        assert_eq!(
+            age_in_years(&birth, &ymd(2001, 3, 1), MonthOverflow::Spill),
+            Some(1)
+        );
+        assert_eq!(
+            age_in_years(&birth, &ymd(2004, 2, 28), MonthOverflow::Clamp),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn counts_monthly_anniversaries_at_month_ends() {
+        let birth = ymd(2023, 1, 31);
+
+        assert_eq!(
+            age_in_months(&birth, &ymd(2023, 2, 28), MonthOverflow::Clamp),
+            Some(1)
+        );
+        assert_eq!(
+            age_in_months(&birth, &ymd(2023, 2, 28), MonthOverflow::Spill),
+            Some(0)
+        );
+        assert_eq!(
+            age_in_months(&birth, &ymd(2023, 3, 2), MonthOverflow::Spill),
+            Some(0)
+        );
+        assert_eq!(
+            age_in_months(&birth, &ymd(2023, 3, 3), MonthOverflow::Spill),
+            Some(1)
+        );
+    }
+
+    #[quickcheck]
+    fn matches_counting_anniversaries(birth: ArbitraryDate, span: u16) -> bool {
+        let birth = birth.0;
+        let on = birth + chrono::Duration::days(i64::from(span));
+
+        [MonthOverflow::Clamp, MonthOverflow::Spill]
+            .iter()
+            .all(|&overflow| {
+                let counted = (1..)
+                    .take_while(|&months| shift_months(&birth, months, overflow).unwrap() <= on)
+                    .count() as u32;
+
+                age_in_months(&birth, &on, overflow) == Some(counted)
+                    && age_in_years(&birth, &on, overflow) == Some(counted / 12)
+            })
+    }
+}
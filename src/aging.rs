@@ -0,0 +1,199 @@
+//! Accounts-receivable aging: sorting amounts by how many days past due they are.
+//!
+//! [`aging_bucket`] uses the customary buckets (current, 1–30, 31–60, 61–90, and over 90 days
+//! past due), and [`AgingBuckets`] takes other edges.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::aging::{aging_bucket, AgingBuckets, Bucket};
+//!
+//! let due = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+//! let as_of = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
+//!
+//! assert_eq!(aging_bucket(&due, &as_of), Bucket::PastDue { from: 31, through: Some(60) });
+//! assert_eq!(aging_bucket(&due, &as_of).to_string(), "31-60");
+//!
+//! let weekly = AgingBuckets::new(&[7, 14]).unwrap();
+//! assert_eq!(weekly.bucket(&due, &as_of), Bucket::PastDue { from: 15, through: None });
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use chrono::prelude::*;
+use core::fmt;
+
+/// How far past due an amount is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Bucket {
+    /// Not yet past due, including amounts due on the as-of date.
+    Current,
+    /// Past due by `from` through `through` days, or by at least `from` days in the last,
+    /// open-ended bucket.
+    PastDue {
+        /// The fewest days past due in the bucket.
+        from: u32,
+        /// The most days past due in the bucket, or `None` for the last bucket.
+        through: Option<u32>,
+    },
+}
+
+/// Formats buckets as `current`, `1-30`, or `91+`.
+impl fmt::Display for Bucket {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Bucket::Current => write!(f, "current"),
+            Bucket::PastDue {
+                from,
+                through: Some(through),
+            } => write!(f, "{}-{}", from, through),
+            Bucket::PastDue {
+                from,
+                through: None,
+            } => write!(f, "{}+", from),
+        }
+    }
+}
+
+/// Aging buckets split at the last day past due of each closed bucket.
+///
+/// The edges `[30, 60, 90]` give the buckets 1–30, 31–60, 61–90, and 91 and over.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AgingBuckets<'a> {
+    edges: &'a [u32],
+}
+
+impl<'a> AgingBuckets<'a> {
+    /// The customary buckets: 1–30, 31–60, 61–90, and over 90 days past due.
+    pub const STANDARD: AgingBuckets<'static> = AgingBuckets {
+        edges: &[30, 60, 90],
+    };
+
+    /// Builds buckets from their edges, returning `None` unless the edges are positive and
+    /// strictly increasing.
+    pub fn new(edges: &'a [u32]) -> Option<Self> {
+        let increasing = edges.windows(2).all(|pair| pair[0] < pair[1]);
+
+        if increasing && !matches!(edges.first(), Some(0)) {
+            Some(AgingBuckets { edges })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the edges of the buckets.
+    pub fn edges(&self) -> &'a [u32] {
+        self.edges
+    }
+
+    /// Returns the bucket of an amount due on `due`, as of `as_of`.
+    pub fn bucket(&self, due: &NaiveDate, as_of: &NaiveDate) -> Bucket {
+        let days = as_of.signed_duration_since(*due).num_days();
+        if days <= 0 {
+            return Bucket::Current;
+        }
+
+        let index = self
+            .edges
+            .iter()
+            .position(|edge| days <= i64::from(*edge))
+            .unwrap_or(self.edges.len());
+        let from = match index {
+            0 => 1,
+            _ => self.edges[index - 1] + 1,
+        };
+
+        Bucket::PastDue {
+            from,
+            through: self.edges.get(index).copied(),
+        }
+    }
+
+    /// Returns the bucket of each due date, as of `as_of`.
+    #[cfg(feature = "alloc")]
+    pub fn bucket_batch(&self, due_dates: &[NaiveDate], as_of: &NaiveDate) -> Vec<Bucket> {
+        due_dates
+            .iter()
+            .map(|due| self.bucket(due, as_of))
+            .collect()
+    }
+}
+
+impl Default for AgingBuckets<'static> {
+    fn default() -> Self {
+        AgingBuckets::STANDARD
+    }
+}
+
+/// Returns the [standard](AgingBuckets::STANDARD) bucket of an amount due on `due`, as of
+/// `as_of`.
+pub fn aging_bucket(due: &NaiveDate, as_of: &NaiveDate) -> Bucket {
+    AgingBuckets::STANDARD.bucket(due, as_of)
+}
+
+/// Returns the [standard](AgingBuckets::STANDARD) bucket of each due date, as of `as_of`.
+#[cfg(feature = "alloc")]
+pub fn aging_bucket_batch(due_dates: &[NaiveDate], as_of: &NaiveDate) -> Vec<Bucket> {
+    AgingBuckets::STANDARD.bucket_batch(due_dates, as_of)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn buckets_by_days_past_due() {
+        let due = ymd(2024, 1, 1);
+        let bucket = |days| aging_bucket(&due, &(due + chrono::Duration::days(days))).to_string();
+
+        assert_eq!(bucket(-5), "current");
+        assert_eq!(bucket(0), "current");
+        assert_eq!(bucket(1), "1-30");
+        assert_eq!(bucket(30), "1-30");
+        assert_eq!(bucket(31), "31-60");
+        assert_eq!(bucket(90), "61-90");
+        assert_eq!(bucket(91), "91+");
+    }
+
+    #[test]
+    fn validates_edges() {
+        assert_eq!(AgingBuckets::new(&[30, 30]), None);
+        assert_eq!(AgingBuckets::new(&[0, 30]), None);
+        assert_eq!(AgingBuckets::new(&[60, 30]), None);
+
+        let single = AgingBuckets::new(&[]).unwrap();
+        assert_eq!(
+            single.bucket(&ymd(2024, 1, 1), &ymd(2024, 6, 1)),
+            Bucket::PastDue {
+                from: 1,
+                through: None
+            }
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn buckets_slices() {
+        let as_of = ymd(2024, 5, 15);
+        let dues = [ymd(2024, 6, 1), ymd(2024, 5, 1), ymd(2023, 12, 31)];
+
+        assert_eq!(
+            aging_bucket_batch(&dues, &as_of),
+            vec![
+                Bucket::Current,
+                Bucket::PastDue {
+                    from: 1,
+                    through: Some(30)
+                },
+                Bucket::PastDue {
+                    from: 91,
+                    through: None
+                },
+            ]
+        );
+        assert!(Bucket::Current < aging_bucket(&dues[1], &as_of));
+    }
+}
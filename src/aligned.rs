@@ -0,0 +1,240 @@
+use crate::*;
+use core::fmt;
+
+/// A date known to begin a period: a [`WeekStart`], [`MonthStart`], [`QuarterStart`], or
+/// [`YearStart`], for code generic over the kind of period, such as
+/// [`batch::histogram_by`](crate::batch::histogram_by).
+pub trait Aligned: Copy + Ord + Into<NaiveDate> {
+    /// Returns the beginning of the period containing the date, or `None` if it precedes
+    /// `NaiveDate::MIN`.
+    fn containing(date: &NaiveDate) -> Option<Self>;
+
+    /// Returns the beginning of the next period, or `None` if it follows `NaiveDate::MAX`.
+    fn next(self) -> Option<Self>;
+}
+
+macro_rules! aligned {
+    ($(#[$doc:meta])* $name:ident, $period:literal, $is_aligned:expr, $next:path, $previous:path) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(NaiveDate);
+
+        impl $name {
+            #[doc = concat!("Wraps the date, returning `None` unless it begins a ", $period, ".")]
+            pub fn new(date: NaiveDate) -> Option<Self> {
+                let is_aligned: fn(&NaiveDate) -> bool = $is_aligned;
+
+                if is_aligned(&date) {
+                    Some($name(date))
+                } else {
+                    None
+                }
+            }
+
+            #[doc = concat!("Returns the first day of the ", $period, ".")]
+            pub fn date(self) -> NaiveDate {
+                self.0
+            }
+
+            #[doc = concat!("Returns the beginning of the next ", $period, ".")]
+            pub fn next(self) -> Option<Self> {
+                $next(&self.0).map($name)
+            }
+
+            #[doc = concat!("Returns the beginning of the previous ", $period, ".")]
+            pub fn previous(self) -> Option<Self> {
+                $previous(&self.0).map($name)
+            }
+        }
+
+        impl Aligned for $name {
+            fn containing(date: &NaiveDate) -> Option<Self> {
+                Option::from($name::containing(date))
+            }
+
+            fn next(self) -> Option<Self> {
+                $name::next(self)
+            }
+        }
+
+        impl From<$name> for NaiveDate {
+            fn from(start: $name) -> Self {
+                start.0
+            }
+        }
+
+        /// Formats the first day of the period as `YYYY-MM-DD`.
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+aligned! {
+    /// The first day of a week, a Sunday.
+    ///
+    /// New values come only from dates already known to be aligned, so functions accepting a
+    /// `WeekStart` cannot be handed a date in the middle of a week:
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use date_calculations::WeekStart;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+    /// let week = WeekStart::containing(&date).unwrap();
+    ///
+    /// assert_eq!(week.date(), NaiveDate::from_ymd_opt(2024, 5, 12).unwrap());
+    /// assert_eq!(WeekStart::new(date), None);
+    /// ```
+    WeekStart, "week", |date| date.weekday() == Weekday::Sun, next_week, previous_week
+}
+
+aligned! {
+    /// The first day of a month.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use date_calculations::MonthStart;
+    ///
+    /// let month = MonthStart::containing(&NaiveDate::from_ymd_opt(2024, 1, 31).unwrap());
+    ///
+    /// assert_eq!(month.next().unwrap().to_string(), "2024-02-01");
+    /// assert_eq!(month.end(), NaiveDate::from_ymd_opt(2024, 1, 31).unwrap());
+    /// ```
+    MonthStart, "month", |date| date.day() == 1, next_month, previous_month
+}
+
+aligned! {
+    /// The first day of a calendar quarter: January 1, April 1, July 1, or October 1.
+    QuarterStart, "quarter", |date| date.day() == 1 && date.month0() % 3 == 0, next_quarter, previous_quarter
+}
+
+aligned! {
+    /// January 1 of a year.
+    YearStart, "year", |date| date.ordinal() == 1, next_year, previous_year
+}
+
+impl WeekStart {
+    /// Returns the beginning of the week containing the date, or `None` if it precedes
+    /// `NaiveDate::MIN`.
+    pub fn containing(date: &NaiveDate) -> Option<Self> {
+        beginning_of_week(date).map(WeekStart)
+    }
+
+    /// Returns the last day of the week, or `None` if it follows `NaiveDate::MAX`.
+    pub fn end(self) -> Option<NaiveDate> {
+        end_of_week(&self.0)
+    }
+
+    /// Returns the range of dates within the week, or `None` if it ends after `NaiveDate::MAX`.
+    pub fn range(self) -> Option<DateRange> {
+        DateRange::new(self.0, self.end()?)
+    }
+}
+
+impl MonthStart {
+    /// Returns the beginning of the month containing the date.
+    pub fn containing(date: &NaiveDate) -> Self {
+        MonthStart(beginning_of_month(date))
+    }
+
+    /// Returns the last day of the month.
+    pub fn end(self) -> NaiveDate {
+        end_of_month(&self.0)
+    }
+
+    /// Returns the range of dates within the month.
+    pub fn range(self) -> DateRange {
+        DateRange::new(self.0, self.end()).expect("periods end after they begin")
+    }
+
+    /// Returns the month as a [`YearMonth`].
+    pub fn year_month(self) -> YearMonth {
+        YearMonth::from_date(&self.0)
+    }
+}
+
+impl QuarterStart {
+    /// Returns the beginning of the quarter containing the date.
+    pub fn containing(date: &NaiveDate) -> Self {
+        QuarterStart(beginning_of_quarter(date))
+    }
+
+    /// Returns the last day of the quarter.
+    pub fn end(self) -> NaiveDate {
+        end_of_quarter(&self.0)
+    }
+
+    /// Returns the range of dates within the quarter.
+    pub fn range(self) -> DateRange {
+        DateRange::new(self.0, self.end()).expect("periods end after they begin")
+    }
+
+    /// Returns the quarter as a [`Quarter`].
+    pub fn quarter(self) -> Quarter {
+        Quarter::from_date(&self.0)
+    }
+}
+
+impl YearStart {
+    /// Returns January 1 of the year containing the date.
+    pub fn containing(date: &NaiveDate) -> Self {
+        YearStart(beginning_of_year(date))
+    }
+
+    /// Returns December 31 of the year.
+    pub fn end(self) -> NaiveDate {
+        end_of_year(&self.0)
+    }
+
+    /// Returns the range of dates within the year.
+    pub fn range(self) -> DateRange {
+        DateRange::new(self.0, self.end()).expect("periods end after they begin")
+    }
+
+    /// Returns the year.
+    pub fn year(self) -> i32 {
+        self.0.year()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    #[quickcheck]
+    fn containing_dates_are_aligned(date: ArbitraryDate) -> bool {
+        let date = date.0;
+        let month = MonthStart::containing(&date);
+        let quarter = QuarterStart::containing(&date);
+
+        WeekStart::containing(&date)
+            .and_then(|week| WeekStart::new(week.date()))
+            .is_some()
+            && MonthStart::new(month.date()) == Some(month)
+            && QuarterStart::new(quarter.date()) == Some(quarter)
+            && YearStart::new(YearStart::containing(&date).date()).is_some()
+            && month.range().contains(&date)
+            && quarter.quarter() == Quarter::from_date(&date)
+            && month.year_month() == YearMonth::from_date(&date)
+    }
+
+    #[test]
+    fn rejects_unaligned_dates() {
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+
+        assert_eq!(MonthStart::new(date(2024, 5, 2)), None);
+        assert_eq!(QuarterStart::new(date(2024, 5, 1)), None);
+        assert!(QuarterStart::new(date(2024, 4, 1)).is_some());
+        assert_eq!(YearStart::new(date(2024, 2, 1)), None);
+        assert_eq!(
+            YearStart::containing(&date(2024, 5, 17)).next(),
+            YearStart::new(date(2025, 1, 1))
+        );
+        assert_eq!(YearStart::containing(&NaiveDate::MAX).next(), None);
+    }
+}
@@ -0,0 +1,214 @@
+// Astronomical positions of the sun and moon shared by the calendar modules.
+//
+// Moments are Rata Die days in Universal Time, with fractions counting from midnight. Lunar
+// phases use Meeus's series and the sun a low-precision longitude, both accurate to a few
+// minutes for the years 1900 through 2100 and degrading slowly outside them.
+
+use core::f64::consts::PI;
+
+pub(crate) const MEAN_SYNODIC_MONTH: f64 = 29.530_588_861;
+const MEAN_TROPICAL_YEAR: f64 = 365.242_189;
+
+// The principal phases, as fractions of a lunation after the new moon
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LunarPhase {
+    New,
+    FirstQuarter,
+    Full,
+    LastQuarter,
+}
+
+pub(crate) fn new_moon_at_or_after(moment: f64) -> f64 {
+    lunar_phase_at_or_after(LunarPhase::New, moment)
+}
+
+pub(crate) fn new_moon_before_moment(moment: f64) -> f64 {
+    lunar_phase(
+        LunarPhase::New,
+        lunation_estimate(new_moon_at_or_after(moment)) - 1,
+    )
+}
+
+pub(crate) fn lunar_phase_at_or_after(phase: LunarPhase, moment: f64) -> f64 {
+    let mut k = lunation_estimate(moment);
+    while lunar_phase(phase, k) < moment {
+        k += 1;
+    }
+    while lunar_phase(phase, k - 1) >= moment {
+        k -= 1;
+    }
+    lunar_phase(phase, k)
+}
+
+fn lunation_estimate(moment: f64) -> i64 {
+    ((julian_day(moment) - 2_451_550.097_66) / MEAN_SYNODIC_MONTH).round() as i64
+}
+
+// Meeus, Astronomical Algorithms, chapter 49, omitting the planetary arguments
+fn lunar_phase(phase: LunarPhase, lunation: i64) -> f64 {
+    let k = lunation as f64
+        + match phase {
+            LunarPhase::New => 0.0,
+            LunarPhase::FirstQuarter => 0.25,
+            LunarPhase::Full => 0.5,
+            LunarPhase::LastQuarter => 0.75,
+        };
+    let t = k / 1236.85;
+    let e = 1.0 - 0.002_516 * t - 0.000_007_4 * t * t;
+
+    let jde = 2_451_550.097_66 + MEAN_SYNODIC_MONTH * k + 0.000_154_37 * t * t
+        - 0.000_000_150 * t.powi(3)
+        + 0.000_000_000_73 * t.powi(4);
+    let m = radians(2.5534 + 29.105_356_70 * k - 0.000_001_4 * t * t - 0.000_000_11 * t.powi(3));
+    let mp = radians(
+        201.5643 + 385.816_935_28 * k + 0.010_758_2 * t * t + 0.000_012_38 * t.powi(3)
+            - 0.000_000_058 * t.powi(4),
+    );
+    let f = radians(
+        160.7108 + 390.670_502_84 * k - 0.001_611_8 * t * t - 0.000_002_27 * t.powi(3)
+            + 0.000_000_011 * t.powi(4),
+    );
+    let omega =
+        radians(124.7746 - 1.563_755_88 * k + 0.002_067_2 * t * t + 0.000_002_15 * t.powi(3));
+
+    let correction = match phase {
+        LunarPhase::New => {
+            -0.40720 * mp.sin()
+                + 0.17241 * e * m.sin()
+                + 0.01608 * (2.0 * mp).sin()
+                + 0.01039 * (2.0 * f).sin()
+                + 0.00739 * e * (mp - m).sin()
+                - 0.00514 * e * (mp + m).sin()
+                + 0.00208 * e * e * (2.0 * m).sin()
+                - 0.00111 * (mp - 2.0 * f).sin()
+                - 0.00057 * (mp + 2.0 * f).sin()
+                + 0.00056 * e * (2.0 * mp + m).sin()
+                - 0.00042 * (3.0 * mp).sin()
+                + 0.00042 * e * (m + 2.0 * f).sin()
+                + 0.00038 * e * (m - 2.0 * f).sin()
+                - 0.00024 * e * (2.0 * mp - m).sin()
+                - 0.00017 * omega.sin()
+                - 0.00007 * (mp + 2.0 * m).sin()
+                + 0.00004 * (2.0 * mp - 2.0 * f).sin()
+                + 0.00004 * (3.0 * m).sin()
+                + 0.00003 * (mp + m - 2.0 * f).sin()
+                + 0.00003 * (2.0 * mp + 2.0 * f).sin()
+                - 0.00003 * (mp + m + 2.0 * f).sin()
+                + 0.00003 * (mp - m + 2.0 * f).sin()
+                - 0.00002 * (mp - m - 2.0 * f).sin()
+                - 0.00002 * (3.0 * mp + m).sin()
+                + 0.00002 * (4.0 * mp).sin()
+        }
+        LunarPhase::Full => {
+            -0.40614 * mp.sin()
+                + 0.17302 * e * m.sin()
+                + 0.01614 * (2.0 * mp).sin()
+                + 0.01043 * (2.0 * f).sin()
+                + 0.00734 * e * (mp - m).sin()
+                - 0.00515 * e * (mp + m).sin()
+                + 0.00209 * e * e * (2.0 * m).sin()
+                - 0.00111 * (mp - 2.0 * f).sin()
+                - 0.00057 * (mp + 2.0 * f).sin()
+                + 0.00056 * e * (2.0 * mp + m).sin()
+                - 0.00042 * (3.0 * mp).sin()
+                + 0.00042 * e * (m + 2.0 * f).sin()
+                + 0.00038 * e * (m - 2.0 * f).sin()
+                - 0.00024 * e * (2.0 * mp - m).sin()
+                - 0.00017 * omega.sin()
+                - 0.00007 * (mp + 2.0 * m).sin()
+                + 0.00004 * (2.0 * mp - 2.0 * f).sin()
+                + 0.00004 * (3.0 * m).sin()
+                + 0.00003 * (mp + m - 2.0 * f).sin()
+                + 0.00003 * (2.0 * mp + 2.0 * f).sin()
+                - 0.00003 * (mp + m + 2.0 * f).sin()
+                + 0.00003 * (mp - m + 2.0 * f).sin()
+                - 0.00002 * (mp - m - 2.0 * f).sin()
+                - 0.00002 * (3.0 * mp + m).sin()
+                + 0.00002 * (4.0 * mp).sin()
+        }
+        LunarPhase::FirstQuarter | LunarPhase::LastQuarter => {
+            let quarter = -0.62801 * mp.sin() + 0.17172 * e * m.sin()
+                - 0.01183 * e * (mp + m).sin()
+                + 0.00862 * (2.0 * mp).sin()
+                + 0.00804 * (2.0 * f).sin()
+                + 0.00454 * e * (mp - m).sin()
+                + 0.00204 * e * e * (2.0 * m).sin()
+                - 0.00180 * (mp - 2.0 * f).sin()
+                - 0.00070 * (mp + 2.0 * f).sin()
+                - 0.00040 * (3.0 * mp).sin()
+                - 0.00034 * e * (2.0 * mp - m).sin()
+                + 0.00032 * e * (m + 2.0 * f).sin()
+                + 0.00032 * e * (m - 2.0 * f).sin()
+                - 0.00028 * e * e * (mp + 2.0 * m).sin()
+                + 0.00027 * e * (2.0 * mp + m).sin()
+                - 0.00017 * omega.sin()
+                - 0.00005 * (mp - m - 2.0 * f).sin()
+                + 0.00004 * (2.0 * mp + 2.0 * f).sin()
+                - 0.00004 * (mp + m + 2.0 * f).sin()
+                + 0.00004 * (mp - 2.0 * m).sin()
+                + 0.00003 * (mp + m - 2.0 * f).sin()
+                + 0.00003 * (3.0 * m).sin()
+                + 0.00002 * (2.0 * mp - 2.0 * f).sin()
+                + 0.00002 * (mp - m + 2.0 * f).sin()
+                - 0.00002 * (3.0 * mp + m).sin();
+            let w = 0.00306 - 0.00038 * e * m.cos() + 0.00026 * mp.cos() - 0.00002 * (mp - m).cos()
+                + 0.00002 * (mp + m).cos()
+                + 0.00002 * (2.0 * f).cos();
+
+            if phase == LunarPhase::FirstQuarter {
+                quarter + w
+            } else {
+                quarter - w
+            }
+        }
+    };
+
+    let dynamical = jde + correction - 1_721_424.5;
+    dynamical - delta_t(dynamical)
+}
+
+// Meeus, Astronomical Algorithms, chapter 25, accurate to about 0.01 degrees
+pub(crate) fn solar_longitude(moment: f64) -> f64 {
+    let t = (julian_day(moment + delta_t(moment)) - 2_451_545.0) / 36525.0;
+    let mean_longitude = 280.466_46 + 36_000.769_83 * t + 0.000_303_2 * t * t;
+    let m = radians(357.529_11 + 35_999.050_29 * t - 0.000_153_7 * t * t);
+    let center = (1.914_602 - 0.004_817 * t - 0.000_014 * t * t) * m.sin()
+        + (0.019_993 - 0.000_101 * t) * (2.0 * m).sin()
+        + 0.000_289 * (3.0 * m).sin();
+    let omega = radians(125.04 - 1934.136 * t);
+
+    (mean_longitude + center - 0.005_69 - 0.004_78 * omega.sin()).rem_euclid(360.0)
+}
+
+// The first moment at or after `moment` when the sun reaches the longitude
+pub(crate) fn solar_longitude_after(longitude: f64, moment: f64) -> f64 {
+    let rate = MEAN_TROPICAL_YEAR / 360.0;
+    let estimate = moment + rate * (longitude - solar_longitude(moment)).rem_euclid(360.0);
+    let (mut low, mut high) = ((estimate - 5.0).max(moment), estimate + 5.0);
+
+    while high - low > 1e-5 {
+        let middle = (low + high) / 2.0;
+        if (solar_longitude(middle) - longitude).rem_euclid(360.0) < 180.0 {
+            high = middle;
+        } else {
+            low = middle;
+        }
+    }
+
+    (low + high) / 2.0
+}
+
+// Terrestrial minus Universal Time in days, from Morrison and Stephenson's parabola
+fn delta_t(moment: f64) -> f64 {
+    let year = 1.0 + moment / 365.2425;
+    let u = (year - 1820.0) / 100.0;
+    (-20.0 + 32.0 * u * u) / 86_400.0
+}
+
+fn julian_day(moment: f64) -> f64 {
+    moment + 1_721_424.5
+}
+
+fn radians(degrees: f64) -> f64 {
+    degrees.rem_euclid(360.0) * PI / 180.0
+}
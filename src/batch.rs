@@ -0,0 +1,337 @@
+//! Boundary calculations over slices of dates.
+//!
+//! Each boundary function at the crate root has a `_batch` variant here, returning a new vector,
+//! and an `_in_place` variant, overwriting the slice, sharing the boundary functions' day counts
+//! so a slice of millions of dates is shifted without a call per date through the crate root.
+//!
+//! Boundaries within a date's own year are always representable. The others return `None` if any
+//! boundary falls outside the dates chrono can represent.
+//!
+//! [`histogram`] counts the dates in each period, for plotting how often events occur, and
+//! [`histogram_by`] keys the counts by a typed period start such as [`MonthStart`].
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::batch;
+//!
+//! let mut dates = vec![
+//!     NaiveDate::from_ymd_opt(2024, 2, 12).unwrap(),
+//!     NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(),
+//! ];
+//!
+//! assert_eq!(
+//!     batch::end_of_month_batch(&dates),
+//!     vec![
+//!         NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+//!         NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+//!     ]
+//! );
+//!
+//! batch::next_quarter_in_place(&mut dates).unwrap();
+//! assert_eq!(dates[1], NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+//! ```
+
+use crate::offset::{self, shift, shift_within_year};
+use crate::{Aligned, MonthStart, Period, QuarterStart, WeekStart, YearStart};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use chrono::prelude::*;
+
+macro_rules! batch {
+    ($($boundary:ident => $batch:ident, $in_place:ident;)*) => {
+        $(
+            #[doc = concat!("Returns [`", stringify!($boundary), "`](crate::", stringify!($boundary), ") of each date.")]
+            pub fn $batch(dates: &[NaiveDate]) -> Option<Vec<NaiveDate>> {
+                dates.iter().map(|date| shift(date, offset::$boundary(date))).collect()
+            }
+
+            #[doc = concat!("Replaces each date with its [`", stringify!($boundary), "`](crate::", stringify!($boundary), ").")]
+            ///
+            /// If a boundary cannot be represented this returns `None`, leaving the dates before it
+            /// already replaced.
+            pub fn $in_place(dates: &mut [NaiveDate]) -> Option<()> {
+                for date in dates.iter_mut() {
+                    *date = shift(date, offset::$boundary(date))?;
+                }
+                Some(())
+            }
+        )*
+    };
+}
+
+// Boundaries within the date's own year, which are always representable
+macro_rules! infallible_batch {
+    ($($boundary:ident => $batch:ident, $in_place:ident;)*) => {
+        $(
+            #[doc = concat!("Returns [`", stringify!($boundary), "`](crate::", stringify!($boundary), ") of each date.")]
+            pub fn $batch(dates: &[NaiveDate]) -> Vec<NaiveDate> {
+                dates.iter().map(|date| shift_within_year(date, offset::$boundary(date))).collect()
+            }
+
+            #[doc = concat!("Replaces each date with its [`", stringify!($boundary), "`](crate::", stringify!($boundary), ").")]
+            pub fn $in_place(dates: &mut [NaiveDate]) {
+                for date in dates.iter_mut() {
+                    *date = shift_within_year(date, offset::$boundary(date));
+                }
+            }
+        )*
+    };
+}
+
+batch! {
+    beginning_of_week => beginning_of_week_batch, beginning_of_week_in_place;
+    end_of_week => end_of_week_batch, end_of_week_in_place;
+    next_week => next_week_batch, next_week_in_place;
+    previous_week => previous_week_batch, previous_week_in_place;
+    next_month => next_month_batch, next_month_in_place;
+    previous_month => previous_month_batch, previous_month_in_place;
+    next_quarter => next_quarter_batch, next_quarter_in_place;
+    previous_quarter => previous_quarter_batch, previous_quarter_in_place;
+    next_year => next_year_batch, next_year_in_place;
+    previous_year => previous_year_batch, previous_year_in_place;
+}
+
+infallible_batch! {
+    beginning_of_month => beginning_of_month_batch, beginning_of_month_in_place;
+    end_of_month => end_of_month_batch, end_of_month_in_place;
+    beginning_of_quarter => beginning_of_quarter_batch, beginning_of_quarter_in_place;
+    end_of_quarter => end_of_quarter_batch, end_of_quarter_in_place;
+    beginning_of_year => beginning_of_year_batch, beginning_of_year_in_place;
+    end_of_year => end_of_year_batch, end_of_year_in_place;
+}
+
+/// Counts the dates in each period, keyed by the beginning of the period.
+///
+/// This is [`histogram_by`] with a runtime choice of period, unwrapping the keys into dates. Every
+/// period from the earliest date's through the latest date's has an entry, with periods
+/// containing none of the dates counted as zero, so the counts can be plotted without gaps.
+/// Returns `None` if a date's week begins before `NaiveDate::MIN`.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::{batch, Period};
+///
+/// let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// let counts = batch::histogram(&[ymd(2024, 3, 9), ymd(2024, 1, 2), ymd(2024, 1, 31)], Period::Month)
+///     .unwrap();
+///
+/// assert_eq!(
+///     counts.into_iter().collect::<Vec<_>>(),
+///     vec![(ymd(2024, 1, 1), 2), (ymd(2024, 2, 1), 0), (ymd(2024, 3, 1), 1)]
+/// );
+/// ```
+pub fn histogram(dates: &[NaiveDate], period: Period) -> Option<BTreeMap<NaiveDate, u64>> {
+    fn dated<S: Aligned>(counts: BTreeMap<S, u64>) -> BTreeMap<NaiveDate, u64> {
+        counts
+            .into_iter()
+            .map(|(start, count)| (start.into(), count))
+            .collect()
+    }
+
+    match period {
+        Period::Week => histogram_by::<WeekStart>(dates).map(dated),
+        Period::Month => histogram_by::<MonthStart>(dates).map(dated),
+        Period::Quarter => histogram_by::<QuarterStart>(dates).map(dated),
+        Period::Year => histogram_by::<YearStart>(dates).map(dated),
+    }
+}
+
+/// Counts the dates in each period of the kind `S`, keyed by the period's aligned beginning.
+///
+/// Every period from the earliest date's through the latest date's has an entry, with periods
+/// containing none of the dates counted as zero. Returns `None` if a date's period begins before
+/// `NaiveDate::MIN`.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::{batch, QuarterStart};
+///
+/// let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// let counts = batch::histogram_by::<QuarterStart>(&[ymd(2024, 11, 5), ymd(2024, 5, 17)]).unwrap();
+///
+/// let totals: Vec<_> = counts.iter().map(|(q, count)| (q.quarter().to_string(), *count)).collect();
+/// assert_eq!(
+///     totals,
+///     vec![("2024-Q2".to_string(), 1), ("2024-Q3".to_string(), 0), ("2024-Q4".to_string(), 1)]
+/// );
+/// ```
+pub fn histogram_by<S: Aligned>(dates: &[NaiveDate]) -> Option<BTreeMap<S, u64>> {
+    let mut counts = BTreeMap::new();
+    let (first, last) = match (dates.iter().min(), dates.iter().max()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return Some(counts),
+    };
+
+    let last = S::containing(last)?;
+    let starts = core::iter::successors(Some(S::containing(first)?), |start| start.next());
+    for start in starts.take_while(|start| *start <= last) {
+        counts.insert(start, 0);
+    }
+    for date in dates {
+        *counts.entry(S::containing(date)?).or_insert(0) += 1;
+    }
+
+    Some(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    type Boundary = fn(&NaiveDate) -> Option<NaiveDate>;
+    type Batch = fn(&[NaiveDate]) -> Option<Vec<NaiveDate>>;
+    type InPlace = fn(&mut [NaiveDate]) -> Option<()>;
+
+    type InfallibleBoundary = fn(&NaiveDate) -> NaiveDate;
+    type InfallibleBatch = fn(&[NaiveDate]) -> Vec<NaiveDate>;
+    type InfallibleInPlace = fn(&mut [NaiveDate]);
+
+    const OPERATIONS: [(Boundary, Batch, InPlace); 10] = [
+        (
+            crate::beginning_of_week,
+            beginning_of_week_batch,
+            beginning_of_week_in_place,
+        ),
+        (crate::end_of_week, end_of_week_batch, end_of_week_in_place),
+        (crate::next_week, next_week_batch, next_week_in_place),
+        (
+            crate::previous_week,
+            previous_week_batch,
+            previous_week_in_place,
+        ),
+        (crate::next_month, next_month_batch, next_month_in_place),
+        (
+            crate::previous_month,
+            previous_month_batch,
+            previous_month_in_place,
+        ),
+        (
+            crate::next_quarter,
+            next_quarter_batch,
+            next_quarter_in_place,
+        ),
+        (
+            crate::previous_quarter,
+            previous_quarter_batch,
+            previous_quarter_in_place,
+        ),
+        (crate::next_year, next_year_batch, next_year_in_place),
+        (
+            crate::previous_year,
+            previous_year_batch,
+            previous_year_in_place,
+        ),
+    ];
+
+    const INFALLIBLE_OPERATIONS: [(InfallibleBoundary, InfallibleBatch, InfallibleInPlace); 6] = [
+        (
+            crate::beginning_of_month,
+            beginning_of_month_batch,
+            beginning_of_month_in_place,
+        ),
+        (
+            crate::end_of_month,
+            end_of_month_batch,
+            end_of_month_in_place,
+        ),
+        (
+            crate::beginning_of_quarter,
+            beginning_of_quarter_batch,
+            beginning_of_quarter_in_place,
+        ),
+        (
+            crate::end_of_quarter,
+            end_of_quarter_batch,
+            end_of_quarter_in_place,
+        ),
+        (
+            crate::beginning_of_year,
+            beginning_of_year_batch,
+            beginning_of_year_in_place,
+        ),
+        (crate::end_of_year, end_of_year_batch, end_of_year_in_place),
+    ];
+
+    #[test]
+    fn histograms_fill_empty_periods() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let dates = [ymd(2024, 5, 17), ymd(2024, 4, 28), ymd(2024, 5, 4)];
+        let weekly = histogram(&dates, Period::Week).unwrap();
+
+        assert_eq!(
+            weekly.into_iter().collect::<Vec<_>>(),
+            vec![
+                (ymd(2024, 4, 28), 2),
+                (ymd(2024, 5, 5), 0),
+                (ymd(2024, 5, 12), 1)
+            ]
+        );
+        assert_eq!(histogram(&[], Period::Year), Some(BTreeMap::new()));
+        assert_eq!(histogram(&[NaiveDate::MIN], Period::Week), None);
+        assert_eq!(
+            histogram(&[ymd(2024, 12, 31)], Period::Quarter)
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![(ymd(2024, 10, 1), 1)]
+        );
+    }
+
+    #[test]
+    fn keys_histograms_by_aligned_starts() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let monthly = histogram_by::<MonthStart>(&[ymd(2024, 3, 9), ymd(2024, 1, 31)]).unwrap();
+
+        assert_eq!(
+            monthly.into_iter().collect::<Vec<_>>(),
+            vec![
+                (MonthStart::containing(&ymd(2024, 1, 1)), 1),
+                (MonthStart::containing(&ymd(2024, 2, 1)), 0),
+                (MonthStart::containing(&ymd(2024, 3, 1)), 1),
+            ]
+        );
+        assert_eq!(histogram_by::<WeekStart>(&[NaiveDate::MIN]), None);
+        assert_eq!(
+            histogram_by::<YearStart>(&[NaiveDate::MAX]).map(|counts| counts.len()),
+            Some(1)
+        );
+    }
+
+    #[quickcheck]
+    fn batches_match_the_boundary_functions(dates: Vec<ArbitraryDate>) -> bool {
+        let dates: Vec<NaiveDate> = dates.into_iter().map(|date| date.0).collect();
+
+        let fallible = OPERATIONS.iter().all(|(boundary, batch, in_place)| {
+            let expected: Option<Vec<NaiveDate>> = dates.iter().map(boundary).collect();
+            let mut replaced = dates.clone();
+
+            batch(&dates) == expected && in_place(&mut replaced).map(|_| replaced) == expected
+        });
+        let infallible = INFALLIBLE_OPERATIONS
+            .iter()
+            .all(|(boundary, batch, in_place)| {
+                let expected: Vec<NaiveDate> = dates.iter().map(boundary).collect();
+                let mut replaced = dates.clone();
+                in_place(&mut replaced);
+
+                batch(&dates) == expected && replaced == expected
+            });
+
+        fallible && infallible
+    }
+
+    #[test]
+    fn handles_the_limits_of_chrono() {
+        let dates = [NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(), NaiveDate::MAX];
+
+        assert_eq!(next_year_batch(&dates), None);
+        assert_eq!(previous_year_batch(&[NaiveDate::MIN]), None);
+        assert_eq!(end_of_year_batch(&dates)[1], NaiveDate::MAX);
+        assert_eq!(
+            beginning_of_year_batch(&[NaiveDate::MIN]),
+            vec![NaiveDate::MIN]
+        );
+    }
+}
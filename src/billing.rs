@@ -0,0 +1,183 @@
+//! Subscription charge dates anchored on a signup date.
+//!
+//! Adding a month to the previous charge drifts: a subscription started on January 31 would
+//! charge on February 29 and then on the 29th forever after. A [`BillingCycle`] instead counts
+//! every charge from the signup date, clamping to the end of shorter months, so the charge
+//! returns to the 31st as soon as a month has one.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::billing::{BillingCycle, BillingInterval};
+//!
+//! let signup = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+//! let cycle = BillingCycle::new(signup, BillingInterval::Monthly);
+//!
+//! assert_eq!(cycle.charge(1), NaiveDate::from_ymd_opt(2024, 2, 29));
+//! assert_eq!(cycle.charge(2), NaiveDate::from_ymd_opt(2024, 3, 31));
+//! assert_eq!(
+//!     cycle.next_charge(&NaiveDate::from_ymd_opt(2024, 4, 15).unwrap()),
+//!     NaiveDate::from_ymd_opt(2024, 4, 30)
+//! );
+//! ```
+
+use crate::duration::shift_months;
+use crate::MonthOverflow;
+use chrono::prelude::*;
+
+/// How often a subscription is charged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BillingInterval {
+    /// Every month, on the signup day of the month.
+    Monthly,
+    /// Every year, on the signup month and day.
+    Annual,
+}
+
+impl BillingInterval {
+    /// Returns the number of months between charges.
+    pub fn months(self) -> u32 {
+        match self {
+            BillingInterval::Monthly => 1,
+            BillingInterval::Annual => 12,
+        }
+    }
+}
+
+/// The charge dates of a subscription, counted from its signup date.
+///
+/// The first charge falls on the signup date, and each later charge on the signup day of the
+/// month, or the last day of months too short to have it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BillingCycle {
+    signup: NaiveDate,
+    interval: BillingInterval,
+}
+
+impl BillingCycle {
+    /// Builds a cycle charging every `interval` from `signup`.
+    pub fn new(signup: NaiveDate, interval: BillingInterval) -> Self {
+        BillingCycle { signup, interval }
+    }
+
+    /// Returns the signup date.
+    pub fn signup(&self) -> NaiveDate {
+        self.signup
+    }
+
+    /// Returns the interval between charges.
+    pub fn interval(&self) -> BillingInterval {
+        self.interval
+    }
+
+    /// Returns the date of the charge `index` intervals after signup, where charge 0 is the
+    /// signup date itself.
+    pub fn charge(&self, index: u32) -> Option<NaiveDate> {
+        let months = i64::from(index) * i64::from(self.interval.months());
+
+        shift_months(&self.signup, months, MonthOverflow::Clamp)
+    }
+
+    /// Returns the first charge falling after `date`.
+    pub fn next_charge(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        if *date < self.signup {
+            return Some(self.signup);
+        }
+
+        let index = self.index_in_month_of(date);
+        let charge = self.charge(index)?;
+
+        if charge > *date {
+            Some(charge)
+        } else {
+            self.charge(index.checked_add(1)?)
+        }
+    }
+
+    /// Returns the last charge falling on or before `date`, or `None` if `date` precedes signup.
+    pub fn previous_charge(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        if *date < self.signup {
+            return None;
+        }
+
+        let index = self.index_in_month_of(date);
+        let charge = self.charge(index)?;
+
+        if charge <= *date {
+            Some(charge)
+        } else {
+            self.charge(index - 1)
+        }
+    }
+
+    // The index of the last charge falling in or before the month of a date on or after signup
+    fn index_in_month_of(&self, date: &NaiveDate) -> u32 {
+        let months =
+            (date.year() - self.signup.year()) as u32 * 12 + date.month() - self.signup.month();
+
+        months / self.interval.months()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn returns_to_the_signup_day_after_short_months() {
+        let cycle = BillingCycle::new(ymd(2023, 10, 31), BillingInterval::Monthly);
+        let charges: Vec<_> = (0..6).map(|index| cycle.charge(index).unwrap()).collect();
+
+        assert_eq!(
+            charges,
+            vec![
+                ymd(2023, 10, 31),
+                ymd(2023, 11, 30),
+                ymd(2023, 12, 31),
+                ymd(2024, 1, 31),
+                ymd(2024, 2, 29),
+                ymd(2024, 3, 31),
+            ]
+        );
+    }
+
+    #[test]
+    fn charges_leap_day_signups_annually_on_february_28() {
+        let cycle = BillingCycle::new(ymd(2024, 2, 29), BillingInterval::Annual);
+
+        assert_eq!(cycle.next_charge(&ymd(2024, 2, 29)), Some(ymd(2025, 2, 28)));
+        assert_eq!(cycle.charge(4), Some(ymd(2028, 2, 29)));
+        assert_eq!(
+            cycle.previous_charge(&ymd(2027, 2, 27)),
+            Some(ymd(2026, 2, 28))
+        );
+        assert_eq!(cycle.previous_charge(&ymd(2024, 2, 28)), None);
+        assert_eq!(cycle.next_charge(&ymd(2020, 1, 1)), Some(ymd(2024, 2, 29)));
+    }
+
+    #[quickcheck]
+    fn brackets_dates_between_consecutive_charges(date: ArbitraryDate, offset: u16) -> bool {
+        let signup = date.0;
+        let cycle = BillingCycle::new(signup, BillingInterval::Monthly);
+        let date = match signup.checked_add_signed(chrono::Duration::days(offset.into())) {
+            Some(date) => date,
+            None => return true,
+        };
+
+        match (cycle.previous_charge(&date), cycle.next_charge(&date)) {
+            (Some(previous), Some(next)) => {
+                previous <= date
+                    && date < next
+                    && cycle.next_charge(&previous) == Some(next)
+                    && next.day() == signup.day().min(crate::end_of_month(&next).day())
+            }
+            (Some(previous), None) => previous <= date,
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! Command-line access to the date-calculations library.
+//!
+//! ```text
+//! datecalc end-of-quarter 2024-05-17
+//! datecalc add-business-days 2024-12-20 5 --calendar us
+//! datecalc range 2024-01-01 2024-12-31 --by month
+//! ```
+
+use chrono::prelude::*;
+use date_calculations::business::{
+    add_business_days, HolidayCalendar, UsFederalHolidays, WeekendsOnly,
+};
+use date_calculations::{DateRange, Period};
+use std::env;
+use std::process;
+
+const USAGE: &str = "usage:
+  datecalc <beginning-of|end-of|next|previous>-<week|month|quarter|year> <date>
+  datecalc add-business-days <date> <days> [--calendar us|none]
+  datecalc range <start> <end> [--by day|week|month|quarter|year]
+
+dates are written as YYYY-MM-DD or `today`";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(lines) => {
+            for line in lines {
+                println!("{}", line);
+            }
+        }
+        Err(message) => {
+            eprintln!("datecalc: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<Vec<String>, String> {
+    let (command, rest) = args.split_first().ok_or("missing command")?;
+    let (positional, options) = split_options(rest)?;
+    let known: &[&str] = match command.as_str() {
+        "add-business-days" => &["--calendar"],
+        "range" => &["--by"],
+        _ => &[],
+    };
+    if let Some((unknown, _)) = options.iter().find(|(name, _)| !known.contains(name)) {
+        return Err(format!("unknown option {} for {:?}", unknown, command));
+    }
+
+    match (command.as_str(), positional.as_slice()) {
+        ("add-business-days", [date, days]) => {
+            let date = parse_date(date)?;
+            let days = days
+                .parse()
+                .map_err(|_| format!("invalid number of days {:?}", days))?;
+            let calendar = calendar(option(&options, "--calendar").unwrap_or("none"))?;
+
+            add_business_days(&date, days, calendar.as_ref())
+                .map(|d| vec![d.to_string()])
+                .ok_or_else(|| "result is outside the supported range of dates".to_string())
+        }
+        ("range", [start, end]) => {
+            let range = DateRange::new(parse_date(start)?, parse_date(end)?)
+                .ok_or("the end of the range precedes its start")?;
+
+            match option(&options, "--by").unwrap_or("day") {
+                "day" | "days" | "d" => Ok(range.iter().map(|d| d.to_string()).collect()),
+                by => {
+                    let period: Period = by.parse().map_err(|e| format!("{}", e))?;
+                    Ok(range
+                        .split_by_period(period)
+                        .iter()
+                        .map(|r| r.to_string())
+                        .collect())
+                }
+            }
+        }
+        (boundary, [date]) => {
+            let date = parse_date(date)?;
+            let (shift, period) = boundary_command(boundary)
+                .ok_or_else(|| format!("unknown command {:?}", boundary))?;
+
+            shift(period, &date)
+                .map(|d| vec![d.to_string()])
+                .ok_or_else(|| "result is outside the supported range of dates".to_string())
+        }
+        _ => Err(format!("unexpected arguments for {:?}", command)),
+    }
+}
+
+type Shift = fn(Period, &NaiveDate) -> Option<NaiveDate>;
+
+fn boundary_command(command: &str) -> Option<(Shift, Period)> {
+    let (direction, period) = command.rsplit_once('-')?;
+    let period = period.parse().ok()?;
+    let shift: Shift = match direction {
+        "beginning-of" => Period::beginning,
+        "end-of" => Period::end,
+        "next" => Period::next,
+        "previous" => Period::previous,
+        _ => return None,
+    };
+
+    Some((shift, period))
+}
+
+fn calendar(name: &str) -> Result<Box<dyn HolidayCalendar>, String> {
+    match name {
+        "us" => Ok(Box::new(UsFederalHolidays)),
+        "none" => Ok(Box::new(WeekendsOnly)),
+        other => Err(format!("unknown calendar {:?}", other)),
+    }
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    if value == "today" {
+        Ok(Local::now().date_naive())
+    } else {
+        value
+            .parse()
+            .map_err(|_| format!("invalid date {:?}", value))
+    }
+}
+
+type Options<'a> = Vec<(&'a str, &'a str)>;
+
+fn split_options(args: &[String]) -> Result<(Vec<&str>, Options<'_>), String> {
+    let mut positional = vec![];
+    let mut options = vec![];
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        if arg.starts_with("--") {
+            let value = iter
+                .next()
+                .ok_or_else(|| format!("missing value for {}", arg))?;
+            options.push((arg.as_str(), value.as_str()));
+        } else {
+            positional.push(arg.as_str());
+        }
+    }
+
+    Ok((positional, options))
+}
+
+fn option<'a>(options: &[(&str, &'a str)], name: &str) -> Option<&'a str> {
+    options
+        .iter()
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| *value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_with(args: &[&str]) -> Result<Vec<String>, String> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        run(&args)
+    }
+
+    #[test]
+    fn runs_boundary_commands() {
+        assert_eq!(
+            run_with(&["end-of-quarter", "2024-05-17"]),
+            Ok(vec!["2024-06-30".to_string()])
+        );
+        assert_eq!(
+            run_with(&["beginning-of-week", "2024-05-17"]),
+            Ok(vec!["2024-05-12".to_string()])
+        );
+        assert_eq!(
+            run_with(&["next-month", "2024-01-31"]),
+            Ok(vec!["2024-02-01".to_string()])
+        );
+        assert_eq!(
+            run_with(&["previous-year", "2024-05-17"]),
+            Ok(vec!["2023-01-01".to_string()])
+        );
+        assert!(run_with(&["end-of-month", "today"]).is_ok());
+        assert_eq!(
+            run_with(&["next-year", &NaiveDate::MAX.to_string()]),
+            Err("result is outside the supported range of dates".to_string())
+        );
+    }
+
+    #[test]
+    fn adds_business_days() {
+        let args = ["add-business-days", "2024-12-20", "5"];
+
+        assert_eq!(run_with(&args), Ok(vec!["2024-12-27".to_string()]));
+        assert_eq!(
+            run_with(&[&args[..], &["--calendar", "us"]].concat()),
+            Ok(vec!["2024-12-30".to_string()])
+        );
+        assert_eq!(
+            run_with(&["add-business-days", "2024-12-30", "-1", "--calendar", "us"]),
+            Ok(vec!["2024-12-27".to_string()])
+        );
+        assert!(run_with(&[&args[..], &["--calendar", "uk"]].concat()).is_err());
+        assert!(run_with(&["add-business-days", "2024-12-20", "five"]).is_err());
+        assert_eq!(
+            run_with(&["add-business-days", "2024-01-01", "-9223372036854775808"]),
+            Err("result is outside the supported range of dates".to_string())
+        );
+    }
+
+    #[test]
+    fn lists_ranges() {
+        assert_eq!(
+            run_with(&["range", "2024-02-27", "2024-03-01"]).map(|lines| lines.len()),
+            Ok(4)
+        );
+        assert_eq!(
+            run_with(&["range", "2024-01-15", "2024-03-10", "--by", "month"])
+                .map(|lines| lines.len()),
+            Ok(3)
+        );
+        assert!(run_with(&["range", "2024-03-01", "2024-02-01"]).is_err());
+        assert!(run_with(&["range", "2024-01-01", "2024-12-31", "--by", "decade"]).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        for args in &[
+            &[][..],
+            &["end-of-fortnight", "2024-05-17"],
+            &["sideways-month", "2024-05-17"],
+            &["end-of-month", "2024-02-30"],
+            &["end-of-month"],
+            &["range", "2024-01-01", "2024-12-31", "--by"],
+            &["range", "2024-01-01", "2024-12-31", "--calendar", "us"],
+            &["end-of-month", "2024-05-17", "--by", "day"],
+            &["add-business-days", "2024-12-20", "5", "--calender", "us"],
+        ] {
+            assert!(run_with(args).is_err(), "{:?}", args);
+        }
+
+        assert_eq!(
+            run_with(&["range", "2024-01-01", "2024-12-31", "--by"]),
+            Err("missing value for --by".to_string())
+        );
+        assert_eq!(
+            run_with(&["end-of-fortnight", "2024-05-17"]),
+            Err("unknown command \"end-of-fortnight\"".to_string())
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use crate::fiscal::FiscalCalendar;
+use crate::*;
+
+/// Every boundary of the periods containing a date, computed once.
+///
+/// Listing several boundaries for the same date, such as a report row showing its week, month,
+/// and quarter, would otherwise repeat the calculation per boundary. Week boundaries are `None`
+/// only at chrono's limits, and the fiscal boundaries are `None` unless a fiscal calendar is
+/// given.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::fiscal::{FiscalCalendar, FiscalYearLabel};
+/// use date_calculations::PeriodBoundaries;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+/// let fiscal = FiscalCalendar::new(7, FiscalYearLabel::EndYear).unwrap();
+/// let boundaries = PeriodBoundaries::with_calendars(&date, Weekday::Mon, Some(&fiscal));
+///
+/// assert_eq!(boundaries.week_start, NaiveDate::from_ymd_opt(2024, 5, 13));
+/// assert_eq!(boundaries.quarter_end, NaiveDate::from_ymd_opt(2024, 6, 30).unwrap());
+/// assert_eq!(boundaries.fiscal_year_start, NaiveDate::from_ymd_opt(2023, 7, 1));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PeriodBoundaries {
+    /// The date the boundaries surround.
+    pub date: NaiveDate,
+    /// The first day of the week.
+    pub week_start: Option<NaiveDate>,
+    /// The last day of the week.
+    pub week_end: Option<NaiveDate>,
+    /// The first day of the month.
+    pub month_start: NaiveDate,
+    /// The last day of the month.
+    pub month_end: NaiveDate,
+    /// The first day of the calendar quarter.
+    pub quarter_start: NaiveDate,
+    /// The last day of the calendar quarter.
+    pub quarter_end: NaiveDate,
+    /// January 1 of the year.
+    pub year_start: NaiveDate,
+    /// December 31 of the year.
+    pub year_end: NaiveDate,
+    /// The first day of the fiscal quarter.
+    pub fiscal_quarter_start: Option<NaiveDate>,
+    /// The last day of the fiscal quarter.
+    pub fiscal_quarter_end: Option<NaiveDate>,
+    /// The first day of the fiscal year.
+    pub fiscal_year_start: Option<NaiveDate>,
+    /// The last day of the fiscal year.
+    pub fiscal_year_end: Option<NaiveDate>,
+}
+
+impl PeriodBoundaries {
+    /// Computes the boundaries for weeks beginning on Sunday, without fiscal boundaries.
+    pub fn new(date: &NaiveDate) -> Self {
+        PeriodBoundaries::with_calendars(date, Weekday::Sun, None)
+    }
+
+    /// Computes the boundaries for weeks beginning on `week_start`, including the fiscal
+    /// boundaries of the fiscal calendar when one is given.
+    pub fn with_calendars(
+        date: &NaiveDate,
+        week_start: Weekday,
+        fiscal: Option<&FiscalCalendar>,
+    ) -> Self {
+        let fiscal_boundary = |boundary: fn(&FiscalCalendar, &NaiveDate) -> Option<NaiveDate>| {
+            fiscal.and_then(|fiscal| boundary(fiscal, date))
+        };
+
+        PeriodBoundaries {
+            date: *date,
+            week_start: beginning_of_week_starting_on(date, week_start),
+            week_end: end_of_week_starting_on(date, week_start),
+            month_start: beginning_of_month(date),
+            month_end: end_of_month(date),
+            quarter_start: beginning_of_quarter(date),
+            quarter_end: end_of_quarter(date),
+            year_start: beginning_of_year(date),
+            year_end: end_of_year(date),
+            fiscal_quarter_start: fiscal_boundary(FiscalCalendar::beginning_of_fiscal_quarter),
+            fiscal_quarter_end: fiscal_boundary(FiscalCalendar::end_of_fiscal_quarter),
+            fiscal_year_start: fiscal_boundary(FiscalCalendar::beginning_of_fiscal_year),
+            fiscal_year_end: fiscal_boundary(FiscalCalendar::end_of_fiscal_year),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fiscal::FiscalYearLabel;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    #[quickcheck]
+    fn matches_the_boundary_functions(date: ArbitraryDate) -> bool {
+        let date = date.0;
+        let fiscal = FiscalCalendar::new(10, FiscalYearLabel::EndYear).unwrap();
+        let boundaries = PeriodBoundaries::with_calendars(&date, Weekday::Sun, Some(&fiscal));
+
+        boundaries.week_start == beginning_of_week(&date)
+            && boundaries.week_end == end_of_week(&date)
+            && boundaries.month_end == end_of_month(&date)
+            && boundaries.quarter_start == beginning_of_quarter(&date)
+            && boundaries.year_end == end_of_year(&date)
+            && boundaries.fiscal_quarter_end == fiscal.end_of_fiscal_quarter(&date)
+            && boundaries.fiscal_year_start == fiscal.beginning_of_fiscal_year(&date)
+    }
+
+    #[test]
+    fn omits_fiscal_boundaries_without_a_calendar() {
+        let boundaries = PeriodBoundaries::new(&NaiveDate::MIN);
+
+        assert_eq!(boundaries.fiscal_year_start, None);
+        assert_eq!(boundaries.fiscal_quarter_end, None);
+        assert_eq!(boundaries.month_start, NaiveDate::MIN);
+        assert_eq!(
+            boundaries.week_start,
+            beginning_of_week_starting_on(&NaiveDate::MIN, Weekday::Sun)
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! Thai solar calendar support: Buddhist Era (BE) years, which run 543 years ahead of the
+//! Common Era and, since 1941, begin on January 1.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::buddhist;
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+//!
+//! assert_eq!(buddhist::buddhist_year(&date), 2567);
+//! assert_eq!(buddhist::format(&date, "%d/%m/%Y").unwrap(), "17/05/2567");
+//! assert_eq!(buddhist::parse("17/05/2567"), Ok(date));
+//! assert_eq!(buddhist::end_of_year(2567), NaiveDate::from_ymd_opt(2024, 12, 31));
+//! ```
+
+use crate::format::format_period;
+use crate::ParseError;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use chrono::prelude::*;
+
+const OFFSET: i32 = 543;
+const EXPECTED: &str = "a Buddhist Era date like \"17/05/2567\" or \"2567-05-17\"";
+
+/// Returns the Buddhist Era year for a Common Era year, or `None` if it overflows an `i32`.
+pub fn to_buddhist_year(common_era_year: i32) -> Option<i32> {
+    common_era_year.checked_add(OFFSET)
+}
+
+/// Returns the Common Era year for a Buddhist Era year, or `None` if it overflows an `i32`.
+pub fn from_buddhist_year(buddhist_year: i32) -> Option<i32> {
+    buddhist_year.checked_sub(OFFSET)
+}
+
+/// Returns the Buddhist Era year of the date.
+pub fn buddhist_year(date: &NaiveDate) -> i32 {
+    to_buddhist_year(date.year()).expect("chrono's years fit")
+}
+
+/// Returns the first day of the Buddhist Era year.
+pub fn beginning_of_year(buddhist_year: i32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(from_buddhist_year(buddhist_year)?, 1, 1)
+}
+
+/// Returns the last day of the Buddhist Era year.
+pub fn end_of_year(buddhist_year: i32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(from_buddhist_year(buddhist_year)?, 12, 31)
+}
+
+/// Formats the date like [`format_period`], with `%Y` and `%y` writing the Buddhist Era year
+/// and its last two digits.
+pub fn format(date: &NaiveDate, pattern: &str) -> Result<String, ParseError> {
+    let year = buddhist_year(date);
+    let mut rewritten = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            rewritten.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('Y') => rewritten.push_str(&year.to_string()),
+            Some('y') => rewritten.push_str(&format!("{:02}", year.rem_euclid(100))),
+            Some(other) => {
+                rewritten.push('%');
+                rewritten.push(other);
+            }
+            None => rewritten.push('%'),
+        }
+    }
+
+    format_period(date, &rewritten).map_err(|_| ParseError::new(pattern, "a valid format string"))
+}
+
+/// Parses a date with a Buddhist Era year, written day first (`17/05/2567`, `17-5-2567`,
+/// `17.05.2567`) as on Thai documents, or year first (`2567-05-17`).
+pub fn parse(input: &str) -> Result<NaiveDate, ParseError> {
+    let error = || ParseError::new(input, EXPECTED);
+    let parts: Vec<&str> = input.trim().split(['/', '-', '.']).collect();
+
+    if parts.len() != 3 || parts.iter().any(|part| !is_digits(part)) {
+        return Err(error());
+    }
+
+    let (year, month, day) = if parts[0].len() == 4 {
+        (parts[0], parts[1], parts[2])
+    } else if parts[2].len() == 4 {
+        (parts[2], parts[1], parts[0])
+    } else {
+        return Err(error());
+    };
+
+    let year: i32 = year.parse().map_err(|_| error())?;
+    let month = month.parse().map_err(|_| error())?;
+    let day = day.parse().map_err(|_| error())?;
+
+    NaiveDate::from_ymd_opt(from_buddhist_year(year).ok_or_else(error)?, month, day)
+        .ok_or_else(error)
+}
+
+fn is_digits(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn converts_years() {
+        assert_eq!(to_buddhist_year(2024), Some(2567));
+        assert_eq!(from_buddhist_year(2500), Some(1957));
+        assert_eq!(to_buddhist_year(i32::MAX), None);
+        assert_eq!(from_buddhist_year(i32::MIN), None);
+        assert_eq!(beginning_of_year(i32::MIN), None);
+        assert_eq!(beginning_of_year(2568), Some(ymd(2025, 1, 1)));
+    }
+
+    #[test]
+    fn formats_buddhist_years() {
+        let date = ymd(2024, 12, 31);
+
+        assert_eq!(format(&date, "%Y-%m-%d").unwrap(), "2567-12-31");
+        assert_eq!(format(&date, "%e %B %y").unwrap(), "31 December 67");
+        assert_eq!(format(&date, "Q%Q %Y, 100%%").unwrap(), "Q4 2567, 100%");
+        assert!(format(&date, "%J").is_err());
+    }
+
+    #[test]
+    fn parses_buddhist_dates() {
+        let expected = Ok(ymd(2024, 5, 7));
+
+        assert_eq!(parse("07/05/2567"), expected);
+        assert_eq!(parse("7-5-2567"), expected);
+        assert_eq!(parse("7.05.2567"), expected);
+        assert_eq!(parse("2567-05-07"), expected);
+
+        for input in &["2567/13/01", "31/02/2567", "07/05/67", "May 7 2567", ""] {
+            assert!(parse(input).is_err(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,1276 @@
+//! Business-day calculations, skipping the weekend and the holidays of a [`HolidayCalendar`].
+//!
+//! Weekends are Saturday and Sunday unless the calendar names another [`Weekend`], such as
+//! Friday and Saturday, through [`WithWeekend`].
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::business::*;
+//!
+//! let friday = NaiveDate::from_ymd_opt(2024, 12, 20).unwrap();
+//!
+//! assert_eq!(
+//!     add_business_days(&friday, 5, &UsFederalHolidays),
+//!     NaiveDate::from_ymd_opt(2024, 12, 30)
+//! );
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use chrono::prelude::*;
+use chrono::Days;
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+/// A set of dates, beyond weekends, on which no business is conducted.
+pub trait HolidayCalendar {
+    /// Returns whether the provided date is a holiday.
+    fn is_holiday(&self, date: &NaiveDate) -> bool;
+
+    /// Returns the days of the week on which no business is conducted, Saturday and Sunday by
+    /// default.
+    fn weekend(&self) -> Weekend {
+        Weekend::SATURDAY_SUNDAY
+    }
+
+    /// Returns the holidays falling within the provided year, in order and without repeats.
+    ///
+    /// [`business_days_between`] counts holidays through this method, so calendars whose
+    /// holidays follow rules should override it. The default checks every day of the year with
+    /// [`is_holiday`](Self::is_holiday).
+    #[cfg(feature = "alloc")]
+    fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        NaiveDate::from_yo_opt(year, 1)
+            .into_iter()
+            .flat_map(|first| first.iter_days())
+            .take_while(|d| d.year() == year)
+            .filter(|d| self.is_holiday(d))
+            .collect()
+    }
+}
+
+/// A calendar without any holidays, where every weekday is a business day.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WeekendsOnly;
+
+impl HolidayCalendar for WeekendsOnly {
+    fn is_holiday(&self, _date: &NaiveDate) -> bool {
+        false
+    }
+
+    #[cfg(feature = "alloc")]
+    fn holidays_in_year(&self, _year: i32) -> Vec<NaiveDate> {
+        Vec::new()
+    }
+}
+
+/// The days of the week on which no business is conducted.
+///
+/// A weekend is also a calendar without holidays, and [`WithWeekend`] pairs one with another
+/// calendar's holidays:
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::business::{add_business_days, is_business_day, Weekend};
+///
+/// let thursday = NaiveDate::from_ymd_opt(2024, 5, 16).unwrap();
+/// let friday = thursday.succ_opt().unwrap();
+///
+/// assert!(!is_business_day(&friday, &Weekend::FRIDAY_SATURDAY));
+/// assert_eq!(
+///     add_business_days(&thursday, 1, &Weekend::FRIDAY_SATURDAY),
+///     NaiveDate::from_ymd_opt(2024, 5, 19)
+/// );
+/// assert!(Weekend::new(&[Weekday::Sun]).unwrap().is_weekend(&(friday + chrono::Days::new(2))));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Weekend {
+    // one bit per day, from Monday in the lowest bit
+    days: u8,
+}
+
+impl Weekend {
+    /// Saturday and Sunday, the default.
+    pub const SATURDAY_SUNDAY: Weekend = Weekend::of(&[Weekday::Sat, Weekday::Sun]);
+
+    /// Friday and Saturday, as in much of the Middle East and North Africa.
+    pub const FRIDAY_SATURDAY: Weekend = Weekend::of(&[Weekday::Fri, Weekday::Sat]);
+
+    /// Thursday and Friday.
+    pub const THURSDAY_FRIDAY: Weekend = Weekend::of(&[Weekday::Thu, Weekday::Fri]);
+
+    /// Builds a weekend from its days, returning `None` if it covers the whole week and so would
+    /// leave no business days.
+    pub const fn new(days: &[Weekday]) -> Option<Self> {
+        let weekend = Weekend::of(days);
+
+        if weekend.days == 0x7f {
+            None
+        } else {
+            Some(weekend)
+        }
+    }
+
+    /// A weekend of a single day.
+    pub const fn single(day: Weekday) -> Self {
+        Weekend::of(&[day])
+    }
+
+    /// Returns whether the weekday falls within the weekend.
+    pub const fn contains(self, day: Weekday) -> bool {
+        self.days & Weekend::bit(day) != 0
+    }
+
+    /// Returns whether the provided date falls within the weekend.
+    pub fn is_weekend(self, date: &NaiveDate) -> bool {
+        self.contains(date.weekday())
+    }
+
+    /// Returns the first day outside the weekend in the week containing the date, for weeks
+    /// beginning on `week_start`.
+    ///
+    /// Returns `None` if the week extends past chrono's range.
+    pub fn beginning_of_workweek(self, date: &NaiveDate, week_start: Weekday) -> Option<NaiveDate> {
+        let beginning = crate::beginning_of_week_starting_on(date, week_start)?;
+
+        beginning
+            .iter_days()
+            .take(7)
+            .find(|day| !self.is_weekend(day))
+    }
+
+    /// Returns the last day outside the weekend in the week containing the date, for weeks
+    /// beginning on `week_start`.
+    ///
+    /// Returns `None` if the week extends past chrono's range.
+    pub fn end_of_workweek(self, date: &NaiveDate, week_start: Weekday) -> Option<NaiveDate> {
+        let end = crate::end_of_week_starting_on(date, week_start)?;
+
+        end.iter_days()
+            .rev()
+            .take(7)
+            .find(|day| !self.is_weekend(day))
+    }
+
+    /// Counts the business days among the first `days` days of a week beginning on Monday.
+    pub(crate) const fn business_days_from_monday(self, days: u32) -> u32 {
+        (!self.days & ((1 << days) - 1)).count_ones()
+    }
+
+    const fn of(days: &[Weekday]) -> Self {
+        let mut weekend = Weekend { days: 0 };
+        let mut i = 0;
+        while i < days.len() {
+            weekend.days |= Weekend::bit(days[i]);
+            i += 1;
+        }
+
+        weekend
+    }
+
+    const fn bit(day: Weekday) -> u8 {
+        1 << day.num_days_from_monday()
+    }
+}
+
+impl Default for Weekend {
+    fn default() -> Self {
+        Weekend::SATURDAY_SUNDAY
+    }
+}
+
+impl HolidayCalendar for Weekend {
+    fn is_holiday(&self, _date: &NaiveDate) -> bool {
+        false
+    }
+
+    #[cfg(feature = "alloc")]
+    fn holidays_in_year(&self, _year: i32) -> Vec<NaiveDate> {
+        Vec::new()
+    }
+
+    fn weekend(&self) -> Weekend {
+        *self
+    }
+}
+
+/// A calendar's holidays observed around a different weekend.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::business::{is_business_day, UsFederalHolidays, Weekend, WithWeekend};
+///
+/// let calendar = WithWeekend::new(UsFederalHolidays, Weekend::single(Weekday::Sun));
+/// let saturday = NaiveDate::from_ymd_opt(2024, 5, 18).unwrap();
+///
+/// assert!(is_business_day(&saturday, &calendar));
+/// assert!(!is_business_day(&NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(), &calendar));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WithWeekend<C> {
+    calendar: C,
+    weekend: Weekend,
+}
+
+impl<C: HolidayCalendar> WithWeekend<C> {
+    /// Pairs the calendar's holidays with the weekend.
+    pub fn new(calendar: C, weekend: Weekend) -> Self {
+        WithWeekend { calendar, weekend }
+    }
+
+    /// Returns the wrapped calendar.
+    pub fn calendar(&self) -> &C {
+        &self.calendar
+    }
+}
+
+impl<C: HolidayCalendar> HolidayCalendar for WithWeekend<C> {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        self.calendar.is_holiday(date)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        self.calendar.holidays_in_year(year)
+    }
+
+    fn weekend(&self) -> Weekend {
+        self.weekend
+    }
+}
+
+/// United States federal holidays, on the dates they are observed.
+///
+/// Holidays falling on a Saturday are observed the preceding Friday and holidays falling on a
+/// Sunday are observed the following Monday. Martin Luther King Jr. Day is included from 1986 and
+/// Juneteenth from 2021.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UsFederalHolidays;
+
+impl UsFederalHolidays {
+    /// Returns the observed federal holidays falling within the provided year, in order.
+    #[cfg(feature = "alloc")]
+    pub fn holidays(&self, year: i32) -> Vec<NaiveDate> {
+        self.holidays_observed_around(year, Weekend::SATURDAY_SUNDAY)
+    }
+
+    /// Returns the federal holidays falling within the provided year, in order, with those
+    /// falling on the weekend observed on the [`nearest_weekday`] outside it.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use date_calculations::business::{UsFederalHolidays, Weekend};
+    ///
+    /// let holidays = UsFederalHolidays.holidays_observed_around(2026, Weekend::FRIDAY_SATURDAY);
+    ///
+    /// // Independence Day falls on a Saturday
+    /// assert!(holidays.contains(&NaiveDate::from_ymd_opt(2026, 7, 5).unwrap()));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn holidays_observed_around(&self, year: i32, weekend: Weekend) -> Vec<NaiveDate> {
+        let mut holidays: Vec<NaiveDate> = us_federal_holidays(year, weekend)
+            .iter()
+            .chain(us_federal_holidays(year + 1, weekend).iter())
+            .flatten()
+            .copied()
+            .filter(|d| d.year() == year)
+            .collect();
+        holidays.sort();
+        holidays
+    }
+}
+
+impl HolidayCalendar for UsFederalHolidays {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        // New Year's Day of the following year may be observed on December 31
+        let observed = Some(*date);
+        [date.year(), date.year() + 1]
+            .iter()
+            .any(|&year| us_federal_holidays(year, Weekend::SATURDAY_SUNDAY).contains(&observed))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        self.holidays(year)
+    }
+}
+
+/// A holiday calendar that computes each year's holidays once and remembers them.
+///
+/// Clones share a single table behind an `Arc`, and the calendar is `Send` and `Sync` when the
+/// wrapped calendar is, so one can be built at startup and handed to every thread that needs
+/// it.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::business::{is_business_day, CachedCalendar, UsFederalHolidays};
+///
+/// let calendar = CachedCalendar::new(UsFederalHolidays);
+/// let shared = calendar.clone();
+/// let christmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+///
+/// let handle = std::thread::spawn(move || is_business_day(&christmas, &shared));
+/// assert!(!handle.join().unwrap());
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct CachedCalendar<C> {
+    shared: Arc<Shared<C>>,
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct Shared<C> {
+    calendar: C,
+    years: RwLock<HashMap<i32, Arc<[NaiveDate]>>>,
+}
+
+#[cfg(feature = "std")]
+impl<C: HolidayCalendar> CachedCalendar<C> {
+    /// Wraps the calendar with an empty table of years.
+    pub fn new(calendar: C) -> Self {
+        CachedCalendar {
+            shared: Arc::new(Shared {
+                calendar,
+                years: RwLock::new(HashMap::new()),
+            }),
+        }
+    }
+
+    /// Returns the wrapped calendar.
+    pub fn calendar(&self) -> &C {
+        &self.shared.calendar
+    }
+
+    /// Returns the holidays falling within the provided year, in order, computing them on first
+    /// use.
+    pub fn holidays(&self, year: i32) -> Arc<[NaiveDate]> {
+        // a poisoned table still holds complete years, since entries are inserted whole
+        let years = &self.shared.years;
+        if let Some(holidays) = years
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&year)
+        {
+            return Arc::clone(holidays);
+        }
+
+        let holidays: Arc<[NaiveDate]> = self.shared.calendar.holidays_in_year(year).into();
+        Arc::clone(
+            years
+                .write()
+                .unwrap_or_else(PoisonError::into_inner)
+                .entry(year)
+                .or_insert(holidays),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> Clone for CachedCalendar<C> {
+    fn clone(&self) -> Self {
+        CachedCalendar {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: HolidayCalendar> HolidayCalendar for CachedCalendar<C> {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        self.holidays(date.year()).binary_search(date).is_ok()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        self.holidays(year).to_vec()
+    }
+
+    fn weekend(&self) -> Weekend {
+        self.shared.calendar.weekend()
+    }
+}
+
+/// How to move a date that falls on a non-business day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BusinessDayConvention {
+    /// Leave the date as-is.
+    Unadjusted,
+    /// Move to the next business day.
+    Following,
+    /// Move to the next business day, unless that crosses into the next month, in which case move
+    /// to the previous business day.
+    ModifiedFollowing,
+    /// Move to the previous business day.
+    Preceding,
+    /// Move to the previous business day, unless that crosses into the previous month, in which
+    /// case move to the next business day.
+    ModifiedPreceding,
+}
+
+impl BusinessDayConvention {
+    #[cfg(feature = "alloc")]
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            BusinessDayConvention::Unadjusted => "unadjusted",
+            BusinessDayConvention::Following => "following",
+            BusinessDayConvention::ModifiedFollowing => "modified_following",
+            BusinessDayConvention::Preceding => "preceding",
+            BusinessDayConvention::ModifiedPreceding => "modified_preceding",
+        }
+    }
+}
+
+/// Moves the provided date onto a business day according to the convention.
+///
+/// Business days are returned unchanged.
+pub fn adjust<C: HolidayCalendar + ?Sized>(
+    date: &NaiveDate,
+    convention: BusinessDayConvention,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    if is_business_day(date, calendar) {
+        return Some(*date);
+    }
+
+    let following = || add_business_days(date, 1, calendar);
+    let preceding = || add_business_days(date, -1, calendar);
+
+    match convention {
+        BusinessDayConvention::Unadjusted => Some(*date),
+        BusinessDayConvention::Following => following(),
+        BusinessDayConvention::Preceding => preceding(),
+        BusinessDayConvention::ModifiedFollowing => following()
+            .filter(|d| d.month() == date.month())
+            .or_else(preceding),
+        BusinessDayConvention::ModifiedPreceding => preceding()
+            .filter(|d| d.month() == date.month())
+            .or_else(following),
+    }
+}
+
+/// Returns whether the provided date falls on a Saturday or Sunday.
+///
+/// Use [`Weekend::is_weekend`] for other weekends.
+pub fn is_weekend(date: &NaiveDate) -> bool {
+    Weekend::SATURDAY_SUNDAY.is_weekend(date)
+}
+
+/// Returns the date itself if it falls outside the weekend, or else the nearest day outside it,
+/// preferring the following day when two are equally near.
+///
+/// This is the usual rule for observing a holiday that falls on the weekend: with a Saturday and
+/// Sunday weekend, a Saturday is observed the preceding Friday and a Sunday the following
+/// Monday. Days past chrono's range are skipped, so this returns `None` only if no day outside the
+/// weekend is within reach.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::business::{nearest_weekday, Weekend};
+///
+/// let saturday = NaiveDate::from_ymd_opt(2026, 7, 4).unwrap();
+///
+/// assert_eq!(
+///     nearest_weekday(&saturday, Weekend::SATURDAY_SUNDAY),
+///     NaiveDate::from_ymd_opt(2026, 7, 3)
+/// );
+/// assert_eq!(
+///     nearest_weekday(&saturday, Weekend::FRIDAY_SATURDAY),
+///     NaiveDate::from_ymd_opt(2026, 7, 5)
+/// );
+/// ```
+pub fn nearest_weekday(date: &NaiveDate, weekend: Weekend) -> Option<NaiveDate> {
+    if !weekend.is_weekend(date) {
+        return Some(*date);
+    }
+
+    let outside = |day: &NaiveDate| !weekend.is_weekend(day);
+
+    (1..7).find_map(|days| {
+        let days = Days::new(days);
+
+        date.checked_add_days(days)
+            .filter(outside)
+            .or_else(|| date.checked_sub_days(days).filter(outside))
+    })
+}
+
+/// Returns the Monday of the week, running Monday through Sunday, containing the date.
+///
+/// Returns `None` if the week extends past chrono's range. Use
+/// [`Weekend::beginning_of_workweek`] for other weekends and week starts.
+pub fn beginning_of_workweek(date: &NaiveDate) -> Option<NaiveDate> {
+    Weekend::SATURDAY_SUNDAY.beginning_of_workweek(date, Weekday::Mon)
+}
+
+/// Returns the Friday of the week, running Monday through Sunday, containing the date.
+///
+/// Returns `None` if the week extends past chrono's range. Use [`Weekend::end_of_workweek`] for
+/// other weekends and week starts.
+pub fn end_of_workweek(date: &NaiveDate) -> Option<NaiveDate> {
+    Weekend::SATURDAY_SUNDAY.end_of_workweek(date, Weekday::Mon)
+}
+
+/// Returns whether the provided date falls on neither the calendar's weekend nor a holiday.
+pub fn is_business_day<C: HolidayCalendar + ?Sized>(date: &NaiveDate, calendar: &C) -> bool {
+    !calendar.weekend().is_weekend(date) && !calendar.is_holiday(date)
+}
+
+/// Shifts the provided date by `days` business days, forward when positive and backward when
+/// negative.
+///
+/// Shifting by zero returns the date itself, even if it is not a business day.
+pub fn add_business_days<C: HolidayCalendar + ?Sized>(
+    date: &NaiveDate,
+    days: i64,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    let mut current = *date;
+    let mut remaining = days.unsigned_abs();
+
+    // no count beyond the number of days chrono can represent can be reached
+    let span = NaiveDate::MAX
+        .signed_duration_since(NaiveDate::MIN)
+        .num_days();
+    if remaining > span.unsigned_abs() {
+        return None;
+    }
+
+    while remaining > 0 {
+        current = if days > 0 {
+            current.succ_opt()?
+        } else {
+            current.pred_opt()?
+        };
+
+        if is_business_day(&current, calendar) {
+            remaining -= 1;
+        }
+    }
+
+    Some(current)
+}
+
+/// Counts the business days after `start` up to and including `end`.
+///
+/// This is the inverse of [`add_business_days`]: when `start` is a business day, shifting it by
+/// the result lands on `end` (or the business day preceding it). The count is negative when `end`
+/// precedes `start`.
+///
+/// Weekdays are counted arithmetically and holidays are taken from
+/// [`HolidayCalendar::holidays_in_year`], so the cost grows with the number of years spanned
+/// rather than days; wrap the calendar in a `CachedCalendar` to compute each year only once.
+#[cfg(feature = "alloc")]
+pub fn business_days_between<C: HolidayCalendar + ?Sized>(
+    start: &NaiveDate,
+    end: &NaiveDate,
+    calendar: &C,
+) -> i64 {
+    if end < start {
+        return -business_days_between(end, start, calendar);
+    }
+
+    let weekend = calendar.weekend();
+    let holidays = (start.year()..=end.year())
+        .flat_map(|year| calendar.holidays_in_year(year))
+        .filter(|d| start < d && d <= end && !weekend.is_weekend(d))
+        .count() as i64;
+
+    weekdays_through(end, weekend) - weekdays_through(start, weekend) - holidays
+}
+
+/// Returns the first business day of the month containing the date.
+///
+/// Returns `None` if the month has no business days. Use [`WithWeekend`] for calendars with
+/// another workweek.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::business::*;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+///
+/// // Sunday, September 1 is followed by Labor Day
+/// assert_eq!(
+///     first_business_day_of_month(&date, &UsFederalHolidays),
+///     NaiveDate::from_ymd_opt(2024, 9, 3)
+/// );
+/// assert_eq!(
+///     first_business_day_of_month(&date, &WithWeekend::new(UsFederalHolidays, Weekend::FRIDAY_SATURDAY)),
+///     NaiveDate::from_ymd_opt(2024, 9, 1)
+/// );
+/// ```
+pub fn first_business_day_of_month<C: HolidayCalendar + ?Sized>(
+    date: &NaiveDate,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    first_business_day(
+        crate::beginning_of_month(date),
+        crate::end_of_month(date),
+        calendar,
+    )
+}
+
+/// Returns the last business day of the month containing the date.
+///
+/// Returns `None` if the month has no business days.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::business::*;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 8, 15).unwrap();
+///
+/// assert_eq!(
+///     last_business_day_of_month(&date, &UsFederalHolidays),
+///     NaiveDate::from_ymd_opt(2024, 8, 30)
+/// );
+/// assert_eq!(
+///     last_business_day_of_month(&date, &WithWeekend::new(UsFederalHolidays, Weekend::FRIDAY_SATURDAY)),
+///     NaiveDate::from_ymd_opt(2024, 8, 29)
+/// );
+/// ```
+pub fn last_business_day_of_month<C: HolidayCalendar + ?Sized>(
+    date: &NaiveDate,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    last_business_day(
+        crate::beginning_of_month(date),
+        crate::end_of_month(date),
+        calendar,
+    )
+}
+
+/// Returns the first business day of the quarter containing the date.
+///
+/// Returns `None` if the quarter has no business days.
+pub fn first_business_day_of_quarter<C: HolidayCalendar + ?Sized>(
+    date: &NaiveDate,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    first_business_day(
+        crate::beginning_of_quarter(date),
+        crate::end_of_quarter(date),
+        calendar,
+    )
+}
+
+/// Returns the last business day of the quarter containing the date.
+///
+/// Returns `None` if the quarter has no business days.
+pub fn last_business_day_of_quarter<C: HolidayCalendar + ?Sized>(
+    date: &NaiveDate,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    last_business_day(
+        crate::beginning_of_quarter(date),
+        crate::end_of_quarter(date),
+        calendar,
+    )
+}
+
+/// Returns the first business day of the year containing the date.
+///
+/// Returns `None` if the year has no business days.
+pub fn first_business_day_of_year<C: HolidayCalendar + ?Sized>(
+    date: &NaiveDate,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    first_business_day(
+        crate::beginning_of_year(date),
+        crate::end_of_year(date),
+        calendar,
+    )
+}
+
+/// Returns the last business day of the year containing the date.
+///
+/// Returns `None` if the year has no business days.
+pub fn last_business_day_of_year<C: HolidayCalendar + ?Sized>(
+    date: &NaiveDate,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    last_business_day(
+        crate::beginning_of_year(date),
+        crate::end_of_year(date),
+        calendar,
+    )
+}
+
+/// Returns the deadline falling due at the end of the month containing the date: the month's
+/// last day if it is a business day, or else the business day preceding it.
+///
+/// Unlike [`last_business_day_of_month`], the deadline may fall in an earlier month when the
+/// month has no business days. Returns `None` if no business day precedes the month's end.
+pub fn deadline_on_or_before_end_of_month<C: HolidayCalendar + ?Sized>(
+    date: &NaiveDate,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    adjust(
+        &crate::end_of_month(date),
+        BusinessDayConvention::Preceding,
+        calendar,
+    )
+}
+
+/// Returns the deadline falling due at the end of the quarter containing the date, its last
+/// day or the business day preceding it.
+///
+/// Returns `None` if no business day precedes the quarter's end.
+pub fn deadline_on_or_before_end_of_quarter<C: HolidayCalendar + ?Sized>(
+    date: &NaiveDate,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    adjust(
+        &crate::end_of_quarter(date),
+        BusinessDayConvention::Preceding,
+        calendar,
+    )
+}
+
+/// Returns the deadline falling due at the end of the year containing the date, December 31 or
+/// the business day preceding it.
+///
+/// Returns `None` if no business day precedes the year's end.
+pub fn deadline_on_or_before_end_of_year<C: HolidayCalendar + ?Sized>(
+    date: &NaiveDate,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    adjust(
+        &crate::end_of_year(date),
+        BusinessDayConvention::Preceding,
+        calendar,
+    )
+}
+
+/// Returns the four due dates of the United States quarterly estimated tax payments for the tax
+/// year: April 15, June 15, and September 15 of the year and January 15 of the next, each moved
+/// onto the following business day of [`UsFederalHolidays`].
+///
+/// As the IRS does, Washington, D.C.'s Emancipation Day (April 16, observed on the nearest
+/// weekday) is also treated as a holiday, which delays the April deadline in some years. Returns
+/// `None` if a deadline falls outside chrono's range.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::business::estimated_tax_deadlines;
+///
+/// let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// assert_eq!(
+///     estimated_tax_deadlines(2024),
+///     Some([ymd(2024, 4, 15), ymd(2024, 6, 17), ymd(2024, 9, 16), ymd(2025, 1, 15)])
+/// );
+/// ```
+pub fn estimated_tax_deadlines(year: i32) -> Option<[NaiveDate; 4]> {
+    let deadline = |year, month| {
+        adjust(
+            &NaiveDate::from_ymd_opt(year, month, 15)?,
+            BusinessDayConvention::Following,
+            &IrsHolidays,
+        )
+    };
+
+    Some([
+        deadline(year, 4)?,
+        deadline(year, 6)?,
+        deadline(year, 9)?,
+        deadline(year.checked_add(1)?, 1)?,
+    ])
+}
+
+// The federal holidays plus Washington, D.C.'s Emancipation Day, which the IRS observes when
+// extending deadlines
+struct IrsHolidays;
+
+impl HolidayCalendar for IrsHolidays {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        UsFederalHolidays.is_holiday(date)
+            || (date.year() >= 2005
+                && NaiveDate::from_ymd_opt(date.year(), 4, 16)
+                    .map(|day| observed(day, Weekend::SATURDAY_SUNDAY))
+                    == Some(*date))
+    }
+}
+
+fn first_business_day<C: HolidayCalendar + ?Sized>(
+    start: NaiveDate,
+    end: NaiveDate,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    start
+        .iter_days()
+        .take_while(|date| *date <= end)
+        .find(|date| is_business_day(date, calendar))
+}
+
+fn last_business_day<C: HolidayCalendar + ?Sized>(
+    start: NaiveDate,
+    end: NaiveDate,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    end.iter_days()
+        .rev()
+        .take_while(|date| *date >= start)
+        .find(|date| is_business_day(date, calendar))
+}
+
+/// Counts the days falling within the weekend after `start` up to and including `end`.
+///
+/// This complements [`business_days_between`] without regard to holidays, and is computed
+/// arithmetically however far apart the dates are. The count is negative when `end` precedes
+/// `start`.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::business::{weekend_days_between, Weekend};
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 4, 30).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 5, 31).unwrap();
+///
+/// assert_eq!(weekend_days_between(&start, &end, &Weekend::SATURDAY_SUNDAY), 8);
+/// assert_eq!(weekend_days_between(&start, &end, &Weekend::FRIDAY_SATURDAY), 9);
+/// ```
+pub fn weekend_days_between(start: &NaiveDate, end: &NaiveDate, weekend: &Weekend) -> i64 {
+    let days = end.signed_duration_since(*start).num_days();
+
+    days - (weekdays_through(end, *weekend) - weekdays_through(start, *weekend))
+}
+
+// Days outside the weekend from the Monday, January 1 of year 1 through the date, negative
+// before then
+fn weekdays_through(date: &NaiveDate, weekend: Weekend) -> i64 {
+    let days = i64::from(date.num_days_from_ce());
+    let per_week = i64::from(weekend.business_days_from_monday(7));
+
+    days.div_euclid(7) * per_week
+        + i64::from(weekend.business_days_from_monday(days.rem_euclid(7) as u32))
+}
+
+fn us_federal_holidays(year: i32, weekend: Weekend) -> [Option<NaiveDate>; 11] {
+    let fixed =
+        |month, day| NaiveDate::from_ymd_opt(year, month, day).map(|date| observed(date, weekend));
+    [
+        fixed(1, 1),
+        nth_weekday(year, 2, Weekday::Mon, 3),
+        last_weekday(year, 5, Weekday::Mon),
+        fixed(7, 4),
+        nth_weekday(year, 9, Weekday::Mon, 1),
+        nth_weekday(year, 10, Weekday::Mon, 2),
+        fixed(11, 11),
+        nth_weekday(year, 11, Weekday::Thu, 4),
+        fixed(12, 25),
+        nth_weekday(year, 1, Weekday::Mon, 3).filter(|_| year >= 1986),
+        fixed(6, 19).filter(|_| year >= 2021),
+    ]
+}
+
+fn observed(date: NaiveDate, weekend: Weekend) -> NaiveDate {
+    nearest_weekday(&date, weekend).unwrap_or(date)
+}
+
+pub(crate) fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+}
+
+pub(crate) fn last_weekday(year: i32, month: u32, weekday: Weekday) -> Option<NaiveDate> {
+    nth_weekday(year, month, weekday, 5).or_else(|| nth_weekday(year, month, weekday, 4))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn finds_business_days_at_period_ends() {
+        let calendar = UsFederalHolidays;
+
+        // New Year's Day falls on a Sunday and is observed on Monday, January 2
+        assert_eq!(
+            first_business_day_of_year(&ymd(2023, 6, 1), &calendar),
+            Some(ymd(2023, 1, 3))
+        );
+        assert_eq!(
+            last_business_day_of_year(&ymd(2023, 6, 1), &calendar),
+            Some(ymd(2023, 12, 29))
+        );
+        assert_eq!(
+            first_business_day_of_quarter(&ymd(2024, 8, 15), &calendar),
+            Some(ymd(2024, 7, 1))
+        );
+        assert_eq!(
+            last_business_day_of_quarter(&ymd(2024, 5, 1), &calendar),
+            Some(ymd(2024, 6, 28))
+        );
+        assert_eq!(
+            first_business_day_of_month(&ymd(2024, 9, 20), &calendar),
+            Some(ymd(2024, 9, 3))
+        );
+        assert_eq!(
+            first_business_day_of_month(&ymd(2024, 9, 20), &Weekend::single(Weekday::Mon)),
+            Some(ymd(2024, 9, 1))
+        );
+    }
+
+    #[test]
+    fn deadlines_fall_on_or_before_the_period_end() {
+        struct ClosedInDecember;
+
+        impl HolidayCalendar for ClosedInDecember {
+            fn is_holiday(&self, date: &NaiveDate) -> bool {
+                date.month() == 12
+            }
+        }
+
+        assert_eq!(
+            deadline_on_or_before_end_of_month(&ymd(2024, 8, 15), &UsFederalHolidays),
+            Some(ymd(2024, 8, 30))
+        );
+        assert_eq!(
+            deadline_on_or_before_end_of_quarter(&ymd(2024, 4, 15), &UsFederalHolidays),
+            Some(ymd(2024, 6, 28))
+        );
+        assert_eq!(
+            deadline_on_or_before_end_of_year(&ymd(2024, 4, 15), &ClosedInDecember),
+            Some(ymd(2024, 11, 29))
+        );
+        assert_eq!(
+            last_business_day_of_month(&ymd(2024, 12, 1), &ClosedInDecember),
+            None
+        );
+        assert_eq!(
+            deadline_on_or_before_end_of_month(&ymd(2024, 12, 1), &ClosedInDecember),
+            Some(ymd(2024, 11, 29))
+        );
+    }
+
+    #[test]
+    fn moves_estimated_tax_deadlines_past_holidays() {
+        assert_eq!(
+            estimated_tax_deadlines(2023),
+            Some([
+                ymd(2023, 4, 18),
+                ymd(2023, 6, 15),
+                ymd(2023, 9, 15),
+                ymd(2024, 1, 16),
+            ])
+        );
+        // Emancipation Day falls on a Saturday and is observed on Friday, April 15
+        assert_eq!(
+            estimated_tax_deadlines(2022).map(|deadlines| deadlines[0]),
+            Some(ymd(2022, 4, 18))
+        );
+        assert_eq!(estimated_tax_deadlines(NaiveDate::MAX.year()), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn lists_observed_us_federal_holidays() {
+        assert_eq!(
+            UsFederalHolidays.holidays(2021),
+            vec![
+                ymd(2021, 1, 1),
+                ymd(2021, 1, 18),
+                ymd(2021, 2, 15),
+                ymd(2021, 5, 31),
+                ymd(2021, 6, 18),
+                ymd(2021, 7, 5),
+                ymd(2021, 9, 6),
+                ymd(2021, 10, 11),
+                ymd(2021, 11, 11),
+                ymd(2021, 11, 25),
+                ymd(2021, 12, 24),
+                ymd(2021, 12, 31),
+            ]
+        );
+    }
+
+    #[test]
+    fn moves_new_years_day_observance_into_the_previous_year() {
+        assert!(UsFederalHolidays.is_holiday(&ymd(2021, 12, 31)));
+        assert!(!UsFederalHolidays.is_holiday(&ymd(2022, 1, 1)));
+        #[cfg(feature = "alloc")]
+        assert!(!UsFederalHolidays
+            .holidays(2022)
+            .contains(&ymd(2021, 12, 31)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn lists_holidays_by_checking_each_day() {
+        struct FirstOfMonth;
+
+        impl HolidayCalendar for FirstOfMonth {
+            fn is_holiday(&self, date: &NaiveDate) -> bool {
+                date.day() == 1
+            }
+        }
+
+        let holidays = FirstOfMonth.holidays_in_year(2024);
+
+        assert_eq!(holidays.len(), 12);
+        assert_eq!(holidays[11], ymd(2024, 12, 1));
+        assert_eq!(
+            UsFederalHolidays.holidays_in_year(2024),
+            UsFederalHolidays.holidays(2024)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn caches_holidays_across_clones() {
+        fn assert_shareable<T: Clone + Send + Sync>() {}
+        assert_shareable::<WeekendsOnly>();
+        assert_shareable::<UsFederalHolidays>();
+        assert_shareable::<CachedCalendar<UsFederalHolidays>>();
+        assert_shareable::<crate::fiscal::FiscalCalendar>();
+
+        let calendar = CachedCalendar::new(UsFederalHolidays);
+        let clone = calendar.clone();
+        let holidays = calendar.holidays(2021);
+
+        assert!(Arc::ptr_eq(&holidays, &clone.holidays(2021)));
+        assert_eq!(holidays.to_vec(), UsFederalHolidays.holidays(2021));
+        assert!(clone.is_holiday(&ymd(2021, 12, 31)));
+        assert!(!clone.is_holiday(&ymd(2022, 1, 1)));
+        assert_eq!(
+            business_days_between(&ymd(2024, 1, 1), &ymd(2054, 1, 1), &clone),
+            business_days_between(&ymd(2024, 1, 1), &ymd(2054, 1, 1), &UsFederalHolidays)
+        );
+    }
+
+    #[test]
+    fn adds_business_days() {
+        let friday = ymd(2024, 12, 20);
+
+        assert_eq!(
+            add_business_days(&friday, 1, &WeekendsOnly),
+            Some(ymd(2024, 12, 23))
+        );
+        assert_eq!(
+            add_business_days(&friday, 5, &UsFederalHolidays),
+            Some(ymd(2024, 12, 30))
+        );
+        assert_eq!(
+            add_business_days(&ymd(2024, 12, 26), -2, &UsFederalHolidays),
+            Some(ymd(2024, 12, 23))
+        );
+        assert_eq!(add_business_days(&friday, 0, &WeekendsOnly), Some(friday));
+        assert_eq!(add_business_days(&friday, i64::MIN, &WeekendsOnly), None);
+        assert_eq!(
+            add_business_days(&friday, i64::MAX, &UsFederalHolidays),
+            None
+        );
+    }
+
+    #[test]
+    fn adjusts_by_convention() {
+        let saturday = ymd(2024, 8, 31);
+
+        assert_eq!(
+            adjust(&saturday, BusinessDayConvention::Unadjusted, &WeekendsOnly),
+            Some(saturday)
+        );
+        assert_eq!(
+            adjust(
+                &saturday,
+                BusinessDayConvention::Following,
+                &UsFederalHolidays
+            ),
+            Some(ymd(2024, 9, 3))
+        );
+        assert_eq!(
+            adjust(
+                &saturday,
+                BusinessDayConvention::ModifiedFollowing,
+                &WeekendsOnly
+            ),
+            Some(ymd(2024, 8, 30))
+        );
+        assert_eq!(
+            adjust(
+                &ymd(2024, 6, 1),
+                BusinessDayConvention::ModifiedPreceding,
+                &WeekendsOnly
+            ),
+            Some(ymd(2024, 6, 3))
+        );
+        assert_eq!(
+            adjust(
+                &ymd(2024, 6, 3),
+                BusinessDayConvention::Preceding,
+                &WeekendsOnly
+            ),
+            Some(ymd(2024, 6, 3))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn counts_business_days_between() {
+        let start = ymd(2024, 12, 20);
+        let end = ymd(2024, 12, 30);
+
+        assert_eq!(business_days_between(&start, &end, &UsFederalHolidays), 5);
+        assert_eq!(business_days_between(&end, &start, &UsFederalHolidays), -5);
+        assert_eq!(business_days_between(&start, &end, &WeekendsOnly), 6);
+        assert_eq!(business_days_between(&start, &start, &WeekendsOnly), 0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn counts_business_days_across_decades() {
+        let start = ymd(2024, 1, 1);
+        let end = ymd(2054, 1, 1);
+
+        assert_eq!(business_days_between(&start, &end, &WeekendsOnly), 7828);
+        let all = business_days_between(&NaiveDate::MIN, &NaiveDate::MAX, &WeekendsOnly);
+        let days = NaiveDate::MAX
+            .signed_duration_since(NaiveDate::MIN)
+            .num_days();
+        assert!((all - days * 5 / 7).abs() <= 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[quickcheck]
+    fn counting_matches_iterating(start: ArbitraryDate, span: u16) -> bool {
+        let start = start.0;
+        let end = start + chrono::Duration::days(i64::from(span % 1000));
+        let iterated = start
+            .iter_days()
+            .skip(1)
+            .take_while(|d| d <= &end)
+            .filter(|d| is_business_day(d, &UsFederalHolidays))
+            .count() as i64;
+
+        business_days_between(&start, &end, &UsFederalHolidays) == iterated
+            && business_days_between(&end, &start, &UsFederalHolidays) == -iterated
+    }
+
+    #[cfg(feature = "alloc")]
+    #[quickcheck]
+    fn counting_other_weekends_matches_iterating(start: ArbitraryDate, span: u16) -> bool {
+        let start = start.0;
+        let end = start + chrono::Duration::days(i64::from(span % 1000));
+        let weekends = [
+            Weekend::FRIDAY_SATURDAY,
+            Weekend::single(Weekday::Sun),
+            Weekend::new(&[]).unwrap(),
+        ];
+
+        weekends.iter().all(|weekend| {
+            let calendar = WithWeekend::new(UsFederalHolidays, *weekend);
+            let iterated = start
+                .iter_days()
+                .skip(1)
+                .take_while(|d| d <= &end)
+                .filter(|d| is_business_day(d, &calendar))
+                .count() as i64;
+
+            business_days_between(&start, &end, &calendar) == iterated
+        })
+    }
+
+    #[quickcheck]
+    fn counting_weekend_days_matches_iterating(start: ArbitraryDate, span: u16) -> bool {
+        let start = start.0;
+        let end = start + chrono::Duration::days(i64::from(span % 1000));
+
+        [
+            Weekend::SATURDAY_SUNDAY,
+            Weekend::THURSDAY_FRIDAY,
+            Weekend::single(Weekday::Fri),
+        ]
+        .iter()
+        .all(|weekend| {
+            let iterated = start
+                .iter_days()
+                .skip(1)
+                .take_while(|d| d <= &end)
+                .filter(|d| weekend.is_weekend(d))
+                .count() as i64;
+
+            weekend_days_between(&start, &end, weekend) == iterated
+                && weekend_days_between(&end, &start, weekend) == -iterated
+        })
+    }
+
+    #[test]
+    fn spans_workweeks() {
+        let sunday = ymd(2024, 5, 19);
+
+        assert_eq!(beginning_of_workweek(&sunday), Some(ymd(2024, 5, 13)));
+        assert_eq!(end_of_workweek(&sunday), Some(ymd(2024, 5, 17)));
+        assert_eq!(
+            Weekend::FRIDAY_SATURDAY.beginning_of_workweek(&sunday, Weekday::Sun),
+            Some(sunday)
+        );
+        assert_eq!(
+            Weekend::FRIDAY_SATURDAY.end_of_workweek(&sunday, Weekday::Sun),
+            Some(ymd(2024, 5, 23))
+        );
+        assert_eq!(
+            Weekend::single(Weekday::Mon).beginning_of_workweek(&sunday, Weekday::Mon),
+            Some(ymd(2024, 5, 14))
+        );
+        assert_eq!(end_of_workweek(&NaiveDate::MAX), None);
+    }
+
+    #[test]
+    fn builds_weekends_from_days() {
+        let all = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+
+        assert_eq!(Weekend::new(&all), None);
+        assert_eq!(
+            Weekend::new(&[Weekday::Sun, Weekday::Sat]),
+            Some(Weekend::default())
+        );
+        assert!(Weekend::THURSDAY_FRIDAY.contains(Weekday::Thu));
+        assert!(!Weekend::THURSDAY_FRIDAY.contains(Weekday::Sat));
+        assert_eq!(WeekendsOnly.weekend(), Weekend::SATURDAY_SUNDAY);
+        assert_eq!(
+            nearest_weekday(&ymd(2024, 5, 19), Weekend::SATURDAY_SUNDAY),
+            Some(ymd(2024, 5, 20))
+        );
+        assert_eq!(
+            nearest_weekday(&ymd(2024, 5, 17), Weekend::THURSDAY_FRIDAY),
+            Some(ymd(2024, 5, 18))
+        );
+        assert_eq!(
+            nearest_weekday(&ymd(2024, 5, 16), Weekend::THURSDAY_FRIDAY),
+            Some(ymd(2024, 5, 15))
+        );
+        assert_eq!(
+            nearest_weekday(&ymd(2024, 5, 15), Weekend::THURSDAY_FRIDAY),
+            Some(ymd(2024, 5, 15))
+        );
+        assert_eq!(
+            nearest_weekday(&NaiveDate::MAX, Weekend::single(NaiveDate::MAX.weekday())),
+            NaiveDate::MAX.pred_opt()
+        );
+        assert_eq!(
+            adjust(
+                &ymd(2024, 5, 17),
+                BusinessDayConvention::Following,
+                &Weekend::FRIDAY_SATURDAY
+            ),
+            Some(ymd(2024, 5, 19))
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! Chinese lunisolar calendar helpers: Lunar New Year, lunar month boundaries, and zodiac years.
+//!
+//! Months begin on the day of the new moon, and the year begins with the second new moon after
+//! the winter solstice unless a leap month intervenes, following the rules formalized in
+//! *Calendrical Calculations*. Chinese dates are reckoned in Beijing time (UTC+8); Korea and
+//! Vietnam apply the same rules in their own time zones, which occasionally moves their New Year
+//! to a different day, so [`new_year_at`] accepts an offset.
+//!
+//! Astronomical positions use Meeus's new moon series and a low-precision solar longitude,
+//! accurate to a few minutes for the years 1900 through 2100. A new moon or solar term within
+//! minutes of local midnight could therefore be assigned to the neighbouring day.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::chinese::{self, ZodiacAnimal};
+//!
+//! assert_eq!(chinese::new_year(2024), NaiveDate::from_ymd_opt(2024, 2, 10));
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 2, 9).unwrap();
+//! assert_eq!(chinese::zodiac(&date), Some(ZodiacAnimal::Rabbit));
+//! assert_eq!(chinese::next_month(&date), NaiveDate::from_ymd_opt(2024, 2, 10));
+//! ```
+
+use crate::astronomy::{
+    new_moon_at_or_after, new_moon_before_moment, solar_longitude, solar_longitude_after,
+    MEAN_SYNODIC_MONTH,
+};
+use crate::day_number::{from_rata_die, to_rata_die};
+use chrono::prelude::*;
+use core::fmt;
+
+const BEIJING: i32 = 8 * 60;
+const WINTER_SOLSTICE: f64 = 270.0;
+
+/// An animal of the twelve-year zodiac cycle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ZodiacAnimal {
+    /// Rat.
+    Rat,
+    /// Ox.
+    Ox,
+    /// Tiger.
+    Tiger,
+    /// Rabbit.
+    Rabbit,
+    /// Dragon.
+    Dragon,
+    /// Snake.
+    Snake,
+    /// Horse.
+    Horse,
+    /// Goat.
+    Goat,
+    /// Monkey.
+    Monkey,
+    /// Rooster.
+    Rooster,
+    /// Dog.
+    Dog,
+    /// Pig.
+    Pig,
+}
+
+const ZODIAC: [ZodiacAnimal; 12] = [
+    ZodiacAnimal::Rat,
+    ZodiacAnimal::Ox,
+    ZodiacAnimal::Tiger,
+    ZodiacAnimal::Rabbit,
+    ZodiacAnimal::Dragon,
+    ZodiacAnimal::Snake,
+    ZodiacAnimal::Horse,
+    ZodiacAnimal::Goat,
+    ZodiacAnimal::Monkey,
+    ZodiacAnimal::Rooster,
+    ZodiacAnimal::Dog,
+    ZodiacAnimal::Pig,
+];
+
+impl ZodiacAnimal {
+    /// Returns the animal of the lunar year that begins in the Gregorian year.
+    pub fn for_year(gregorian_year: i32) -> Self {
+        ZODIAC[(i64::from(gregorian_year) - 4).rem_euclid(12) as usize]
+    }
+}
+
+/// Formats the animal's English name.
+impl fmt::Display for ZodiacAnimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Returns the Chinese New Year falling in the Gregorian year.
+pub fn new_year(gregorian_year: i32) -> Option<NaiveDate> {
+    new_year_at(gregorian_year, BEIJING)
+}
+
+/// Returns the Lunar New Year falling in the Gregorian year, reckoned in the time zone the
+/// provided number of minutes east of UTC: 540 for Seollal in Korea or 420 for Tết in Vietnam.
+pub fn new_year_at(gregorian_year: i32, utc_offset_minutes: i32) -> Option<NaiveDate> {
+    let midyear = to_rata_die(&NaiveDate::from_ymd_opt(gregorian_year, 7, 1)?);
+    from_rata_die(new_year_on_or_before(midyear, zone(utc_offset_minutes)))
+}
+
+/// Returns the zodiac animal of the lunar year containing the provided date.
+pub fn zodiac(date: &NaiveDate) -> Option<ZodiacAnimal> {
+    let year = if *date < new_year(date.year())? {
+        date.year() - 1
+    } else {
+        date.year()
+    };
+
+    Some(ZodiacAnimal::for_year(year))
+}
+
+/// Returns the beginning of the lunar month containing the provided date.
+pub fn beginning_of_month(date: &NaiveDate) -> Option<NaiveDate> {
+    from_rata_die(new_moon_before(to_rata_die(date) + 1, zone(BEIJING)))
+}
+
+/// Returns the end of the lunar month containing the provided date.
+pub fn end_of_month(date: &NaiveDate) -> Option<NaiveDate> {
+    next_month(date)?.pred_opt()
+}
+
+/// Returns the beginning of the next lunar month.
+pub fn next_month(date: &NaiveDate) -> Option<NaiveDate> {
+    from_rata_die(new_moon_on_or_after(to_rata_die(date) + 1, zone(BEIJING)))
+}
+
+/// Returns the beginning of the previous lunar month.
+pub fn previous_month(date: &NaiveDate) -> Option<NaiveDate> {
+    beginning_of_month(&beginning_of_month(date)?.pred_opt()?)
+}
+
+fn zone(utc_offset_minutes: i32) -> f64 {
+    f64::from(utc_offset_minutes) / 1440.0
+}
+
+fn new_year_on_or_before(fixed: i64, zone: f64) -> i64 {
+    let new_year = new_year_in_sui(fixed, zone);
+
+    if fixed >= new_year {
+        new_year
+    } else {
+        new_year_in_sui(fixed - 180, zone)
+    }
+}
+
+// The New Year within the solar year (sui) running between the winter solstices around `fixed`
+fn new_year_in_sui(fixed: i64, zone: f64) -> i64 {
+    let s1 = winter_solstice_on_or_before(fixed, zone);
+    let s2 = winter_solstice_on_or_before(s1 + 370, zone);
+    let m12 = new_moon_on_or_after(s1 + 1, zone);
+    let m13 = new_moon_on_or_after(m12 + 1, zone);
+    let next_m11 = new_moon_before(s2 + 1, zone);
+
+    let leap_year = ((next_m11 - m12) as f64 / MEAN_SYNODIC_MONTH).round() as i64 == 12;
+    if leap_year && (no_major_solar_term(m12, zone) || no_major_solar_term(m13, zone)) {
+        new_moon_on_or_after(m13 + 1, zone)
+    } else {
+        m13
+    }
+}
+
+fn no_major_solar_term(fixed: i64, zone: f64) -> bool {
+    major_solar_term(fixed, zone) == major_solar_term(new_moon_on_or_after(fixed + 1, zone), zone)
+}
+
+fn major_solar_term(fixed: i64, zone: f64) -> i64 {
+    let longitude = solar_longitude(fixed as f64 - zone);
+    (2 + (longitude / 30.0).floor() as i64 - 1).rem_euclid(12) + 1
+}
+
+fn winter_solstice_on_or_before(fixed: i64, zone: f64) -> i64 {
+    let local_day = |moment: f64| (moment + zone).floor() as i64;
+    let mut solstice = solar_longitude_after(WINTER_SOLSTICE, (fixed - 370) as f64 - zone);
+
+    loop {
+        let next = solar_longitude_after(WINTER_SOLSTICE, solstice + 1.0);
+        if local_day(next) > fixed {
+            return local_day(solstice);
+        }
+        solstice = next;
+    }
+}
+
+// The local day of the first new moon at or after local midnight beginning `fixed`
+fn new_moon_on_or_after(fixed: i64, zone: f64) -> i64 {
+    (new_moon_at_or_after(fixed as f64 - zone) + zone).floor() as i64
+}
+
+// The local day of the last new moon before local midnight beginning `fixed`
+fn new_moon_before(fixed: i64, zone: f64) -> i64 {
+    (new_moon_before_moment(fixed as f64 - zone) + zone).floor() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn computes_chinese_new_years() {
+        let expected = [
+            ymd(1985, 2, 20),
+            ymd(1990, 1, 27),
+            ymd(2000, 2, 5),
+            ymd(2010, 2, 14),
+            ymd(2015, 2, 19),
+            ymd(2016, 2, 8),
+            ymd(2017, 1, 28),
+            ymd(2018, 2, 16),
+            ymd(2019, 2, 5),
+            ymd(2020, 1, 25),
+            ymd(2021, 2, 12),
+            ymd(2022, 2, 1),
+            ymd(2023, 1, 22),
+            ymd(2024, 2, 10),
+            ymd(2025, 1, 29),
+            ymd(2026, 2, 17),
+            ymd(2033, 1, 31),
+            ymd(2034, 2, 19),
+        ];
+
+        for date in &expected {
+            assert_eq!(new_year(date.year()), Some(*date));
+        }
+    }
+
+    #[test]
+    fn computes_new_years_in_other_time_zones() {
+        // Vietnam's Tết fell a month before China's New Year in 1985
+        assert_eq!(new_year_at(1985, 420), Some(ymd(1985, 1, 21)));
+        assert_eq!(new_year_at(2024, 540), Some(ymd(2024, 2, 10)));
+    }
+
+    #[test]
+    fn computes_lunar_month_boundaries() {
+        let date = ymd(2024, 5, 17);
+
+        assert_eq!(beginning_of_month(&date), Some(ymd(2024, 5, 8)));
+        assert_eq!(end_of_month(&date), Some(ymd(2024, 6, 5)));
+        assert_eq!(next_month(&date), Some(ymd(2024, 6, 6)));
+        assert_eq!(previous_month(&date), Some(ymd(2024, 4, 9)));
+        assert_eq!(
+            beginning_of_month(&ymd(2024, 2, 10)),
+            Some(ymd(2024, 2, 10))
+        );
+    }
+
+    #[test]
+    fn finds_zodiac_animals() {
+        assert_eq!(ZodiacAnimal::for_year(2020), ZodiacAnimal::Rat);
+        assert_eq!(ZodiacAnimal::for_year(1900), ZodiacAnimal::Rat);
+        assert_eq!(zodiac(&ymd(2025, 1, 28)), Some(ZodiacAnimal::Dragon));
+        assert_eq!(zodiac(&ymd(2025, 1, 29)), Some(ZodiacAnimal::Snake));
+        assert_eq!(ZodiacAnimal::Snake.to_string(), "Snake");
+    }
+}
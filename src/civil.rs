@@ -0,0 +1,420 @@
+//! Gregorian date math as `const fn`s over `(year, month, day)` triples and Rata Die day
+//! numbers, so boundaries for fixed dates can be computed at compile time.
+//!
+//! These mirror the boundary functions at the crate root, with weeks beginning on Sunday, and
+//! return `None` for triples naming no date or results past the range of an `i32` year. They are
+//! not limited to the years chrono can represent; convert results with
+//! [`NaiveDate::from_ymd_opt`](chrono::NaiveDate::from_ymd_opt).
+//!
+//! ```
+//! use date_calculations::civil::{self, Ymd};
+//!
+//! const QUARTER_STARTS: [Option<Ymd>; 2] = [
+//!     civil::beginning_of_quarter((2024, 5, 17)),
+//!     civil::next_quarter((2024, 11, 5)),
+//! ];
+//!
+//! assert_eq!(QUARTER_STARTS, [Some((2024, 4, 1)), Some((2025, 1, 1))]);
+//! assert_eq!(civil::to_rata_die((2024, 1, 1)), Some(738_886));
+//! ```
+
+/// A proleptic Gregorian date as a `(year, month, day)` triple, with months from 1 through 12.
+pub type Ymd = (i32, u32, u32);
+
+// Days from 0000-03-01, where the computation's years begin, to Rata Die day 0
+const RATA_DIE_OFFSET: i64 = 305;
+
+const DAYS_IN_400_YEARS: i64 = 146_097;
+
+// Days before the start of each month in a common year, with the year's length last
+pub(crate) const DAYS_BEFORE_MONTH: [u32; 13] =
+    [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334, 365];
+
+// The first month of the quarter containing each month
+const QUARTER_START_MONTH: [u32; 12] = [1, 1, 1, 4, 4, 4, 7, 7, 7, 10, 10, 10];
+
+/// Returns whether the year has 366 days.
+pub const fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days in the month of the year, or `None` if the month is not between
+/// 1 and 12.
+pub const fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    if month > 12 {
+        return None;
+    }
+
+    match (
+        days_before_month(year, month),
+        days_before_month(year, month + 1),
+    ) {
+        (Some(before), Some(through)) => Some(through - before),
+        _ => None,
+    }
+}
+
+/// Returns the number of days in the year, 365 or 366.
+pub const fn days_in_year(year: i32) -> u32 {
+    DAYS_BEFORE_MONTH[12] + is_leap_year(year) as u32
+}
+
+/// Returns the number of days in the quarter (1 through 4) of the year, or `None` if the
+/// quarter is out of range.
+pub const fn days_in_quarter(year: i32, quarter: u32) -> Option<u32> {
+    if quarter < 1 || quarter > 4 {
+        return None;
+    }
+
+    match (
+        days_before_month(year, quarter * 3 - 2),
+        days_before_month(year, quarter * 3 + 1),
+    ) {
+        (Some(before), Some(through)) => Some(through - before),
+        _ => None,
+    }
+}
+
+/// Returns the number of days in the year before the first of the month, or `None` if the
+/// month is not between 1 and 12.
+///
+/// A month of 13 is accepted as the end of the year, returning the year's length.
+pub const fn days_before_month(year: i32, month: u32) -> Option<u32> {
+    if month < 1 || month > 13 {
+        return None;
+    }
+
+    let leap_day = (is_leap_year(year) && month > 2) as u32;
+    Some(DAYS_BEFORE_MONTH[month as usize - 1] + leap_day)
+}
+
+/// Returns the first month of the quarter containing the month, or `None` if the month is not
+/// between 1 and 12.
+pub const fn quarter_start_month(month: u32) -> Option<u32> {
+    if month < 1 || month > 12 {
+        None
+    } else {
+        Some(QUARTER_START_MONTH[month as usize - 1])
+    }
+}
+
+/// Returns whether the triple names a day of the proleptic Gregorian calendar.
+pub const fn is_valid(date: Ymd) -> bool {
+    let (year, month, day) = date;
+
+    match days_in_month(year, month) {
+        Some(days) => day >= 1 && day <= days,
+        None => false,
+    }
+}
+
+/// Returns the Rata Die of the date, where January 1 of year 1 is day 1, or `None` if the
+/// triple is not a valid date.
+///
+/// This is Howard Hinnant's `days_from_civil`, counting years from March so the leap day falls
+/// at the end of each.
+pub const fn to_rata_die(date: Ymd) -> Option<i64> {
+    if !is_valid(date) {
+        return None;
+    }
+
+    let (year, month, day) = date;
+    let year = year as i64 - (month <= 2) as i64;
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    Some(era * DAYS_IN_400_YEARS + day_of_era - RATA_DIE_OFFSET)
+}
+
+/// Returns the date with the Rata Die, or `None` if its year does not fit in an `i32`.
+///
+/// This is Howard Hinnant's `civil_from_days`.
+pub const fn from_rata_die(rata_die: i64) -> Option<Ymd> {
+    let days = match rata_die.checked_add(RATA_DIE_OFFSET) {
+        Some(days) => days,
+        None => return None,
+    };
+    let era = days.div_euclid(DAYS_IN_400_YEARS);
+    let day_of_era = days - era * DAYS_IN_400_YEARS;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = era * 400 + year_of_era + (month <= 2) as i64;
+
+    if year < i32::MIN as i64 || year > i32::MAX as i64 {
+        None
+    } else {
+        Some((year as i32, month as u32, day as u32))
+    }
+}
+
+/// Returns the number of days from the preceding Sunday to the Rata Die, from 0 through 6.
+pub const fn days_from_sunday(rata_die: i64) -> u32 {
+    // Rata Die 0 is a Sunday
+    rata_die.rem_euclid(7) as u32
+}
+
+// weeks
+
+/// Returns the beginning of the week (Sunday) containing the date.
+pub const fn beginning_of_week(date: Ymd) -> Option<Ymd> {
+    match to_rata_die(date) {
+        Some(rata_die) => from_rata_die(rata_die - days_from_sunday(rata_die) as i64),
+        None => None,
+    }
+}
+
+/// Returns the end of the week (Saturday) containing the date.
+pub const fn end_of_week(date: Ymd) -> Option<Ymd> {
+    shift_week_start(date, 6)
+}
+
+/// Returns the beginning of the next week.
+pub const fn next_week(date: Ymd) -> Option<Ymd> {
+    shift_week_start(date, 7)
+}
+
+/// Returns the beginning of the previous week.
+pub const fn previous_week(date: Ymd) -> Option<Ymd> {
+    shift_week_start(date, -7)
+}
+
+const fn shift_week_start(date: Ymd, days: i64) -> Option<Ymd> {
+    match to_rata_die(date) {
+        Some(rata_die) => from_rata_die(rata_die - days_from_sunday(rata_die) as i64 + days),
+        None => None,
+    }
+}
+
+// months
+
+/// Returns the first day of the date's month.
+pub const fn beginning_of_month(date: Ymd) -> Option<Ymd> {
+    let (year, month, _) = date;
+
+    if is_valid(date) {
+        Some((year, month, 1))
+    } else {
+        None
+    }
+}
+
+/// Returns the last day of the date's month.
+pub const fn end_of_month(date: Ymd) -> Option<Ymd> {
+    let (year, month, _) = date;
+
+    match days_in_month(year, month) {
+        Some(days) if is_valid(date) => Some((year, month, days)),
+        _ => None,
+    }
+}
+
+/// Returns the first day of the next month.
+pub const fn next_month(date: Ymd) -> Option<Ymd> {
+    first_of_month_after(date, date.1, 1)
+}
+
+/// Returns the first day of the previous month.
+pub const fn previous_month(date: Ymd) -> Option<Ymd> {
+    first_of_month_after(date, date.1, -1)
+}
+
+// quarters
+
+/// Returns the first day of the date's quarter.
+pub const fn beginning_of_quarter(date: Ymd) -> Option<Ymd> {
+    let (year, month, _) = date;
+
+    if is_valid(date) {
+        Some((year, QUARTER_START_MONTH[month as usize - 1], 1))
+    } else {
+        None
+    }
+}
+
+/// Returns the last day of the date's quarter.
+pub const fn end_of_quarter(date: Ymd) -> Option<Ymd> {
+    let (year, month, _) = date;
+
+    if is_valid(date) {
+        end_of_month((year, QUARTER_START_MONTH[month as usize - 1] + 2, 1))
+    } else {
+        None
+    }
+}
+
+/// Returns the first day of the next quarter.
+pub const fn next_quarter(date: Ymd) -> Option<Ymd> {
+    match quarter_start_month(date.1) {
+        Some(month) => first_of_month_after(date, month, 3),
+        None => None,
+    }
+}
+
+/// Returns the first day of the previous quarter.
+pub const fn previous_quarter(date: Ymd) -> Option<Ymd> {
+    match quarter_start_month(date.1) {
+        Some(month) => first_of_month_after(date, month, -3),
+        None => None,
+    }
+}
+
+// years
+
+/// Returns January 1 of the date's year.
+pub const fn beginning_of_year(date: Ymd) -> Option<Ymd> {
+    if is_valid(date) {
+        Some((date.0, 1, 1))
+    } else {
+        None
+    }
+}
+
+/// Returns December 31 of the date's year.
+pub const fn end_of_year(date: Ymd) -> Option<Ymd> {
+    if is_valid(date) {
+        Some((date.0, 12, 31))
+    } else {
+        None
+    }
+}
+
+/// Returns January 1 of the next year.
+pub const fn next_year(date: Ymd) -> Option<Ymd> {
+    first_of_month_after(date, 1, 12)
+}
+
+/// Returns January 1 of the previous year.
+pub const fn previous_year(date: Ymd) -> Option<Ymd> {
+    first_of_month_after(date, 1, -12)
+}
+
+// The first day of the month `months` after `month` of the date's year, counting months from
+// January of year 0 so the year needs no special case
+const fn first_of_month_after(date: Ymd, month: u32, months: i64) -> Option<Ymd> {
+    if !is_valid(date) {
+        return None;
+    }
+
+    let index = date.0 as i64 * 12 + month as i64 - 1 + months;
+    let year = index.div_euclid(12);
+
+    if year < i32::MIN as i64 || year > i32::MAX as i64 {
+        None
+    } else {
+        Some((year as i32, index.rem_euclid(12) as u32 + 1, 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use chrono::prelude::*;
+    use quickcheck_macros::quickcheck;
+
+    type Boundary = fn(&NaiveDate) -> Option<NaiveDate>;
+    type Civil = fn(Ymd) -> Option<Ymd>;
+
+    const BOUNDARIES: [(Boundary, Civil); 16] = [
+        (crate::beginning_of_week, beginning_of_week),
+        (crate::end_of_week, end_of_week),
+        (crate::next_week, next_week),
+        (crate::previous_week, previous_week),
+        (|d| Some(crate::beginning_of_month(d)), beginning_of_month),
+        (|d| Some(crate::end_of_month(d)), end_of_month),
+        (crate::next_month, next_month),
+        (crate::previous_month, previous_month),
+        (
+            |d| Some(crate::beginning_of_quarter(d)),
+            beginning_of_quarter,
+        ),
+        (|d| Some(crate::end_of_quarter(d)), end_of_quarter),
+        (crate::next_quarter, next_quarter),
+        (crate::previous_quarter, previous_quarter),
+        (|d| Some(crate::beginning_of_year(d)), beginning_of_year),
+        (|d| Some(crate::end_of_year(d)), end_of_year),
+        (crate::next_year, next_year),
+        (crate::previous_year, previous_year),
+    ];
+
+    fn ymd(date: &NaiveDate) -> Ymd {
+        (date.year(), date.month(), date.day())
+    }
+
+    #[quickcheck]
+    fn matches_the_boundary_functions(date: ArbitraryDate) -> bool {
+        BOUNDARIES
+            .iter()
+            .all(|(boundary, civil)| boundary(&date.0).map(|d| ymd(&d)) == civil(ymd(&date.0)))
+    }
+
+    #[quickcheck]
+    fn round_trips_through_rata_die(date: ArbitraryDate) -> bool {
+        let rata_die = i64::from(date.0.num_days_from_ce());
+
+        to_rata_die(ymd(&date.0)) == Some(rata_die) && from_rata_die(rata_die) == Some(ymd(&date.0))
+    }
+
+    #[test]
+    fn evaluates_at_compile_time() {
+        const END: Option<Ymd> = end_of_month((2024, 2, 3));
+        const WEEK: Option<Ymd> = beginning_of_week((2021, 1, 1));
+
+        assert_eq!(END, Some((2024, 2, 29)));
+        assert_eq!(WEEK, Some((2020, 12, 27)));
+    }
+
+    #[test]
+    fn rejects_invalid_dates_and_overflow() {
+        assert_eq!(to_rata_die((2023, 2, 29)), None);
+        assert_eq!(next_quarter((2024, 13, 1)), None);
+        assert_eq!(next_quarter((2024, 2, 30)), None);
+        assert_eq!(end_of_year((2024, 4, 31)), None);
+        assert_eq!(next_year((i32::MAX, 6, 1)), None);
+        assert_eq!(previous_month((i32::MIN, 1, 1)), None);
+        assert_eq!(from_rata_die(i64::MAX), None);
+        assert_eq!(days_in_month(1900, 2), Some(28));
+        assert_eq!(days_in_month(2000, 2), Some(29));
+        assert_eq!(days_in_month(2024, u32::MAX), None);
+        assert!(!is_valid((2024, u32::MAX, 1)));
+        assert_eq!(to_rata_die((2024, u32::MAX, 1)), None);
+        assert_eq!(end_of_month((2024, u32::MAX, u32::MAX)), None);
+    }
+
+    #[test]
+    fn numbers_years_astronomically() {
+        assert!(is_leap_year(0));
+        assert!(is_leap_year(-4));
+        assert!(!is_leap_year(-100));
+        assert!(is_leap_year(-400));
+        assert_eq!(to_rata_die((0, 12, 31)), Some(0));
+        assert_eq!(from_rata_die(-365), Some((0, 1, 1)));
+        assert_eq!(next_year((-1, 7, 4)), Some((0, 1, 1)));
+        assert_eq!(end_of_month((0, 2, 1)), Some((0, 2, 29)));
+    }
+
+    #[test]
+    fn looks_up_lengths_from_tables() {
+        assert_eq!(days_before_month(2024, 3), Some(60));
+        assert_eq!(days_before_month(2023, 13), Some(365));
+        assert_eq!(days_before_month(2023, 0), None);
+        assert_eq!(days_in_month(2023, 13), None);
+        assert_eq!(days_in_quarter(2024, 1), Some(91));
+        assert_eq!(days_in_quarter(2023, 4), Some(92));
+        assert_eq!(days_in_quarter(2023, 5), None);
+        assert_eq!(days_in_year(1900), 365);
+        assert_eq!(quarter_start_month(8), Some(7));
+        assert_eq!(quarter_start_month(0), None);
+    }
+}
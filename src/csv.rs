@@ -0,0 +1,130 @@
+//! CSV export of date series, with per-row columns derived from this crate's period functions.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::csv::{write_csv, Column};
+//! use date_calculations::{DateRange, Period};
+//!
+//! let range = DateRange::new(
+//!     NaiveDate::from_ymd_opt(2024, 3, 30).unwrap(),
+//!     NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(),
+//! )
+//! .unwrap();
+//!
+//! let mut output = vec![];
+//! write_csv(&mut output, range, &[Column::Date, Column::PeriodStart(Period::Quarter)]).unwrap();
+//!
+//! assert_eq!(
+//!     String::from_utf8(output).unwrap(),
+//!     "date,quarter_start\n2024-03-30,2024-01-01\n2024-03-31,2024-01-01\n2024-04-01,2024-04-01\n"
+//! );
+//! ```
+
+use crate::fiscal::{FiscalCalendar, FiscalGranularity};
+use crate::Period;
+use chrono::prelude::*;
+use std::io::{self, Write};
+
+/// A value derived from each date in a CSV export.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    /// The date itself, headed `date`.
+    Date,
+    /// The first day of the period containing the date, headed e.g. `month_start`.
+    PeriodStart(Period),
+    /// The last day of the period containing the date, headed e.g. `month_end`.
+    PeriodEnd(Period),
+    /// The ISO 8601 week number of the date, headed `iso_week`.
+    IsoWeek,
+    /// The fiscal quarter label of the date (e.g. `FY2025-Q1`), headed `fiscal_quarter`.
+    FiscalQuarter(FiscalCalendar),
+}
+
+impl Column {
+    fn header(&self) -> String {
+        match self {
+            Column::Date => "date".to_string(),
+            Column::PeriodStart(period) => format!("{}_start", period),
+            Column::PeriodEnd(period) => format!("{}_end", period),
+            Column::IsoWeek => "iso_week".to_string(),
+            Column::FiscalQuarter(_) => "fiscal_quarter".to_string(),
+        }
+    }
+
+    fn value(&self, date: &NaiveDate) -> String {
+        let format = |value: Option<NaiveDate>| value.map(|d| d.to_string()).unwrap_or_default();
+
+        match self {
+            Column::Date => date.to_string(),
+            Column::PeriodStart(period) => format(period.beginning(date)),
+            Column::PeriodEnd(period) => format(period.end(date)),
+            Column::IsoWeek => date.iso_week().week().to_string(),
+            Column::FiscalQuarter(calendar) => {
+                calendar.label(date, FiscalGranularity::Quarter).to_string()
+            }
+        }
+    }
+}
+
+/// Writes a header row followed by one row per date, with the requested columns.
+///
+/// Rows end with `\n`. A boundary that cannot be represented is written as an empty field.
+pub fn write_csv<W, I>(mut writer: W, dates: I, columns: &[Column]) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = NaiveDate>,
+{
+    let header: Vec<String> = columns.iter().map(Column::header).collect();
+    writeln!(writer, "{}", header.join(","))?;
+
+    for date in dates {
+        let row: Vec<String> = columns.iter().map(|c| c.value(&date)).collect();
+        writeln!(writer, "{}", row.join(","))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fiscal::FiscalYearLabel;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn writes_selected_columns() {
+        let fiscal = FiscalCalendar::new(10, FiscalYearLabel::EndYear).unwrap();
+        let mut output = vec![];
+
+        write_csv(
+            &mut output,
+            vec![ymd(2024, 9, 30), ymd(2024, 12, 31)],
+            &[
+                Column::Date,
+                Column::PeriodEnd(Period::Week),
+                Column::IsoWeek,
+                Column::FiscalQuarter(fiscal),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "date,week_end,iso_week,fiscal_quarter\n\
+             2024-09-30,2024-10-05,40,FY2024-Q4\n\
+             2024-12-31,2025-01-04,1,FY2025-Q1\n"
+        );
+    }
+
+    #[test]
+    fn writes_only_a_header_without_dates() {
+        let mut output = vec![];
+
+        write_csv(&mut output, vec![], &[Column::Date]).unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "date\n");
+    }
+}
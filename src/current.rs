@@ -0,0 +1,165 @@
+//! The boundaries of the periods containing today, computed once per day and shared between
+//! threads.
+//!
+//! [`CurrentPeriods`] keeps a [`PeriodBoundaries`] snapshot for the clock's current date and
+//! replaces it the first time it is read on a new day, so a server can answer "the start of this
+//! month" on every request without recomputing it or tracking midnight itself. The clock is
+//! injectable; [`SystemClock`] reads the local date with the `clock` feature, and any
+//! `Fn() -> NaiveDate` can stand in for tests.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::current::CurrentPeriods;
+//! use std::sync::atomic::{AtomicU32, Ordering};
+//!
+//! let day = AtomicU32::new(31);
+//! let periods = CurrentPeriods::new(|| {
+//!     NaiveDate::from_ymd_opt(2024, 1, 1).unwrap() + chrono::Days::new(day.load(Ordering::SeqCst).into())
+//! });
+//!
+//! assert_eq!(periods.get().month_start, NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+//!
+//! day.store(60, Ordering::SeqCst);
+//! assert_eq!(periods.get().month_start, NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+//! ```
+
+use crate::fiscal::FiscalCalendar;
+use crate::PeriodBoundaries;
+use chrono::prelude::*;
+use std::sync::{PoisonError, RwLock};
+
+/// A source of the current date.
+pub trait Clock {
+    /// Returns today's date.
+    fn today(&self) -> NaiveDate;
+}
+
+impl<F: Fn() -> NaiveDate> Clock for F {
+    fn today(&self) -> NaiveDate {
+        self()
+    }
+}
+
+/// The local date of the system clock, enabled with the `clock` feature.
+#[cfg(feature = "clock")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+#[cfg(feature = "clock")]
+impl Clock for SystemClock {
+    fn today(&self) -> NaiveDate {
+        Local::now().date_naive()
+    }
+}
+
+/// The boundaries of the periods containing the clock's current date, refreshed when the date
+/// changes.
+///
+/// The snapshot sits behind a lock, so a single value can be shared between threads (within an
+/// `Arc` or a `static`) when the clock is `Send` and `Sync`.
+#[derive(Debug)]
+pub struct CurrentPeriods<C> {
+    clock: C,
+    week_start: Weekday,
+    fiscal: Option<FiscalCalendar>,
+    snapshot: RwLock<PeriodBoundaries>,
+}
+
+impl<C: Clock> CurrentPeriods<C> {
+    /// Snapshots today's boundaries for weeks beginning on Sunday, without fiscal boundaries.
+    pub fn new(clock: C) -> Self {
+        CurrentPeriods::with_calendars(clock, Weekday::Sun, None)
+    }
+
+    /// Snapshots today's boundaries for weeks beginning on `week_start`, including the fiscal
+    /// boundaries of the fiscal calendar when one is given.
+    pub fn with_calendars(clock: C, week_start: Weekday, fiscal: Option<FiscalCalendar>) -> Self {
+        let today = clock.today();
+
+        CurrentPeriods {
+            snapshot: RwLock::new(PeriodBoundaries::with_calendars(
+                &today,
+                week_start,
+                fiscal.as_ref(),
+            )),
+            clock,
+            week_start,
+            fiscal,
+        }
+    }
+
+    /// Returns the clock.
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Returns the boundaries for today, recomputing them if the date has changed since they
+    /// were last computed.
+    pub fn get(&self) -> PeriodBoundaries {
+        // a poisoned snapshot is still a complete set of boundaries, since it is replaced whole
+        let today = self.clock.today();
+        let snapshot = *self.snapshot.read().unwrap_or_else(PoisonError::into_inner);
+        if snapshot.date == today {
+            return snapshot;
+        }
+
+        let refreshed =
+            PeriodBoundaries::with_calendars(&today, self.week_start, self.fiscal.as_ref());
+        *self
+            .snapshot
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = refreshed;
+        refreshed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fiscal::FiscalYearLabel;
+    use std::sync::atomic::{AtomicI64, Ordering};
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    struct SteppedClock(AtomicI64);
+
+    impl Clock for SteppedClock {
+        fn today(&self) -> NaiveDate {
+            ymd(2024, 3, 31) + chrono::Duration::days(self.0.load(Ordering::SeqCst))
+        }
+    }
+
+    #[test]
+    fn refreshes_when_the_date_changes() {
+        let fiscal = FiscalCalendar::new(4, FiscalYearLabel::StartYear).unwrap();
+        let periods = CurrentPeriods::with_calendars(
+            SteppedClock(AtomicI64::new(0)),
+            Weekday::Mon,
+            Some(fiscal),
+        );
+
+        assert_eq!(periods.get().quarter_end, ymd(2024, 3, 31));
+        assert_eq!(periods.get().fiscal_year_start, Some(ymd(2023, 4, 1)));
+
+        periods.clock().0.store(1, Ordering::SeqCst);
+        let boundaries = periods.get();
+
+        assert_eq!(boundaries.date, ymd(2024, 4, 1));
+        assert_eq!(boundaries.quarter_start, ymd(2024, 4, 1));
+        assert_eq!(boundaries.week_start, Some(ymd(2024, 4, 1)));
+        assert_eq!(boundaries.fiscal_year_start, Some(ymd(2024, 4, 1)));
+    }
+
+    #[test]
+    fn shares_between_threads() {
+        let periods = CurrentPeriods::new(SteppedClock(AtomicI64::new(0)));
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| assert_eq!(periods.get().month_start, ymd(2024, 3, 1)));
+            }
+        });
+    }
+}
@@ -0,0 +1,460 @@
+//! Reporting calendars of arbitrary named periods, such as a finance team's closing periods,
+//! enabled with the `alloc` feature.
+//!
+//! A [`CustomPeriodCalendar`] holds periods that follow no rule, so they are listed outright,
+//! either in code or in a configuration file through [`from_toml`](CustomPeriodCalendar::from_toml)
+//! or [`from_json`](CustomPeriodCalendar::from_json). The periods must not overlap, but may leave
+//! gaps between them.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::custom_period::CustomPeriodCalendar;
+//!
+//! let calendar = CustomPeriodCalendar::from_toml(
+//!     r#"
+//!     [[period]]
+//!     name = "P01 2025"
+//!     start = 2025-01-04
+//!     end = 2025-01-31
+//!
+//!     [[period]]
+//!     name = "P02 2025"
+//!     start = 2025-02-01
+//!     end = 2025-03-01
+//!     "#,
+//! )
+//! .unwrap();
+//!
+//! let date = NaiveDate::from_ymd_opt(2025, 1, 20).unwrap();
+//! assert_eq!(calendar.period_containing(&date).unwrap().name(), "P01 2025");
+//! assert_eq!(calendar.next_period(&date).unwrap().name(), "P02 2025");
+//! assert_eq!(
+//!     calendar.period_range("P02 2025").unwrap().to_string(),
+//!     "2025-02-01/2025-03-01"
+//! );
+//! ```
+
+use crate::{DateRange, ParseError};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use chrono::prelude::*;
+
+const EXPECTED_TOML: &str =
+    "[[period]] tables with a quoted name, and a start and end date like 2025-01-04";
+const EXPECTED_JSON: &str =
+    "an array of objects like {\"name\": \"P01 2025\", \"start\": \"2025-01-04\", \"end\": \"2025-01-31\"}";
+const EXPECTED_PERIODS: &str = "periods with unique names that do not overlap";
+
+/// A named period of a [`CustomPeriodCalendar`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CustomPeriod {
+    name: String,
+    range: DateRange,
+}
+
+impl CustomPeriod {
+    /// Builds a period named `name` covering the range.
+    pub fn new(name: impl Into<String>, range: DateRange) -> Self {
+        CustomPeriod {
+            name: name.into(),
+            range,
+        }
+    }
+
+    /// Returns the name of the period.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the range of dates within the period.
+    pub fn range(&self) -> DateRange {
+        self.range
+    }
+}
+
+/// A calendar of named periods listed outright, in order of their dates.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CustomPeriodCalendar {
+    periods: Vec<CustomPeriod>,
+}
+
+impl CustomPeriodCalendar {
+    /// Builds a calendar from the periods, in any order.
+    ///
+    /// Returns `None` if two periods overlap or share a name.
+    pub fn new(mut periods: Vec<CustomPeriod>) -> Option<Self> {
+        periods.sort_by_key(|period| period.range);
+
+        let overlapping = periods
+            .windows(2)
+            .any(|pair| pair[1].range.start() <= pair[0].range.end());
+        let mut names: Vec<&str> = periods.iter().map(CustomPeriod::name).collect();
+        names.sort_unstable();
+        let repeated = names.windows(2).any(|pair| pair[0] == pair[1]);
+
+        if overlapping || repeated {
+            None
+        } else {
+            Some(CustomPeriodCalendar { periods })
+        }
+    }
+
+    /// Parses a calendar from TOML listing each period in a `[[period]]` table.
+    ///
+    /// Each table holds a `name` and a `start` and `end` date, written as TOML local dates or
+    /// quoted. Quoted values are basic strings, which may use the escapes `\"`, `\\`, and `\/`.
+    /// Only this subset of TOML is understood: comments and blank lines are skipped, and any other
+    /// table or key is an error.
+    pub fn from_toml(input: &str) -> Result<Self, ParseError> {
+        let mut periods = Vec::new();
+        let mut fields: Option<Fields> = None;
+
+        for line in input.lines() {
+            let error = || ParseError::new(line, EXPECTED_TOML);
+            let content = without_comment(line).trim();
+
+            if content.is_empty() {
+                continue;
+            }
+            if content == "[[period]]" {
+                if let Some(fields) = fields.take() {
+                    periods.push(fields.finish(line, EXPECTED_TOML)?);
+                }
+                fields = Some(Fields::default());
+                continue;
+            }
+
+            let (key, value) = content.split_once('=').ok_or_else(error)?;
+            let (key, value) = (key.trim(), value.trim());
+            let value = if value.starts_with('"') {
+                let mut string = Json(value);
+                let value = string.string().ok_or_else(error)?;
+                if !string.0.trim().is_empty() {
+                    return Err(error());
+                }
+                value
+            } else if key == "name" {
+                return Err(error());
+            } else {
+                value.to_string()
+            };
+
+            fields
+                .as_mut()
+                .ok_or_else(error)?
+                .set(key, &value)
+                .ok_or_else(error)?;
+        }
+
+        if let Some(fields) = fields {
+            periods.push(fields.finish(input, EXPECTED_TOML)?);
+        }
+
+        CustomPeriodCalendar::new(periods).ok_or_else(|| ParseError::new(input, EXPECTED_PERIODS))
+    }
+
+    /// Parses a calendar from a JSON array of objects, each with a `name`, a `start` date, and
+    /// an `end` date as strings.
+    ///
+    /// Only this shape of JSON is understood; strings may use the escapes `\"`, `\\`, and `\/`.
+    pub fn from_json(input: &str) -> Result<Self, ParseError> {
+        let error = |rest: &str| ParseError::new(rest, EXPECTED_JSON);
+        let mut json = Json(input);
+        let mut periods = Vec::new();
+
+        json.expect('[').ok_or_else(|| error(json.0))?;
+        if !json.peek(']') {
+            loop {
+                json.expect('{').ok_or_else(|| error(json.0))?;
+                let object = json.0;
+                let mut fields = Fields::default();
+
+                loop {
+                    let key = json.string().ok_or_else(|| error(json.0))?;
+                    json.expect(':').ok_or_else(|| error(json.0))?;
+                    let value = json.string().ok_or_else(|| error(json.0))?;
+                    fields.set(&key, &value).ok_or_else(|| error(object))?;
+
+                    if json.expect('}').is_some() {
+                        break;
+                    }
+                    json.expect(',').ok_or_else(|| error(json.0))?;
+                }
+
+                periods.push(fields.finish(object, EXPECTED_JSON)?);
+                if json.expect(',').is_none() {
+                    break;
+                }
+            }
+        }
+        json.expect(']').ok_or_else(|| error(json.0))?;
+        if !json.0.trim().is_empty() {
+            return Err(error(json.0));
+        }
+
+        CustomPeriodCalendar::new(periods).ok_or_else(|| ParseError::new(input, EXPECTED_PERIODS))
+    }
+
+    /// Returns the periods, in order.
+    pub fn periods(&self) -> &[CustomPeriod] {
+        &self.periods
+    }
+
+    /// Returns the period containing the date, or `None` if it falls outside every period.
+    pub fn period_containing(&self, date: &NaiveDate) -> Option<&CustomPeriod> {
+        let index = self.ending_before(date);
+
+        self.periods
+            .get(index)
+            .filter(|period| period.range.contains(date))
+    }
+
+    /// Returns the first period beginning after the date.
+    pub fn next_period(&self, date: &NaiveDate) -> Option<&CustomPeriod> {
+        let index = self
+            .periods
+            .partition_point(|period| period.range.start() <= *date);
+
+        self.periods.get(index)
+    }
+
+    /// Returns the last period ending before the date.
+    pub fn previous_period(&self, date: &NaiveDate) -> Option<&CustomPeriod> {
+        let index = self.ending_before(date);
+
+        index
+            .checked_sub(1)
+            .and_then(|index| self.periods.get(index))
+    }
+
+    /// Returns the range of the period with the name.
+    pub fn period_range(&self, name: &str) -> Option<DateRange> {
+        self.periods
+            .iter()
+            .find(|period| period.name == name)
+            .map(|period| period.range)
+    }
+
+    // The number of periods ending before the date
+    fn ending_before(&self, date: &NaiveDate) -> usize {
+        self.periods
+            .partition_point(|period| period.range.end() < *date)
+    }
+}
+
+// The line up to a `#` outside quotes
+fn without_comment(line: &str) -> &str {
+    let mut quoted = false;
+    let mut escaped = false;
+
+    for (index, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..index],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+// The fields of a period as they are read from configuration
+#[derive(Default)]
+struct Fields {
+    name: Option<String>,
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+}
+
+impl Fields {
+    fn set(&mut self, key: &str, value: &str) -> Option<()> {
+        match key {
+            "name" if self.name.is_none() => self.name = Some(value.to_string()),
+            "start" if self.start.is_none() => self.start = Some(value.parse().ok()?),
+            "end" if self.end.is_none() => self.end = Some(value.parse().ok()?),
+            _ => return None,
+        }
+
+        Some(())
+    }
+
+    fn finish(self, input: &str, expected: &'static str) -> Result<CustomPeriod, ParseError> {
+        let error = || ParseError::new(input, expected);
+        let range = DateRange::new(self.start.ok_or_else(error)?, self.end.ok_or_else(error)?)
+            .ok_or_else(error)?;
+
+        Ok(CustomPeriod::new(self.name.ok_or_else(error)?, range))
+    }
+}
+
+// The unread remainder of a JSON input
+struct Json<'a>(&'a str);
+
+impl Json<'_> {
+    fn peek(&self, token: char) -> bool {
+        self.0.trim_start().starts_with(token)
+    }
+
+    fn expect(&mut self, token: char) -> Option<()> {
+        self.0 = self.0.trim_start().strip_prefix(token)?;
+        Some(())
+    }
+
+    fn string(&mut self) -> Option<String> {
+        self.expect('"')?;
+        let mut value = String::new();
+        let mut chars = self.0.char_indices();
+
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.0 = &self.0[index + 1..];
+                    return Some(value);
+                }
+                '\\' => match chars.next()?.1 {
+                    escaped @ ('"' | '\\' | '/') => value.push(escaped),
+                    _ => return None,
+                },
+                c => value.push(c),
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn period(name: &str, start: NaiveDate, end: NaiveDate) -> CustomPeriod {
+        CustomPeriod::new(name, DateRange::new(start, end).unwrap())
+    }
+
+    #[test]
+    fn navigates_between_periods_with_gaps() {
+        let calendar = CustomPeriodCalendar::new(vec![
+            period("P02", ymd(2025, 2, 1), ymd(2025, 2, 28)),
+            period("P01", ymd(2025, 1, 4), ymd(2025, 1, 31)),
+            period("P03", ymd(2025, 3, 10), ymd(2025, 4, 4)),
+        ])
+        .unwrap();
+        let name = |period: Option<&CustomPeriod>| period.map(|period| period.name().to_string());
+
+        assert_eq!(calendar.periods()[0].name(), "P01");
+        assert_eq!(
+            name(calendar.period_containing(&ymd(2025, 2, 28))),
+            Some("P02".into())
+        );
+        assert_eq!(name(calendar.period_containing(&ymd(2025, 3, 5))), None);
+        assert_eq!(name(calendar.period_containing(&ymd(2025, 1, 1))), None);
+        assert_eq!(
+            name(calendar.next_period(&ymd(2025, 3, 5))),
+            Some("P03".into())
+        );
+        assert_eq!(name(calendar.next_period(&ymd(2025, 3, 10))), None);
+        assert_eq!(
+            name(calendar.previous_period(&ymd(2025, 3, 10))),
+            Some("P02".into())
+        );
+        assert_eq!(name(calendar.previous_period(&ymd(2025, 1, 31))), None);
+        assert_eq!(calendar.period_range("P04"), None);
+    }
+
+    #[test]
+    fn rejects_overlapping_or_repeated_periods() {
+        assert_eq!(
+            CustomPeriodCalendar::new(vec![
+                period("P01", ymd(2025, 1, 1), ymd(2025, 1, 31)),
+                period("P02", ymd(2025, 1, 31), ymd(2025, 2, 28)),
+            ]),
+            None
+        );
+        assert_eq!(
+            CustomPeriodCalendar::new(vec![
+                period("P01", ymd(2025, 1, 1), ymd(2025, 1, 31)),
+                period("P01", ymd(2025, 2, 1), ymd(2025, 2, 28)),
+            ]),
+            None
+        );
+    }
+
+    #[test]
+    fn parses_toml_with_comments() {
+        let calendar = CustomPeriodCalendar::from_toml(
+            "# closing periods\n[[period]]\nname = \"Close #1\" # first\nstart = \"2025-01-04\"\nend = 2025-01-31\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            calendar.period_range("Close #1"),
+            DateRange::new(ymd(2025, 1, 4), ymd(2025, 1, 31))
+        );
+    }
+
+    #[test]
+    fn parses_json() {
+        let calendar = CustomPeriodCalendar::from_json(
+            r#"[
+                {"name": "P01 \"Jan\"", "start": "2025-01-04", "end": "2025-01-31"},
+                {"start": "2025-02-01", "end": "2025-03-01", "name": "P02"}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(calendar.periods()[0].name(), "P01 \"Jan\"");
+        assert_eq!(
+            calendar.period_range("P02"),
+            DateRange::new(ymd(2025, 2, 1), ymd(2025, 3, 1))
+        );
+        assert_eq!(
+            CustomPeriodCalendar::from_json("[]"),
+            Ok(CustomPeriodCalendar::new(vec![]).unwrap())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_configuration() {
+        for input in &[
+            r#"[{"name": "P01", "start": "2025-01-04"}]"#,
+            r#"[{"name": "P01", "start": "2025-01-04", "end": "2025-01-31", "end": "2025-02-01"}]"#,
+            r#"[{"name": "P01", "start": "2025-01-04", "end": "2025-01-31"},]"#,
+            r#"[{"name": 1, "start": "2025-01-04", "end": "2025-01-31"}]"#,
+            r#"[{"name": "P01", "start": "2025-02-04", "end": "2025-01-31"}]"#,
+        ] {
+            assert!(CustomPeriodCalendar::from_json(input).is_err(), "{}", input);
+        }
+
+        for input in &[
+            "name = \"P01\"\nstart = 2025-01-04\nend = 2025-01-31",
+            "[[period]]\nname = \"P01\"\nstart = 2025-01-04\nfinish = 2025-01-31",
+            "[[period]]\nname = \"P01\"\nstart = 2025-01-04",
+            "[[periods]]\nname = \"P01\"\nstart = 2025-01-04\nend = 2025-01-31",
+            "[[period]]\nname = \"P01\nstart = 2025-01-04\nend = 2025-01-31",
+            "[[period]]\nname = \"A\" junk \"B\"\nstart = 2025-01-04\nend = 2025-01-31",
+            "[[period]]\nname = P01\nstart = 2025-01-04\nend = 2025-01-31",
+            "[[period]]\nname = \"P\\q\"\nstart = 2025-01-04\nend = 2025-01-31",
+            "[[period]]\nname = \"P01\"\nstart = \"2025-01-04\nend = 2025-01-31",
+        ] {
+            assert!(CustomPeriodCalendar::from_toml(input).is_err(), "{}", input);
+        }
+
+        let error =
+            CustomPeriodCalendar::from_toml("[[period]]\nname = \"P01\"\nstart = 2025-13-04")
+                .unwrap_err();
+        assert_eq!(error.input(), "start = 2025-13-04");
+
+        let toml =
+            "[[period]]\nname = \"P\\\"01 # \\\\\" # q\nstart = 2025-01-04\nend = 2025-01-31";
+        let calendar = CustomPeriodCalendar::from_toml(toml).unwrap();
+        assert_eq!(calendar.periods()[0].name(), "P\"01 # \\");
+    }
+}
@@ -0,0 +1,72 @@
+//! Conversions between dates and continuous day counts: the astronomical Julian Day Number and
+//! the Rata Die count used by Reingold and Dershowitz's *Calendrical Calculations*.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::day_number::{from_julian_day_number, to_julian_day_number, to_rata_die};
+//!
+//! let date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+//!
+//! assert_eq!(to_julian_day_number(&date), 2_451_545);
+//! assert_eq!(to_rata_die(&date), 730_120);
+//! assert_eq!(from_julian_day_number(2_451_545), Some(date));
+//! ```
+
+use chrono::prelude::*;
+use core::convert::TryFrom;
+
+const JULIAN_DAY_NUMBER_OF_RATA_DIE_ZERO: i64 = 1_721_425;
+
+/// Returns the Julian Day Number of the date: the number of days since Monday, January 1,
+/// 4713 BC in the proleptic Julian calendar, counting the date's noon.
+pub fn to_julian_day_number(date: &NaiveDate) -> i64 {
+    to_rata_die(date) + JULIAN_DAY_NUMBER_OF_RATA_DIE_ZERO
+}
+
+/// Returns the date with the provided Julian Day Number, or `None` if it cannot be represented.
+pub fn from_julian_day_number(jdn: i64) -> Option<NaiveDate> {
+    from_rata_die(jdn.checked_sub(JULIAN_DAY_NUMBER_OF_RATA_DIE_ZERO)?)
+}
+
+/// Returns the Rata Die (fixed day number) of the date, where January 1 of year 1 in the
+/// proleptic Gregorian calendar is day 1.
+pub fn to_rata_die(date: &NaiveDate) -> i64 {
+    i64::from(date.num_days_from_ce())
+}
+
+/// Returns the date with the provided Rata Die, or `None` if it cannot be represented.
+pub fn from_rata_die(rata_die: i64) -> Option<NaiveDate> {
+    NaiveDate::from_num_days_from_ce_opt(i32::try_from(rata_die).ok()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn converts_known_day_numbers() {
+        assert_eq!(to_julian_day_number(&ymd(-4713, 11, 24)), 0);
+        assert_eq!(to_julian_day_number(&ymd(1858, 11, 17)), 2_400_001);
+        assert_eq!(to_rata_die(&ymd(1, 1, 1)), 1);
+        assert_eq!(to_rata_die(&ymd(0, 12, 31)), 0);
+        assert_eq!(from_rata_die(738_886), Some(ymd(2024, 1, 1)));
+    }
+
+    #[test]
+    fn rejects_unrepresentable_day_numbers() {
+        assert_eq!(from_rata_die(i64::MAX), None);
+        assert_eq!(from_julian_day_number(i64::MIN), None);
+    }
+
+    #[quickcheck]
+    fn round_trips_through_day_numbers(date: ArbitraryDate) -> bool {
+        from_julian_day_number(to_julian_day_number(&date.0)) == Some(date.0)
+            && from_rata_die(to_rata_die(&date.0)) == Some(date.0)
+    }
+}
@@ -0,0 +1,112 @@
+//! Dekads, the three roughly ten-day periods of each month: days 1–10, 11–20, and 21 through
+//! the end of the month.
+//!
+//! Agro-meteorological datasets, such as the FAO's, report rainfall and crop conditions by dekad,
+//! numbering them 1 through 36 within the year.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::dekad::*;
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 2, 25).unwrap();
+//!
+//! assert_eq!(dekad_of_year(&date), 6);
+//! assert_eq!(beginning_of_dekad(&date), NaiveDate::from_ymd_opt(2024, 2, 21).unwrap());
+//! assert_eq!(end_of_dekad(&date), NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+//! assert_eq!(next_dekad(&date), NaiveDate::from_ymd_opt(2024, 3, 1));
+//! ```
+
+use crate::{days_in_month, DateRange};
+use chrono::prelude::*;
+
+/// Returns the dekad (1 through 3) of the month containing the provided date.
+pub fn dekad_of_month(date: &NaiveDate) -> u32 {
+    1 + date.day0().min(20) / 10
+}
+
+/// Returns the dekad (1 through 36) of the year containing the provided date.
+pub fn dekad_of_year(date: &NaiveDate) -> u32 {
+    date.month0() * 3 + dekad_of_month(date)
+}
+
+/// Returns the first day of the dekad: the 1st, 11th, or 21st of the month.
+pub fn beginning_of_dekad(date: &NaiveDate) -> NaiveDate {
+    date.with_day(first_day(dekad_of_month(date)))
+        .expect("dekads begin within the month")
+}
+
+/// Returns the last day of the dekad: the 10th, the 20th, or the end of the month.
+pub fn end_of_dekad(date: &NaiveDate) -> NaiveDate {
+    let last = match dekad_of_month(date) {
+        3 => days_in_month(date),
+        dekad => dekad * 10,
+    };
+
+    date.with_day(last).expect("dekads end within the month")
+}
+
+/// Returns the first day of the next dekad.
+///
+/// Returns `None` if the next dekad begins after `NaiveDate::MAX`.
+pub fn next_dekad(date: &NaiveDate) -> Option<NaiveDate> {
+    end_of_dekad(date).succ_opt()
+}
+
+/// Returns the first day of the previous dekad.
+///
+/// Returns `None` if the previous dekad begins before `NaiveDate::MIN`.
+pub fn previous_dekad(date: &NaiveDate) -> Option<NaiveDate> {
+    Some(beginning_of_dekad(&beginning_of_dekad(date).pred_opt()?))
+}
+
+/// Returns the range of the dekad (1 through 36) of the year.
+pub fn dekad(year: i32, number: u32) -> Option<DateRange> {
+    if !(1..=36).contains(&number) {
+        return None;
+    }
+
+    let month = 1 + (number - 1) / 3;
+    let start = NaiveDate::from_ymd_opt(year, month, first_day(1 + (number - 1) % 3))?;
+
+    DateRange::new(start, end_of_dekad(&start))
+}
+
+fn first_day(dekad: u32) -> u32 {
+    (dekad - 1) * 10 + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[quickcheck]
+    fn dekads_contain_their_dates(date: ArbitraryDate) -> bool {
+        let date = date.0;
+        let range = dekad(date.year(), dekad_of_year(&date)).unwrap();
+
+        range.start() == beginning_of_dekad(&date)
+            && range.end() == end_of_dekad(&date)
+            && range.contains(&date)
+            && next_dekad(&date) == end_of_dekad(&date).succ_opt()
+            && previous_dekad(&next_dekad(&date).unwrap()) == Some(beginning_of_dekad(&date))
+    }
+
+    #[test]
+    fn splits_months_into_three() {
+        assert_eq!(dekad_of_month(&ymd(2023, 2, 10)), 1);
+        assert_eq!(dekad_of_month(&ymd(2023, 2, 11)), 2);
+        assert_eq!(dekad_of_month(&ymd(2023, 1, 31)), 3);
+        assert_eq!(end_of_dekad(&ymd(2023, 2, 21)), ymd(2023, 2, 28));
+        assert_eq!(dekad_of_year(&ymd(2023, 12, 31)), 36);
+        assert_eq!(previous_dekad(&ymd(2024, 1, 5)), Some(ymd(2023, 12, 21)));
+        assert_eq!(dekad(2023, 0), None);
+        assert_eq!(dekad(2023, 37), None);
+        assert_eq!(next_dekad(&NaiveDate::MAX), None);
+    }
+}
@@ -0,0 +1,449 @@
+use crate::{end_of_month, ParseError};
+use chrono::prelude::*;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+const EXPECTED: &str = "an ISO 8601 date duration like \"P1Y2M10D\" or \"P3W\"";
+
+/// How to resolve a day of month that does not exist after shifting by months.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MonthOverflow {
+    /// Clamp to the last day of the resulting month (January 31 plus one month is the end of
+    /// February).
+    Clamp,
+    /// Carry the extra days into the following month (January 31, 2023 plus one month is March
+    /// 3, 2023).
+    Spill,
+}
+
+/// A duration expressed in calendar units, as found in ISO 8601 strings like `P1Y2M10D` or `P3W`.
+///
+/// Unlike `chrono::Duration`, this can represent months and years, whose length depends on the
+/// date they are applied to. Years and months are applied first, followed by weeks and days.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::{CalendarDuration, MonthOverflow};
+///
+/// let duration: CalendarDuration = "P1M".parse().unwrap();
+/// let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+///
+/// assert_eq!(
+///     duration.add_to(&date, MonthOverflow::Clamp),
+///     NaiveDate::from_ymd_opt(2024, 2, 29)
+/// );
+/// assert_eq!(
+///     duration.add_to(&date, MonthOverflow::Spill),
+///     NaiveDate::from_ymd_opt(2024, 3, 2)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CalendarDuration {
+    years: u32,
+    months: u32,
+    weeks: u32,
+    days: u32,
+}
+
+impl CalendarDuration {
+    /// Builds a duration from its components.
+    pub fn new(years: u32, months: u32, weeks: u32, days: u32) -> Self {
+        CalendarDuration {
+            years,
+            months,
+            weeks,
+            days,
+        }
+    }
+
+    /// Returns the number of years.
+    pub fn years(&self) -> u32 {
+        self.years
+    }
+
+    /// Returns the number of months.
+    pub fn months(&self) -> u32 {
+        self.months
+    }
+
+    /// Returns the number of weeks.
+    pub fn weeks(&self) -> u32 {
+        self.weeks
+    }
+
+    /// Returns the number of days.
+    pub fn days(&self) -> u32 {
+        self.days
+    }
+
+    /// Adds the duration to the provided date.
+    pub fn add_to(&self, date: &NaiveDate, overflow: MonthOverflow) -> Option<NaiveDate> {
+        self.apply(date, 1, overflow)
+    }
+
+    /// Subtracts the duration from the provided date.
+    pub fn subtract_from(&self, date: &NaiveDate, overflow: MonthOverflow) -> Option<NaiveDate> {
+        self.apply(date, -1, overflow)
+    }
+
+    fn apply(&self, date: &NaiveDate, sign: i64, overflow: MonthOverflow) -> Option<NaiveDate> {
+        let months = sign * (i64::from(self.years) * 12 + i64::from(self.months));
+        let days = sign * (i64::from(self.weeks) * 7 + i64::from(self.days));
+
+        shift_months(date, months, overflow)?.checked_add_signed(chrono::Duration::days(days))
+    }
+}
+
+/// Signed calendar components for [`advance`], mirroring the options of ActiveSupport's
+/// `Date#advance`.
+///
+/// Components left at their default of zero do nothing, so fields can be named selectively with
+/// struct update syntax.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Advance {
+    /// Years to move, backward when negative.
+    pub years: i32,
+    /// Months to move, backward when negative.
+    pub months: i32,
+    /// Weeks to move, backward when negative.
+    pub weeks: i32,
+    /// Days to move, backward when negative.
+    pub days: i32,
+}
+
+/// Moves the date by each component of `by` in turn: years, then months, then weeks, then days.
+///
+/// As in ActiveSupport, the day is clamped to the end of shorter months after the years and again
+/// after the months, so February 29 advanced by a year and a month lands on March 28 rather than
+/// March 29. Returns `None` if any step leaves chrono's range.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::{advance, Advance};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+///
+/// assert_eq!(
+///     advance(&date, Advance { months: 1, days: 3, ..Advance::default() }),
+///     NaiveDate::from_ymd_opt(2024, 3, 3)
+/// );
+/// assert_eq!(
+///     advance(&date, Advance { years: -1, weeks: 2, ..Advance::default() }),
+///     NaiveDate::from_ymd_opt(2023, 2, 14)
+/// );
+/// ```
+pub fn advance(date: &NaiveDate, by: Advance) -> Option<NaiveDate> {
+    let date = shift_months(date, i64::from(by.years) * 12, MonthOverflow::Clamp)?;
+    let date = shift_months(&date, i64::from(by.months), MonthOverflow::Clamp)?;
+    let days = i64::from(by.weeks) * 7 + i64::from(by.days);
+
+    date.checked_add_signed(chrono::Duration::days(days))
+}
+
+/// The difference between two dates in whole years, whole months, and remaining days, from
+/// [`calendar_diff`].
+///
+/// Every component has the sign of the difference, and the months are always between -11 and
+/// 11.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CalendarDiff {
+    /// Whole years.
+    pub years: i32,
+    /// Whole months beyond the years.
+    pub months: i32,
+    /// Days beyond the years and months.
+    pub days: i32,
+}
+
+impl From<CalendarDiff> for Advance {
+    fn from(diff: CalendarDiff) -> Self {
+        // the months were counted from the start in one step, so the years must not clamp first
+        Advance {
+            years: 0,
+            months: diff.years * 12 + diff.months,
+            weeks: 0,
+            days: diff.days,
+        }
+    }
+}
+
+/// Breaks the time from `from` to `to` into years, months, and days, so that advancing `from` by
+/// the result lands on `to`.
+///
+/// The most whole months that can be added to `from` without passing `to` are split into years
+/// and months (up to 11), and the days are whatever remains, so there are always fewer days than
+/// in the month that follows. The months are added in one step, clamping to the end of a shorter
+/// month only once, and converting into an [`Advance`] keeps them together for the same reason.
+/// January 31 to March 1, 2023 is therefore one month (to February 28) and one day, and February
+/// 29, 2024 to February 28, 2028 is three years and eleven months (to January 29, 2028) and 30
+/// days.
+///
+/// When `to` precedes `from` the same steps run backward and every component is negative; since
+/// clamping depends on the direction, the difference from `to` back to `from` is not always the
+/// negation.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::{advance, calendar_diff, CalendarDiff};
+///
+/// let from = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+/// let to = NaiveDate::from_ymd_opt(2025, 4, 12).unwrap();
+/// let diff = calendar_diff(&from, &to);
+///
+/// assert_eq!(diff, CalendarDiff { years: 2, months: 2, days: 12 });
+/// assert_eq!(advance(&from, diff.into()), Some(to));
+/// ```
+pub fn calendar_diff(from: &NaiveDate, to: &NaiveDate) -> CalendarDiff {
+    let forward = from <= to;
+    let sign = if forward { 1 } else { -1 };
+    let past = |date: &NaiveDate| if forward { date > to } else { date < to };
+
+    // the most whole months from `from` that stay on or before `to`, from an estimate that can
+    // overshoot only by the days within the final month
+    let mut months = ((i64::from(to.year()) - i64::from(from.year())) * 12 + i64::from(to.month())
+        - i64::from(from.month()))
+    .abs();
+    let after_months = loop {
+        let reached = shift_months(from, sign * months, MonthOverflow::Clamp)
+            .expect("shifting toward a date stays within range");
+        if !past(&reached) {
+            break reached;
+        }
+        months -= 1;
+    };
+    let days = to.signed_duration_since(after_months).num_days();
+
+    CalendarDiff {
+        years: (sign * (months / 12)) as i32,
+        months: (sign * (months % 12)) as i32,
+        days: days as i32,
+    }
+}
+
+pub(crate) fn shift_months(
+    date: &NaiveDate,
+    months: i64,
+    overflow: MonthOverflow,
+) -> Option<NaiveDate> {
+    let index = i64::from(date.year()) * 12 + i64::from(date.month0()) + months;
+    let year = i32::try_from(index.div_euclid(12)).ok()?;
+    let month = u32::try_from(index.rem_euclid(12)).ok()? + 1;
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let last_day = end_of_month(&first).day();
+
+    if date.day() <= last_day {
+        first.with_day(date.day())
+    } else {
+        match overflow {
+            MonthOverflow::Clamp => first.with_day(last_day),
+            MonthOverflow::Spill => {
+                first.checked_add_signed(chrono::Duration::days(i64::from(date.day() - 1)))
+            }
+        }
+    }
+}
+
+impl FromStr for CalendarDuration {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || ParseError::new(input, EXPECTED);
+        let body = input.strip_prefix('P').ok_or_else(error)?;
+
+        if body.is_empty() {
+            return Err(error());
+        }
+
+        let mut duration = CalendarDuration::default();
+        let mut last_position = None;
+        let mut digits_start = 0;
+
+        for (index, c) in body.char_indices() {
+            if c.is_ascii_digit() {
+                continue;
+            }
+
+            // designators must appear at most once, in Y, M, W, D order
+            let position = "YMWD".find(c).ok_or_else(error)?;
+            let digits = &body[digits_start..index];
+            if digits.is_empty() || last_position >= Some(position) {
+                return Err(error());
+            }
+
+            let value = digits.parse().map_err(|_| error())?;
+            match c {
+                'Y' => duration.years = value,
+                'M' => duration.months = value,
+                'W' => duration.weeks = value,
+                _ => duration.days = value,
+            }
+
+            last_position = Some(position);
+            digits_start = index + c.len_utf8();
+        }
+
+        if digits_start == body.len() {
+            Ok(duration)
+        } else {
+            Err(error())
+        }
+    }
+}
+
+impl fmt::Display for CalendarDuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "P")?;
+
+        if *self == CalendarDuration::default() {
+            return write!(f, "0D");
+        }
+
+        for (value, designator) in &[
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if *value > 0 {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn parses_iso_durations() {
+        assert_eq!("P1Y2M10D".parse(), Ok(CalendarDuration::new(1, 2, 0, 10)));
+        assert_eq!("P3W".parse(), Ok(CalendarDuration::new(0, 0, 3, 0)));
+        assert_eq!("P0D".parse(), Ok(CalendarDuration::default()));
+    }
+
+    #[test]
+    fn rejects_invalid_durations() {
+        for input in &["", "P", "1Y", "P1H", "PT1H", "P1D1Y", "P1Y1Y", "PY", "P12"] {
+            assert!(input.parse::<CalendarDuration>().is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn displays_round_trip() {
+        for input in &["P1Y2M10D", "P3W", "P0D", "P18M"] {
+            assert_eq!(
+                input.parse::<CalendarDuration>().unwrap().to_string(),
+                *input
+            );
+        }
+    }
+
+    #[test]
+    fn applies_month_overflow_policy() {
+        let duration = CalendarDuration::new(0, 1, 0, 0);
+
+        assert_eq!(
+            duration.add_to(&ymd(2023, 1, 31), MonthOverflow::Clamp),
+            Some(ymd(2023, 2, 28))
+        );
+        assert_eq!(
+            duration.add_to(&ymd(2023, 1, 31), MonthOverflow::Spill),
+            Some(ymd(2023, 3, 3))
+        );
+        assert_eq!(
+            duration.subtract_from(&ymd(2024, 3, 31), MonthOverflow::Clamp),
+            Some(ymd(2024, 2, 29))
+        );
+    }
+
+    #[test]
+    fn advances_years_before_months() {
+        let by = Advance {
+            years: 1,
+            months: 1,
+            ..Advance::default()
+        };
+
+        assert_eq!(advance(&ymd(2024, 2, 29), by), Some(ymd(2025, 3, 28)));
+        assert_eq!(
+            advance(
+                &ymd(2024, 3, 31),
+                Advance {
+                    months: -1,
+                    days: -1,
+                    ..Advance::default()
+                }
+            ),
+            Some(ymd(2024, 2, 28))
+        );
+        assert_eq!(
+            advance(&ymd(2024, 5, 17), Advance::default()),
+            Some(ymd(2024, 5, 17))
+        );
+        assert_eq!(
+            advance(
+                &NaiveDate::MAX,
+                Advance {
+                    weeks: 1,
+                    ..Advance::default()
+                }
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn borrows_across_month_ends() {
+        let diff = |from, to| {
+            let diff = calendar_diff(&from, &to);
+            (diff.years, diff.months, diff.days)
+        };
+
+        assert_eq!(diff(ymd(2023, 1, 31), ymd(2023, 3, 1)), (0, 1, 1));
+        assert_eq!(diff(ymd(2023, 3, 1), ymd(2023, 1, 31)), (0, -1, -1));
+        assert_eq!(diff(ymd(2024, 2, 29), ymd(2025, 2, 28)), (1, 0, 0));
+        assert_eq!(diff(ymd(2024, 2, 29), ymd(2028, 2, 28)), (3, 11, 30));
+        assert_eq!(diff(ymd(2028, 2, 28), ymd(2024, 2, 29)), (-3, -11, -28));
+        assert_eq!(diff(ymd(2024, 5, 17), ymd(2024, 5, 17)), (0, 0, 0));
+        assert_eq!(diff(NaiveDate::MIN, NaiveDate::MAX).1, 11);
+    }
+
+    #[quickcheck]
+    fn calendar_diffs_round_trip(from: ArbitraryDate, to: ArbitraryDate) -> bool {
+        let (from, to) = (from.0, to.0);
+        let diff = calendar_diff(&from, &to);
+        let signs = [diff.years, diff.months, diff.days];
+        let sign = if from <= to { 1 } else { -1 };
+        let months = diff.years * 12 + diff.months;
+        let one_more = shift_months(&from, i64::from(months + sign), MonthOverflow::Clamp);
+
+        advance(&from, diff.into()) == Some(to)
+            && !matches!(one_more, Some(date) if (date - to).num_days() * i64::from(sign) <= 0)
+            && (-11..=11).contains(&diff.months)
+            && (signs.iter().all(|c| *c >= 0) || signs.iter().all(|c| *c <= 0))
+    }
+
+    #[test]
+    fn applies_months_before_days() {
+        let duration = "P1Y2M10D".parse::<CalendarDuration>().unwrap();
+
+        assert_eq!(
+            duration.add_to(&ymd(2023, 12, 25), MonthOverflow::Clamp),
+            Some(ymd(2025, 3, 7))
+        );
+        assert_eq!(
+            duration.subtract_from(&ymd(2025, 3, 7), MonthOverflow::Clamp),
+            Some(ymd(2023, 12, 28))
+        );
+    }
+}
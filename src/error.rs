@@ -0,0 +1,50 @@
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// An error returned when a string cannot be parsed into one of this crate's values.
+///
+/// Without the `alloc` feature the error cannot keep a copy of its input, so it records only
+/// what was expected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    #[cfg(feature = "alloc")]
+    input: String,
+    expected: &'static str,
+}
+
+impl ParseError {
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    pub(crate) fn new(input: &str, expected: &'static str) -> Self {
+        ParseError {
+            #[cfg(feature = "alloc")]
+            input: input.to_string(),
+            expected,
+        }
+    }
+
+    /// Returns the input that failed to parse.
+    #[cfg(feature = "alloc")]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for ParseError {
+    #[cfg(feature = "alloc")]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "unable to parse {:?}: expected {}",
+            self.input, self.expected
+        )
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unable to parse input: expected {}", self.expected)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
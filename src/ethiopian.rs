@@ -0,0 +1,212 @@
+//! Conversion between Gregorian and Ethiopian dates, and Ethiopian month and year boundaries.
+//!
+//! The Ethiopian year has twelve 30-day months followed by Pagume, a thirteenth month of five
+//! days, or six in the year before a Julian leap year. The year begins on Meskerem 1
+//! (Enkutatash), September 11 in most Gregorian years and September 12 in years preceding a
+//! Gregorian leap year.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::ethiopian::{self, EthiopianDate};
+//!
+//! assert_eq!(ethiopian::new_year(2024), NaiveDate::from_ymd_opt(2024, 9, 11));
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+//! assert_eq!(Some(EthiopianDate::from_gregorian(&date)), EthiopianDate::new(2016, 9, 9));
+//! ```
+
+use crate::day_number::{from_rata_die, to_rata_die};
+use chrono::prelude::*;
+use core::fmt;
+
+/// The thirteenth month of five or six days.
+pub const PAGUME: u32 = 13;
+
+// Rata Die of Meskerem 1, year 1: August 29, 8 in the proleptic Julian calendar
+const EPOCH: i64 = 2796;
+
+/// A date in the Ethiopian calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EthiopianDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl EthiopianDate {
+    /// Builds an Ethiopian date, returning `None` if the month or day is out of range.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if (1..=13).contains(&month) && day >= 1 && day <= days_in_month(year, month) {
+            Some(EthiopianDate { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the Ethiopian date falling on the Gregorian date.
+    pub fn from_gregorian(date: &NaiveDate) -> Self {
+        let fixed = to_rata_die(date);
+        let year = (4 * (fixed - EPOCH) + 1463).div_euclid(1461) as i32;
+        let month = ((fixed - fixed_from_ethiopian(year, 1, 1)).div_euclid(30) + 1) as u32;
+        let day = (fixed + 1 - fixed_from_ethiopian(year, month, 1)) as u32;
+
+        EthiopianDate { year, month, day }
+    }
+
+    /// Returns the Gregorian date falling on the same day, or `None` if it cannot be represented.
+    pub fn to_gregorian(&self) -> Option<NaiveDate> {
+        from_rata_die(fixed_from_ethiopian(self.year, self.month, self.day))
+    }
+
+    /// Returns the year, in the Ethiopian (Amete Mihret) era.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, between 1 and 13.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+}
+
+/// Formats the date as `YYYY-MM-DD`.
+impl fmt::Display for EthiopianDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Returns whether the Ethiopian year is a leap year, with a six-day Pagume.
+pub fn is_leap_year(year: i32) -> bool {
+    year.rem_euclid(4) == 3
+}
+
+/// Returns the number of days in the month of the Ethiopian year.
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        PAGUME if is_leap_year(year) => 6,
+        PAGUME => 5,
+        _ => 30,
+    }
+}
+
+/// Returns the Ethiopian New Year (Meskerem 1) falling in the Gregorian year.
+pub fn new_year(gregorian_year: i32) -> Option<NaiveDate> {
+    EthiopianDate::new(gregorian_year.checked_sub(7)?, 1, 1)?.to_gregorian()
+}
+
+/// Returns the beginning of the Ethiopian month containing the provided date.
+pub fn beginning_of_month(date: &NaiveDate) -> Option<NaiveDate> {
+    let ethiopian = EthiopianDate::from_gregorian(date);
+    EthiopianDate::new(ethiopian.year, ethiopian.month, 1)?.to_gregorian()
+}
+
+/// Returns the end of the Ethiopian month containing the provided date.
+pub fn end_of_month(date: &NaiveDate) -> Option<NaiveDate> {
+    let ethiopian = EthiopianDate::from_gregorian(date);
+    let last_day = days_in_month(ethiopian.year, ethiopian.month);
+    EthiopianDate::new(ethiopian.year, ethiopian.month, last_day)?.to_gregorian()
+}
+
+/// Returns the beginning of the next Ethiopian month.
+pub fn next_month(date: &NaiveDate) -> Option<NaiveDate> {
+    end_of_month(date)?.succ_opt()
+}
+
+/// Returns the beginning of the previous Ethiopian month.
+pub fn previous_month(date: &NaiveDate) -> Option<NaiveDate> {
+    beginning_of_month(&beginning_of_month(date)?.pred_opt()?)
+}
+
+/// Returns the beginning of the Ethiopian year containing the provided date.
+pub fn beginning_of_year(date: &NaiveDate) -> Option<NaiveDate> {
+    let ethiopian = EthiopianDate::from_gregorian(date);
+    EthiopianDate::new(ethiopian.year, 1, 1)?.to_gregorian()
+}
+
+/// Returns the end of the Ethiopian year containing the provided date.
+pub fn end_of_year(date: &NaiveDate) -> Option<NaiveDate> {
+    let ethiopian = EthiopianDate::from_gregorian(date);
+    let last_day = days_in_month(ethiopian.year, PAGUME);
+    EthiopianDate::new(ethiopian.year, PAGUME, last_day)?.to_gregorian()
+}
+
+/// Returns the beginning of the next Ethiopian year.
+pub fn next_year(date: &NaiveDate) -> Option<NaiveDate> {
+    end_of_year(date)?.succ_opt()
+}
+
+/// Returns the beginning of the previous Ethiopian year.
+pub fn previous_year(date: &NaiveDate) -> Option<NaiveDate> {
+    beginning_of_year(&beginning_of_year(date)?.pred_opt()?)
+}
+
+fn fixed_from_ethiopian(year: i32, month: u32, day: u32) -> i64 {
+    let year = i64::from(year);
+
+    EPOCH - 1 + 365 * (year - 1) + year.div_euclid(4) + 30 * (i64::from(month) - 1) + i64::from(day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn converts_known_dates() {
+        // Ethiopian Christmas, Tahsas 29
+        assert_eq!(
+            EthiopianDate::new(2017, 4, 29).unwrap().to_gregorian(),
+            Some(ymd(2025, 1, 7))
+        );
+        assert_eq!(
+            EthiopianDate::from_gregorian(&ymd(2023, 9, 11)),
+            EthiopianDate::new(2015, PAGUME, 6).unwrap()
+        );
+    }
+
+    #[test]
+    fn computes_year_structure() {
+        assert!(is_leap_year(2015));
+        assert!(!is_leap_year(2016));
+        assert_eq!(EthiopianDate::new(2016, PAGUME, 6), None);
+        assert_eq!(EthiopianDate::new(2016, 14, 1), None);
+        assert_eq!(new_year(2023), Some(ymd(2023, 9, 12)));
+        assert_eq!(new_year(2025), Some(ymd(2025, 9, 11)));
+        assert_eq!(new_year(i32::MIN), None);
+        assert_eq!(new_year(i32::MAX), None);
+    }
+
+    #[test]
+    fn computes_ethiopian_boundaries() {
+        // Pagume 3, 2016
+        let date = ymd(2024, 9, 8);
+
+        assert_eq!(beginning_of_month(&date), Some(ymd(2024, 9, 6)));
+        assert_eq!(end_of_month(&date), Some(ymd(2024, 9, 10)));
+        assert_eq!(next_month(&date), Some(ymd(2024, 9, 11)));
+        assert_eq!(previous_month(&date), Some(ymd(2024, 8, 7)));
+        assert_eq!(beginning_of_year(&date), Some(ymd(2023, 9, 12)));
+        assert_eq!(end_of_year(&date), Some(ymd(2024, 9, 10)));
+        assert_eq!(next_year(&date), Some(ymd(2024, 9, 11)));
+        assert_eq!(previous_year(&date), Some(ymd(2022, 9, 11)));
+    }
+
+    #[quickcheck]
+    fn round_trips_through_ethiopian_dates(date: ArbitraryDate) -> bool {
+        let ethiopian = EthiopianDate::from_gregorian(&date.0);
+
+        EthiopianDate::new(ethiopian.year(), ethiopian.month(), ethiopian.day()) == Some(ethiopian)
+            && ethiopian.to_gregorian() == Some(date.0)
+    }
+}
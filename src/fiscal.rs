@@ -0,0 +1,539 @@
+//! Fiscal calendars whose years begin on the first day of an arbitrary month.
+//!
+//! Organizations disagree on whether the fiscal year running from October 2024 through September
+//! 2025 is "FY2024" or "FY2025", so every [`FiscalCalendar`] makes that convention explicit with
+//! a [`FiscalYearLabel`].
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::fiscal::*;
+//!
+//! let federal = FiscalCalendar::new(10, FiscalYearLabel::EndYear).unwrap();
+//! let date = NaiveDate::from_ymd_opt(2024, 11, 5).unwrap();
+//!
+//! assert_eq!(federal.fiscal_year(&date), 2025);
+//! assert_eq!(federal.label(&date, FiscalGranularity::Quarter).to_string(), "FY2025-Q1");
+//!
+//! let label: FiscalLabel = "FY25-Q2".parse().unwrap();
+//! let range = federal.range(&label).unwrap();
+//! assert_eq!(range.start(), NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+//! ```
+
+use crate::civil;
+use crate::duration::shift_months;
+use crate::{DateRange, MonthOverflow, ParseError};
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+use chrono::prelude::*;
+use chrono::Duration;
+use core::fmt;
+use core::str::FromStr;
+
+const EXPECTED: &str = "a fiscal label like \"FY2025\", \"FY25-Q2\", or \"FY2025 P03\"";
+
+/// Which calendar year a fiscal year is named after.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FiscalYearLabel {
+    /// Name the fiscal year after the calendar year in which it begins.
+    StartYear,
+    /// Name the fiscal year after the calendar year in which it ends.
+    EndYear,
+}
+
+/// How finely to label a date within a fiscal year.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FiscalGranularity {
+    /// The fiscal year, e.g. `FY2025`.
+    Year,
+    /// The fiscal quarter, e.g. `FY2025-Q2`.
+    Quarter,
+    /// The fiscal period (month), e.g. `FY2025 P03`.
+    Period,
+}
+
+/// A fiscal year beginning on the first day of a configurable month.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FiscalCalendar {
+    start_month: u32,
+    label: FiscalYearLabel,
+}
+
+impl FiscalCalendar {
+    /// Builds a fiscal calendar whose years begin on the first day of `start_month` (1 through
+    /// 12).
+    pub fn new(start_month: u32, label: FiscalYearLabel) -> Option<Self> {
+        if (1..=12).contains(&start_month) {
+            Some(FiscalCalendar { start_month, label })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the month in which each fiscal year begins.
+    pub fn start_month(&self) -> u32 {
+        self.start_month
+    }
+
+    /// Returns the fiscal year labeling convention.
+    pub fn year_label(&self) -> FiscalYearLabel {
+        self.label
+    }
+
+    /// Returns the labeled fiscal year containing the provided date.
+    pub fn fiscal_year(&self, date: &NaiveDate) -> i32 {
+        self.label_year(self.start_year(date))
+    }
+
+    /// Returns the fiscal quarter (1 through 4) containing the provided date.
+    pub fn fiscal_quarter(&self, date: &NaiveDate) -> u32 {
+        1 + self.months_into_year(date) / 3
+    }
+
+    /// Returns the fiscal period (1 through 12) containing the provided date, where each period
+    /// is a calendar month.
+    pub fn fiscal_period(&self, date: &NaiveDate) -> u32 {
+        1 + self.months_into_year(date)
+    }
+
+    /// Returns the fiscal week containing the provided date, for weeks beginning on
+    /// `week_start`.
+    ///
+    /// Week 1 is the week containing the first day of the fiscal year, so it and the last week
+    /// may be partial; a fiscal year spans 53 or 54 such weeks.
+    pub fn fiscal_week(&self, date: &NaiveDate, week_start: Weekday) -> u32 {
+        let year_start = civil::to_rata_die((self.start_year(date), self.start_month, 1))
+            .expect("fiscal years begin on valid dates");
+        let day = civil::to_rata_die((date.year(), date.month(), date.day()))
+            .expect("chrono dates are valid");
+
+        1 + ((day - first_week_start(year_start, week_start)) / 7) as u32
+    }
+
+    /// Returns the range of a fiscal week of the labeled fiscal year, clipped to the fiscal
+    /// year, for weeks beginning on `week_start`.
+    ///
+    /// Returns `None` if the fiscal year has no such week.
+    pub fn fiscal_week_range(
+        &self,
+        fiscal_year: i32,
+        week: u32,
+        week_start: Weekday,
+    ) -> Option<DateRange> {
+        let year = self.range(&FiscalLabel::Year(fiscal_year))?;
+        let year_start = civil::to_rata_die((
+            year.start().year(),
+            year.start().month(),
+            year.start().day(),
+        ))?;
+        let offset = first_week_start(year_start, week_start) - year_start
+            + 7 * i64::from(week.checked_sub(1)?);
+
+        let start = year.start().checked_add_signed(Duration::days(offset))?;
+        let end = start.checked_add_signed(Duration::days(6))?.min(year.end());
+        if start > year.end() {
+            return None;
+        }
+
+        DateRange::new(start.max(year.start()), end)
+    }
+
+    /// Returns the first day of the fiscal year containing the provided date.
+    pub fn beginning_of_fiscal_year(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.start_year(date), self.start_month, 1)
+    }
+
+    /// Returns the last day of the fiscal year containing the provided date.
+    pub fn end_of_fiscal_year(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        self.next_fiscal_year(date)?.pred_opt()
+    }
+
+    /// Returns the first day of the next fiscal year.
+    pub fn next_fiscal_year(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        shift_months(
+            &self.beginning_of_fiscal_year(date)?,
+            12,
+            MonthOverflow::Clamp,
+        )
+    }
+
+    /// Returns the first day of the previous fiscal year.
+    pub fn previous_fiscal_year(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        shift_months(
+            &self.beginning_of_fiscal_year(date)?,
+            -12,
+            MonthOverflow::Clamp,
+        )
+    }
+
+    /// Returns the first day of the fiscal quarter containing the provided date.
+    pub fn beginning_of_fiscal_quarter(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        let months = 3 * (self.fiscal_quarter(date) - 1);
+        shift_months(
+            &self.beginning_of_fiscal_year(date)?,
+            months.into(),
+            MonthOverflow::Clamp,
+        )
+    }
+
+    /// Returns the last day of the fiscal quarter containing the provided date.
+    pub fn end_of_fiscal_quarter(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        self.next_fiscal_quarter(date)?.pred_opt()
+    }
+
+    /// Returns the first day of the next fiscal quarter.
+    pub fn next_fiscal_quarter(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        shift_months(
+            &self.beginning_of_fiscal_quarter(date)?,
+            3,
+            MonthOverflow::Clamp,
+        )
+    }
+
+    /// Returns the first day of the previous fiscal quarter.
+    pub fn previous_fiscal_quarter(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        shift_months(
+            &self.beginning_of_fiscal_quarter(date)?,
+            -3,
+            MonthOverflow::Clamp,
+        )
+    }
+
+    /// Labels the provided date at the requested granularity.
+    pub fn label(&self, date: &NaiveDate, granularity: FiscalGranularity) -> FiscalLabel {
+        let year = self.fiscal_year(date);
+
+        match granularity {
+            FiscalGranularity::Year => FiscalLabel::Year(year),
+            FiscalGranularity::Quarter => FiscalLabel::Quarter(year, self.fiscal_quarter(date)),
+            FiscalGranularity::Period => FiscalLabel::Period(year, self.fiscal_period(date)),
+        }
+    }
+
+    /// Returns the range of dates covered by a fiscal label under this calendar's conventions.
+    ///
+    /// Returns `None` if the label's quarter or period number is out of range.
+    pub fn range(&self, label: &FiscalLabel) -> Option<DateRange> {
+        let (months_in, length) = match *label {
+            FiscalLabel::Year(_) => (0, 12),
+            FiscalLabel::Quarter(_, quarter) if (1..=4).contains(&quarter) => {
+                (3 * (quarter - 1), 3)
+            }
+            FiscalLabel::Period(_, period) if (1..=12).contains(&period) => (period - 1, 1),
+            _ => return None,
+        };
+
+        let year_start =
+            NaiveDate::from_ymd_opt(self.unlabel_year(label.fiscal_year())?, self.start_month, 1)?;
+        let start = shift_months(&year_start, months_in.into(), MonthOverflow::Clamp)?;
+        let end = shift_months(&start, length.into(), MonthOverflow::Clamp)?.pred_opt()?;
+
+        DateRange::new(start, end)
+    }
+
+    fn start_year(&self, date: &NaiveDate) -> i32 {
+        if date.month() >= self.start_month {
+            date.year()
+        } else {
+            date.year() - 1
+        }
+    }
+
+    fn months_into_year(&self, date: &NaiveDate) -> u32 {
+        (date.month() + 12 - self.start_month) % 12
+    }
+
+    fn label_year(&self, start_year: i32) -> i32 {
+        match self.label {
+            FiscalYearLabel::EndYear if self.start_month != 1 => start_year + 1,
+            _ => start_year,
+        }
+    }
+
+    fn unlabel_year(&self, fiscal_year: i32) -> Option<i32> {
+        match self.label {
+            FiscalYearLabel::EndYear if self.start_month != 1 => fiscal_year.checked_sub(1),
+            _ => Some(fiscal_year),
+        }
+    }
+}
+
+// The first day of the week containing the day, as Rata Die day numbers
+fn first_week_start(rata_die: i64, week_start: Weekday) -> i64 {
+    let days_into_week =
+        (civil::days_from_sunday(rata_die) + 7 - week_start.num_days_from_sunday()) % 7;
+
+    rata_die - i64::from(days_into_week)
+}
+
+/// A label naming a fiscal year, quarter, or period.
+///
+/// Labels display with four-digit years (`FY2025`, `FY2025-Q2`, `FY2025 P03`); use
+/// [`FiscalLabel::to_short_string`] for two-digit years. Parsing accepts either, treating
+/// two-digit years as belonging to the 2000s, and accepts `-` or a space before the quarter or
+/// period.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FiscalLabel {
+    /// A fiscal year.
+    Year(i32),
+    /// A fiscal quarter (1 through 4) within a fiscal year.
+    Quarter(i32, u32),
+    /// A fiscal period (1 through 12) within a fiscal year.
+    Period(i32, u32),
+}
+
+impl FiscalLabel {
+    /// Returns the labeled fiscal year.
+    pub fn fiscal_year(&self) -> i32 {
+        match *self {
+            FiscalLabel::Year(year)
+            | FiscalLabel::Quarter(year, _)
+            | FiscalLabel::Period(year, _) => year,
+        }
+    }
+
+    /// Formats the label with a two-digit year, e.g. `FY25-Q2`.
+    #[cfg(feature = "alloc")]
+    pub fn to_short_string(&self) -> String {
+        let year = self.fiscal_year().rem_euclid(100);
+
+        match *self {
+            FiscalLabel::Year(_) => format!("FY{:02}", year),
+            FiscalLabel::Quarter(_, quarter) => format!("FY{:02}-Q{}", year, quarter),
+            FiscalLabel::Period(_, period) => format!("FY{:02} P{:02}", year, period),
+        }
+    }
+}
+
+impl fmt::Display for FiscalLabel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FiscalLabel::Year(year) => write!(f, "FY{}", year),
+            FiscalLabel::Quarter(year, quarter) => write!(f, "FY{}-Q{}", year, quarter),
+            FiscalLabel::Period(year, period) => write!(f, "FY{} P{:02}", year, period),
+        }
+    }
+}
+
+impl FromStr for FiscalLabel {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || ParseError::new(input, EXPECTED);
+        let rest = strip_prefix_ignoring_case(input.trim(), "FY").ok_or_else(error)?;
+
+        let year_length = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let year = match year_length {
+            2 => 2000 + rest[..2].parse::<i32>().map_err(|_| error())?,
+            4 => rest[..4].parse::<i32>().map_err(|_| error())?,
+            _ => return Err(error()),
+        };
+
+        let suffix = &rest[year_length..];
+        if suffix.is_empty() {
+            return Ok(FiscalLabel::Year(year));
+        }
+
+        let suffix = suffix
+            .strip_prefix('-')
+            .or_else(|| suffix.strip_prefix(' '))
+            .ok_or_else(error)?;
+        let number = |digits: &str| {
+            if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+                digits.parse::<u32>().map_err(|_| error())
+            } else {
+                Err(error())
+            }
+        };
+
+        if let Some(quarter) = suffix.strip_prefix(['Q', 'q']) {
+            match number(quarter)? {
+                quarter @ 1..=4 => Ok(FiscalLabel::Quarter(year, quarter)),
+                _ => Err(error()),
+            }
+        } else if let Some(period) = suffix.strip_prefix(['P', 'p']) {
+            match number(period)? {
+                period @ 1..=12 => Ok(FiscalLabel::Period(year, period)),
+                _ => Err(error()),
+            }
+        } else {
+            Err(error())
+        }
+    }
+}
+
+fn strip_prefix_ignoring_case<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = input.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &input[prefix.len()..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn range(start: NaiveDate, end: NaiveDate) -> Option<DateRange> {
+        DateRange::new(start, end)
+    }
+
+    #[test]
+    fn numbers_fiscal_weeks() {
+        // FY2025 of the US federal government begins Tuesday, October 1, 2024
+        let federal = FiscalCalendar::new(10, FiscalYearLabel::EndYear).unwrap();
+
+        assert_eq!(federal.fiscal_week(&ymd(2024, 10, 1), Weekday::Sun), 1);
+        assert_eq!(federal.fiscal_week(&ymd(2024, 10, 5), Weekday::Sun), 1);
+        assert_eq!(federal.fiscal_week(&ymd(2024, 10, 6), Weekday::Sun), 2);
+        assert_eq!(federal.fiscal_week(&ymd(2025, 6, 10), Weekday::Sun), 37);
+        assert_eq!(federal.fiscal_week(&ymd(2025, 9, 30), Weekday::Sun), 53);
+        assert_eq!(
+            federal.fiscal_week_range(2025, 1, Weekday::Sun),
+            range(ymd(2024, 10, 1), ymd(2024, 10, 5))
+        );
+        assert_eq!(
+            federal.fiscal_week_range(2025, 37, Weekday::Sun),
+            range(ymd(2025, 6, 8), ymd(2025, 6, 14))
+        );
+        assert_eq!(
+            federal.fiscal_week_range(2025, 53, Weekday::Sun),
+            range(ymd(2025, 9, 28), ymd(2025, 9, 30))
+        );
+        assert_eq!(federal.fiscal_week_range(2025, 54, Weekday::Sun), None);
+        assert_eq!(federal.fiscal_week_range(2025, 0, Weekday::Sun), None);
+    }
+
+    #[test]
+    fn fiscal_weeks_contain_their_dates() {
+        let calendar = FiscalCalendar::new(7, FiscalYearLabel::StartYear).unwrap();
+        let first = ymd(2023, 7, 1);
+
+        for date in first.iter_days().take(800) {
+            let week = calendar.fiscal_week(&date, Weekday::Mon);
+            let range = calendar
+                .fiscal_week_range(calendar.fiscal_year(&date), week, Weekday::Mon)
+                .unwrap();
+
+            assert!(range.contains(&date), "{}", date);
+        }
+    }
+
+    #[test]
+    fn labels_by_start_or_end_year() {
+        let date = ymd(2024, 11, 5);
+        let by_end = FiscalCalendar::new(10, FiscalYearLabel::EndYear).unwrap();
+        let by_start = FiscalCalendar::new(10, FiscalYearLabel::StartYear).unwrap();
+
+        assert_eq!(by_end.fiscal_year(&date), 2025);
+        assert_eq!(by_start.fiscal_year(&date), 2024);
+        assert_eq!(by_end.fiscal_quarter(&date), 1);
+        assert_eq!(by_end.fiscal_period(&date), 2);
+    }
+
+    #[test]
+    fn calendar_year_fiscal_calendars_ignore_the_label() {
+        let date = ymd(2024, 11, 5);
+        let by_end = FiscalCalendar::new(1, FiscalYearLabel::EndYear).unwrap();
+
+        assert_eq!(by_end.fiscal_year(&date), 2024);
+        assert_eq!(by_end.fiscal_quarter(&date), 4);
+    }
+
+    #[test]
+    fn computes_boundaries() {
+        let calendar = FiscalCalendar::new(7, FiscalYearLabel::EndYear).unwrap();
+        let date = ymd(2024, 2, 29);
+
+        assert_eq!(
+            calendar.beginning_of_fiscal_year(&date),
+            Some(ymd(2023, 7, 1))
+        );
+        assert_eq!(calendar.end_of_fiscal_year(&date), Some(ymd(2024, 6, 30)));
+        assert_eq!(
+            calendar.beginning_of_fiscal_quarter(&date),
+            Some(ymd(2024, 1, 1))
+        );
+        assert_eq!(
+            calendar.end_of_fiscal_quarter(&date),
+            Some(ymd(2024, 3, 31))
+        );
+        assert_eq!(calendar.next_fiscal_quarter(&date), Some(ymd(2024, 4, 1)));
+        assert_eq!(calendar.previous_fiscal_year(&date), Some(ymd(2022, 7, 1)));
+    }
+
+    #[test]
+    fn parses_labels() {
+        assert_eq!("FY2025".parse(), Ok(FiscalLabel::Year(2025)));
+        assert_eq!("fy25".parse(), Ok(FiscalLabel::Year(2025)));
+        assert_eq!("FY25-Q2".parse(), Ok(FiscalLabel::Quarter(2025, 2)));
+        assert_eq!("FY2025 Q2".parse(), Ok(FiscalLabel::Quarter(2025, 2)));
+        assert_eq!("FY2025 P03".parse(), Ok(FiscalLabel::Period(2025, 3)));
+        assert_eq!("FY25-P12".parse(), Ok(FiscalLabel::Period(2025, 12)));
+    }
+
+    #[test]
+    fn rejects_invalid_labels() {
+        for input in &[
+            "2025",
+            "FY",
+            "FY202",
+            "FY2025Q2",
+            "FY2025-Q5",
+            "FY2025 P13",
+            "FY25-X1",
+        ] {
+            assert!(input.parse::<FiscalLabel>().is_err(), "{}", input);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn formats_labels() {
+        assert_eq!(FiscalLabel::Year(2025).to_string(), "FY2025");
+        assert_eq!(FiscalLabel::Quarter(2025, 2).to_string(), "FY2025-Q2");
+        assert_eq!(FiscalLabel::Period(2025, 3).to_string(), "FY2025 P03");
+        assert_eq!(FiscalLabel::Quarter(2005, 2).to_short_string(), "FY05-Q2");
+        assert_eq!(FiscalLabel::Period(2025, 3).to_short_string(), "FY25 P03");
+    }
+
+    #[test]
+    fn resolves_labels_to_ranges() {
+        let by_end = FiscalCalendar::new(10, FiscalYearLabel::EndYear).unwrap();
+        let by_start = FiscalCalendar::new(10, FiscalYearLabel::StartYear).unwrap();
+        let label = FiscalLabel::Year(2025);
+
+        assert_eq!(
+            by_end.range(&label),
+            range(ymd(2024, 10, 1), ymd(2025, 9, 30))
+        );
+        assert_eq!(
+            by_start.range(&label),
+            range(ymd(2025, 10, 1), ymd(2026, 9, 30))
+        );
+        assert_eq!(
+            by_end.range(&FiscalLabel::Period(2025, 5)),
+            range(ymd(2025, 2, 1), ymd(2025, 2, 28))
+        );
+        assert_eq!(by_end.range(&FiscalLabel::Quarter(2025, 5)), None);
+    }
+
+    #[test]
+    fn labels_round_trip_through_ranges() {
+        let calendar = FiscalCalendar::new(4, FiscalYearLabel::StartYear).unwrap();
+        let date = ymd(2025, 2, 14);
+
+        for granularity in &[
+            FiscalGranularity::Year,
+            FiscalGranularity::Quarter,
+            FiscalGranularity::Period,
+        ] {
+            let label = calendar.label(&date, *granularity);
+
+            assert!(calendar.range(&label).unwrap().contains(&date));
+        }
+    }
+}
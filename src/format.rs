@@ -0,0 +1,148 @@
+//! Date formatting with period tokens that chrono's `strftime` lacks.
+//!
+//! The following tokens are added to chrono's
+//! [specifiers](https://docs.rs/chrono/latest/chrono/format/strftime/index.html), which all
+//! remain available (ISO week-year combinations, for example, are written `%G-W%V`):
+//!
+//! | Token              | Meaning                                      | Example      |
+//! |--------------------|----------------------------------------------|--------------|
+//! | `%Q`               | calendar quarter number                      | `3`          |
+//! | `%f`               | fiscal year                                  | `2025`       |
+//! | `%K`               | fiscal quarter number                        | `1`          |
+//! | `%{quarter_start}` | first day of the calendar quarter            | `2024-07-01` |
+//! | `%{quarter_end}`   | last day of the calendar quarter             | `2024-09-30` |
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::fiscal::{FiscalCalendar, FiscalYearLabel};
+//! use date_calculations::format::{format_period, format_period_with};
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 8, 14).unwrap();
+//! let fiscal = FiscalCalendar::new(7, FiscalYearLabel::EndYear).unwrap();
+//!
+//! assert_eq!(format_period(&date, "%Y Q%Q").unwrap(), "2024 Q3");
+//! assert_eq!(format_period_with(&date, "FY%f Q%K", &fiscal).unwrap(), "FY2025 Q1");
+//! ```
+
+use crate::fiscal::{FiscalCalendar, FiscalYearLabel};
+use crate::{beginning_of_quarter, end_of_quarter, ParseError};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use chrono::format::{Item, StrftimeItems};
+use chrono::prelude::*;
+
+const EXPECTED: &str = "a format string of chrono specifiers and period tokens";
+
+/// Formats the date, treating the calendar year as the fiscal year for `%f` and `%K`.
+pub fn format_period(date: &NaiveDate, pattern: &str) -> Result<String, ParseError> {
+    let calendar_year = FiscalCalendar::new(1, FiscalYearLabel::StartYear)
+        .expect("January is a valid fiscal start month");
+
+    format_period_with(date, pattern, &calendar_year)
+}
+
+/// Formats the date, resolving `%f` and `%K` with the provided fiscal calendar.
+///
+/// Returns an error for unknown `%{…}` tokens or invalid chrono specifiers.
+pub fn format_period_with(
+    date: &NaiveDate,
+    pattern: &str,
+    fiscal: &FiscalCalendar,
+) -> Result<String, ParseError> {
+    let error = || ParseError::new(pattern, EXPECTED);
+    let mut expanded = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+
+    while let Some(position) = rest.find('%') {
+        expanded.push_str(&rest[..position]);
+        let token = &rest[position..];
+
+        let consumed = if let Some(named) = token.strip_prefix("%{") {
+            let end = named.find('}').ok_or_else(error)?;
+            let value = match &named[..end] {
+                "quarter_start" => beginning_of_quarter(date),
+                "quarter_end" => end_of_quarter(date),
+                _ => return Err(error()),
+            };
+            expanded.push_str(&value.to_string());
+            end + 3
+        } else {
+            match token.chars().nth(1) {
+                Some('Q') => expanded.push_str(&(1 + date.month0() / 3).to_string()),
+                Some('f') => expanded.push_str(&fiscal.fiscal_year(date).to_string()),
+                Some('K') => expanded.push_str(&fiscal.fiscal_quarter(date).to_string()),
+                Some(other) => {
+                    expanded.push('%');
+                    expanded.push(other);
+                }
+                None => return Err(error()),
+            }
+            1 + token[1..].chars().next().map_or(0, char::len_utf8)
+        };
+
+        rest = &token[consumed..];
+    }
+    expanded.push_str(rest);
+
+    let items: Vec<Item> = StrftimeItems::new(&expanded).collect();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return Err(error());
+    }
+
+    Ok(date.format_with_items(items.into_iter()).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn expands_quarter_tokens() {
+        let date = ymd(2024, 8, 14);
+
+        assert_eq!(format_period(&date, "Q%Q %Y").unwrap(), "Q3 2024");
+        assert_eq!(
+            format_period(&date, "%{quarter_start} to %{quarter_end}").unwrap(),
+            "2024-07-01 to 2024-09-30"
+        );
+    }
+
+    #[test]
+    fn expands_fiscal_tokens() {
+        let date = ymd(2024, 8, 14);
+        let fiscal = FiscalCalendar::new(10, FiscalYearLabel::EndYear).unwrap();
+
+        assert_eq!(format_period(&date, "FY%f Q%K").unwrap(), "FY2024 Q3");
+        assert_eq!(
+            format_period_with(&date, "FY%f Q%K", &fiscal).unwrap(),
+            "FY2024 Q4"
+        );
+    }
+
+    #[test]
+    fn passes_chrono_specifiers_through() {
+        let date = ymd(2024, 12, 30);
+
+        assert_eq!(format_period(&date, "%G-W%V").unwrap(), "2025-W01");
+        assert_eq!(
+            format_period(&date, "100%% of %B").unwrap(),
+            "100% of December"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        let date = ymd(2024, 12, 30);
+
+        assert!(format_period(&date, "%{fortnight}").is_err());
+        assert!(format_period(&date, "%{quarter_start").is_err());
+        assert!(format_period(&date, "trailing %").is_err());
+        assert!(format_period(&date, "%J").is_err());
+    }
+}
@@ -0,0 +1,120 @@
+//! The weeks-as-columns layout of a contribution heatmap, enabled with the `alloc` feature.
+//!
+//! [`heatmap`] lays a range out as one column per week, each holding the week's seven days in
+//! order from the week start, and places a label above the column where each month first
+//! appears.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::heatmap::heatmap;
+//! use date_calculations::DateRange;
+//!
+//! let range: DateRange = "2024-01-30/2024-02-12".parse().unwrap();
+//! let layout = heatmap(&range, Weekday::Sun).unwrap();
+//!
+//! assert_eq!(layout.columns.len(), 3);
+//! assert_eq!(layout.columns[0][1], None);
+//! assert_eq!(layout.columns[0][2], NaiveDate::from_ymd_opt(2024, 1, 30));
+//! assert_eq!(layout.columns[2][1], NaiveDate::from_ymd_opt(2024, 2, 12));
+//! assert_eq!(layout.month_labels[1].column, 0);
+//! assert_eq!(layout.month_labels[1].month.to_string(), "2024-02");
+//! ```
+
+use crate::{beginning_of_week_starting_on, DateRange, YearMonth};
+use alloc::{vec, vec::Vec};
+use chrono::prelude::*;
+
+/// A label above a heatmap column for the month of the column's days.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MonthLabel {
+    /// The index of the column.
+    pub column: usize,
+    /// The month labeled.
+    pub month: YearMonth,
+}
+
+/// A heatmap laid out by [`heatmap`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Heatmap {
+    /// One column per week, each holding seven days from the week start, with `None` for days
+    /// outside the range.
+    pub columns: Vec<Vec<Option<NaiveDate>>>,
+    /// A label for each month of the range, at the column holding its first day in the range,
+    /// in order.
+    pub month_labels: Vec<MonthLabel>,
+}
+
+/// Lays out the range as columns of weeks beginning on `week_start`.
+///
+/// Returns `None` if the first or last week extends beyond the dates chrono can represent.
+pub fn heatmap(range: &DateRange, week_start: Weekday) -> Option<Heatmap> {
+    let first = beginning_of_week_starting_on(&range.start(), week_start)?;
+    let weeks = (range.end().signed_duration_since(first).num_days() / 7 + 1) as usize;
+    // the last column's week must end within chrono's range too
+    first.checked_add_days(chrono::Days::new(weeks as u64 * 7 - 1))?;
+
+    let mut columns = vec![vec![None; 7]; weeks];
+    let mut month_labels: Vec<MonthLabel> = vec![];
+
+    for date in range.iter() {
+        let days = date.signed_duration_since(first).num_days() as usize;
+        let (column, row) = (days / 7, days % 7);
+        columns[column][row] = Some(date);
+
+        let month = YearMonth::from_date(&date);
+        if month_labels.last().map(|label| label.month) != Some(month) {
+            month_labels.push(MonthLabel { column, month });
+        }
+    }
+
+    Some(Heatmap {
+        columns,
+        month_labels,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn lays_out_a_year_in_week_columns() {
+        let range = DateRange::new(ymd(2024, 1, 1), ymd(2024, 12, 31)).unwrap();
+        let layout = heatmap(&range, Weekday::Sun).unwrap();
+
+        assert_eq!(layout.columns.len(), 53);
+        assert!(layout.columns.iter().all(|column| column.len() == 7));
+        // January 1, 2024 is a Monday
+        assert_eq!(layout.columns[0][0], None);
+        assert_eq!(layout.columns[0][1], Some(ymd(2024, 1, 1)));
+        assert_eq!(layout.columns[52][2], Some(ymd(2024, 12, 31)));
+        assert_eq!(layout.columns[52][3], None);
+        assert_eq!(layout.month_labels.len(), 12);
+        assert_eq!(
+            layout.month_labels[2],
+            MonthLabel {
+                column: 8,
+                month: YearMonth::new(2024, 3).unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn starts_columns_on_the_week_start() {
+        let range = DateRange::new(ymd(2024, 5, 13), ymd(2024, 5, 19)).unwrap();
+
+        assert_eq!(heatmap(&range, Weekday::Mon).unwrap().columns.len(), 1);
+        assert_eq!(heatmap(&range, Weekday::Sun).unwrap().columns.len(), 2);
+        assert_eq!(
+            heatmap(
+                &DateRange::new(NaiveDate::MAX, NaiveDate::MAX).unwrap(),
+                Weekday::Mon
+            ),
+            None
+        );
+    }
+}
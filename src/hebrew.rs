@@ -0,0 +1,358 @@
+//! Conversion between Gregorian and Hebrew dates, Hebrew month and year boundaries, and the
+//! dates of major Jewish holidays.
+//!
+//! Months are numbered from Nisan, following *Calendrical Calculations*: Nisan is 1, the year
+//! begins with Tishri (7), and Adar II (13) exists only in leap years, when Adar (12) is Adar I.
+//! The boundary functions take and return Gregorian [`NaiveDate`] values; Hebrew days are
+//! treated as beginning at midnight rather than the preceding sunset.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::hebrew::{self, HebrewDate};
+//!
+//! assert_eq!(hebrew::rosh_hashanah(2024), NaiveDate::from_ymd_opt(2024, 10, 3));
+//! assert_eq!(hebrew::passover(2025), NaiveDate::from_ymd_opt(2025, 4, 13));
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+//! assert_eq!(HebrewDate::from_gregorian(&date), HebrewDate::new(5784, hebrew::IYYAR, 9));
+//! ```
+
+use crate::day_number::{from_rata_die, to_rata_die};
+use chrono::prelude::*;
+use core::fmt;
+
+/// The first month of the Hebrew religious year.
+pub const NISAN: u32 = 1;
+/// The second month.
+pub const IYYAR: u32 = 2;
+/// The third month.
+pub const SIVAN: u32 = 3;
+/// The fourth month.
+pub const TAMMUZ: u32 = 4;
+/// The fifth month.
+pub const AV: u32 = 5;
+/// The sixth month.
+pub const ELUL: u32 = 6;
+/// The seventh month, with which the civil year begins.
+pub const TISHRI: u32 = 7;
+/// The eighth month, also written Marheshvan.
+pub const HESHVAN: u32 = 8;
+/// The ninth month.
+pub const KISLEV: u32 = 9;
+/// The tenth month.
+pub const TEVET: u32 = 10;
+/// The eleventh month.
+pub const SHEVAT: u32 = 11;
+/// The twelfth month; Adar I in leap years.
+pub const ADAR: u32 = 12;
+/// The thirteenth month, present only in leap years.
+pub const ADAR_II: u32 = 13;
+
+// Rata Die of Tishri 1, year 1: October 7, 3761 BC in the proleptic Julian calendar
+const EPOCH: i64 = -1_373_427;
+
+/// A date in the Hebrew calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HebrewDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl HebrewDate {
+    /// Builds a Hebrew date, returning `None` if the year precedes year 1 or the month or day is
+    /// out of range.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if year >= 1
+            && (1..=months_in_year(year)).contains(&month)
+            && day >= 1
+            && day <= days_in_month(year, month)
+        {
+            Some(HebrewDate { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the Hebrew date falling on the Gregorian date, or `None` if the date precedes the
+    /// Hebrew epoch.
+    pub fn from_gregorian(date: &NaiveDate) -> Option<Self> {
+        let fixed = to_rata_die(date);
+        if fixed < EPOCH {
+            return None;
+        }
+
+        let approximate = ((fixed - EPOCH) * 98_496 / 35_975_351) as i32;
+        let year = (approximate.max(1)..)
+            .take_while(|&year| new_year(i64::from(year)) <= fixed)
+            .last()
+            .unwrap_or(1);
+
+        let first_month = if fixed < fixed_from_hebrew(year, NISAN, 1) {
+            TISHRI
+        } else {
+            NISAN
+        };
+        let month = (first_month..)
+            .find(|&month| fixed <= fixed_from_hebrew(year, month, days_in_month(year, month)))
+            .expect("the date falls within its year");
+        let day = (fixed - fixed_from_hebrew(year, month, 1) + 1) as u32;
+
+        Some(HebrewDate { year, month, day })
+    }
+
+    /// Returns the Gregorian date falling on the same day, or `None` if it cannot be represented.
+    pub fn to_gregorian(&self) -> Option<NaiveDate> {
+        from_rata_die(fixed_from_hebrew(self.year, self.month, self.day))
+    }
+
+    /// Returns the year, counted from the traditional date of creation.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, numbered from Nisan.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+}
+
+/// Formats the date as `YYYY-MM-DD`, with months numbered from Nisan.
+impl fmt::Display for HebrewDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Returns whether the Hebrew year is a leap year, containing Adar II.
+pub fn is_leap_year(year: i32) -> bool {
+    (7 * i64::from(year) + 1).rem_euclid(19) < 7
+}
+
+/// Returns the number of months in the Hebrew year: 13 in leap years and 12 otherwise.
+pub fn months_in_year(year: i32) -> u32 {
+    if is_leap_year(year) {
+        13
+    } else {
+        12
+    }
+}
+
+/// Returns the number of days in the Hebrew year, between 353 and 385.
+pub fn days_in_year(year: i32) -> u32 {
+    let year = i64::from(year);
+
+    (new_year(year + 1) - new_year(year)) as u32
+}
+
+/// Returns the number of days in the month of the Hebrew year.
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    let year_length = days_in_year(year);
+
+    match month {
+        IYYAR | TAMMUZ | ELUL | TEVET | ADAR_II => 29,
+        ADAR if !is_leap_year(year) => 29,
+        HESHVAN if year_length % 10 != 5 => 29,
+        KISLEV if year_length % 10 == 3 => 29,
+        _ => 30,
+    }
+}
+
+/// Returns Rosh Hashanah (Tishri 1) falling in the Gregorian year.
+pub fn rosh_hashanah(gregorian_year: i32) -> Option<NaiveDate> {
+    HebrewDate::new(gregorian_year.checked_add(3761)?, TISHRI, 1)?.to_gregorian()
+}
+
+/// Returns Yom Kippur (Tishri 10) falling in the Gregorian year.
+pub fn yom_kippur(gregorian_year: i32) -> Option<NaiveDate> {
+    HebrewDate::new(gregorian_year.checked_add(3761)?, TISHRI, 10)?.to_gregorian()
+}
+
+/// Returns the first day of Sukkot (Tishri 15) falling in the Gregorian year.
+pub fn sukkot(gregorian_year: i32) -> Option<NaiveDate> {
+    HebrewDate::new(gregorian_year.checked_add(3761)?, TISHRI, 15)?.to_gregorian()
+}
+
+/// Returns the first day of Passover (Nisan 15) falling in the Gregorian year.
+pub fn passover(gregorian_year: i32) -> Option<NaiveDate> {
+    HebrewDate::new(gregorian_year.checked_add(3760)?, NISAN, 15)?.to_gregorian()
+}
+
+/// Returns Shavuot (Sivan 6) falling in the Gregorian year.
+pub fn shavuot(gregorian_year: i32) -> Option<NaiveDate> {
+    HebrewDate::new(gregorian_year.checked_add(3760)?, SIVAN, 6)?.to_gregorian()
+}
+
+/// Returns the beginning of the Hebrew month containing the provided date.
+pub fn beginning_of_month(date: &NaiveDate) -> Option<NaiveDate> {
+    let hebrew = HebrewDate::from_gregorian(date)?;
+    HebrewDate::new(hebrew.year, hebrew.month, 1)?.to_gregorian()
+}
+
+/// Returns the end of the Hebrew month containing the provided date.
+pub fn end_of_month(date: &NaiveDate) -> Option<NaiveDate> {
+    let hebrew = HebrewDate::from_gregorian(date)?;
+    let last_day = days_in_month(hebrew.year, hebrew.month);
+    HebrewDate::new(hebrew.year, hebrew.month, last_day)?.to_gregorian()
+}
+
+/// Returns the beginning of the next Hebrew month.
+pub fn next_month(date: &NaiveDate) -> Option<NaiveDate> {
+    end_of_month(date)?.succ_opt()
+}
+
+/// Returns the beginning of the previous Hebrew month.
+pub fn previous_month(date: &NaiveDate) -> Option<NaiveDate> {
+    beginning_of_month(&beginning_of_month(date)?.pred_opt()?)
+}
+
+/// Returns the beginning (Tishri 1) of the Hebrew year containing the provided date.
+pub fn beginning_of_year(date: &NaiveDate) -> Option<NaiveDate> {
+    let hebrew = HebrewDate::from_gregorian(date)?;
+    from_rata_die(new_year(i64::from(hebrew.year)))
+}
+
+/// Returns the end (Elul 29) of the Hebrew year containing the provided date.
+pub fn end_of_year(date: &NaiveDate) -> Option<NaiveDate> {
+    next_year(date)?.pred_opt()
+}
+
+/// Returns the beginning of the next Hebrew year.
+pub fn next_year(date: &NaiveDate) -> Option<NaiveDate> {
+    let hebrew = HebrewDate::from_gregorian(date)?;
+    from_rata_die(new_year(i64::from(hebrew.year) + 1))
+}
+
+/// Returns the beginning of the previous Hebrew year.
+pub fn previous_year(date: &NaiveDate) -> Option<NaiveDate> {
+    let hebrew = HebrewDate::from_gregorian(date)?;
+    HebrewDate::new(hebrew.year - 1, TISHRI, 1)?.to_gregorian()
+}
+
+fn elapsed_days(year: i64) -> i64 {
+    let months_elapsed = (235 * year - 234).div_euclid(19);
+    let parts_elapsed = 12_084 + 13_753 * months_elapsed;
+    let days = 29 * months_elapsed + parts_elapsed.div_euclid(25_920);
+
+    if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+fn year_length_correction(year: i64) -> i64 {
+    let previous = elapsed_days(year - 1);
+    let current = elapsed_days(year);
+    let next = elapsed_days(year + 1);
+
+    if next - current == 356 {
+        2
+    } else if current - previous == 382 {
+        1
+    } else {
+        0
+    }
+}
+
+// Years are widened so that the years around `i32::MAX` can be measured
+fn new_year(year: i64) -> i64 {
+    EPOCH + elapsed_days(year) + year_length_correction(year)
+}
+
+fn fixed_from_hebrew(year: i32, month: u32, day: u32) -> i64 {
+    let months_before: i64 = if month < TISHRI {
+        (TISHRI..=months_in_year(year))
+            .chain(NISAN..month)
+            .map(|m| i64::from(days_in_month(year, m)))
+            .sum()
+    } else {
+        (TISHRI..month)
+            .map(|m| i64::from(days_in_month(year, m)))
+            .sum()
+    };
+
+    new_year(i64::from(year)) + months_before + i64::from(day) - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn converts_known_dates() {
+        assert_eq!(
+            HebrewDate::from_gregorian(&ymd(1948, 5, 14)),
+            HebrewDate::new(5708, IYYAR, 5)
+        );
+        assert_eq!(
+            HebrewDate::new(5784, ADAR_II, 14).unwrap().to_gregorian(),
+            Some(ymd(2024, 3, 24))
+        );
+        assert_eq!(
+            HebrewDate::from_gregorian(&ymd(2023, 3, 7)),
+            HebrewDate::new(5783, ADAR, 14)
+        );
+        assert_eq!(HebrewDate::from_gregorian(&ymd(-3761, 9, 6)), None);
+    }
+
+    #[test]
+    fn computes_year_structure() {
+        assert!(is_leap_year(5784));
+        assert!(!is_leap_year(5785));
+        assert_eq!(days_in_year(5784), 383);
+        assert_eq!(days_in_year(5785), 355);
+        assert_eq!(days_in_month(5785, HESHVAN), 30);
+        assert_eq!(HebrewDate::new(5785, ADAR_II, 1), None);
+        assert!((353..=385).contains(&days_in_year(i32::MAX)));
+        assert_eq!(
+            HebrewDate::new(i32::MAX, 1, 1).unwrap().to_gregorian(),
+            None
+        );
+    }
+
+    #[test]
+    fn computes_holidays() {
+        assert_eq!(rosh_hashanah(2023), Some(ymd(2023, 9, 16)));
+        assert_eq!(yom_kippur(2024), Some(ymd(2024, 10, 12)));
+        assert_eq!(sukkot(2024), Some(ymd(2024, 10, 17)));
+        assert_eq!(passover(2024), Some(ymd(2024, 4, 23)));
+        assert_eq!(shavuot(2024), Some(ymd(2024, 6, 12)));
+        assert_eq!(rosh_hashanah(i32::MAX), None);
+        assert_eq!(passover(i32::MAX), None);
+    }
+
+    #[test]
+    fn computes_hebrew_boundaries() {
+        // Iyyar 9, 5784
+        let date = ymd(2024, 5, 17);
+
+        assert_eq!(beginning_of_month(&date), Some(ymd(2024, 5, 9)));
+        assert_eq!(end_of_month(&date), Some(ymd(2024, 6, 6)));
+        assert_eq!(next_month(&date), Some(ymd(2024, 6, 7)));
+        assert_eq!(previous_month(&date), Some(ymd(2024, 4, 9)));
+        assert_eq!(beginning_of_year(&date), Some(ymd(2023, 9, 16)));
+        assert_eq!(end_of_year(&date), Some(ymd(2024, 10, 2)));
+        assert_eq!(next_year(&date), Some(ymd(2024, 10, 3)));
+        assert_eq!(previous_year(&date), Some(ymd(2022, 9, 26)));
+    }
+
+    #[quickcheck]
+    fn round_trips_through_hebrew_dates(date: ArbitraryDate) -> bool {
+        let hebrew = HebrewDate::from_gregorian(&date.0).unwrap();
+
+        HebrewDate::new(hebrew.year(), hebrew.month(), hebrew.day()) == Some(hebrew)
+            && hebrew.to_gregorian() == Some(date.0)
+    }
+}
@@ -0,0 +1,295 @@
+//! Conversion between Gregorian and Islamic (Hijri) dates using the tabular arithmetic calendar,
+//! Hijri month and year boundaries, and Gregorian dates of major Islamic holidays.
+//!
+//! The tabular calendar alternates 30- and 29-day months and adds a day to the last month in
+//! 11 years of each 30-year cycle, counting from the civil epoch of Friday, July 16, 622
+//! (Julian). Observational and official calendars instead begin months with the sighting or
+//! calculation of the new crescent, so their dates may differ from these by a day or two; use
+//! these values as estimates unless the authority in question uses the tabular calendar.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::hijri::{self, HijriDate};
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 3, 11).unwrap();
+//!
+//! assert_eq!(HijriDate::from_gregorian(&date), HijriDate::new(1445, hijri::RAMADAN, 1));
+//! assert_eq!(
+//!     HijriDate::new(1445, hijri::SHAWWAL, 1).and_then(|eid| eid.to_gregorian()),
+//!     NaiveDate::from_ymd_opt(2024, 4, 10)
+//! );
+//! ```
+
+use crate::day_number::{from_rata_die, to_rata_die};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use chrono::prelude::*;
+use core::fmt;
+
+/// The first month.
+pub const MUHARRAM: u32 = 1;
+/// The second month.
+pub const SAFAR: u32 = 2;
+/// The third month.
+pub const RABI_AL_AWWAL: u32 = 3;
+/// The fourth month.
+pub const RABI_AL_THANI: u32 = 4;
+/// The fifth month.
+pub const JUMADA_AL_ULA: u32 = 5;
+/// The sixth month.
+pub const JUMADA_AL_AKHIRA: u32 = 6;
+/// The seventh month.
+pub const RAJAB: u32 = 7;
+/// The eighth month.
+pub const SHABAN: u32 = 8;
+/// The ninth month, the month of fasting.
+pub const RAMADAN: u32 = 9;
+/// The tenth month.
+pub const SHAWWAL: u32 = 10;
+/// The eleventh month.
+pub const DHU_AL_QADAH: u32 = 11;
+/// The twelfth month, the month of pilgrimage.
+pub const DHU_AL_HIJJAH: u32 = 12;
+
+// Rata Die of 1 Muharram, year 1: July 16, 622 in the proleptic Julian calendar
+const EPOCH: i64 = 227_015;
+
+/// A date in the tabular Islamic calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HijriDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl HijriDate {
+    /// Builds a Hijri date, returning `None` if the year precedes year 1 or the month or day is
+    /// out of range.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if year >= 1 && (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month) {
+            Some(HijriDate { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the Hijri date falling on the Gregorian date, or `None` if the date precedes the
+    /// Hijri epoch.
+    pub fn from_gregorian(date: &NaiveDate) -> Option<Self> {
+        let fixed = to_rata_die(date);
+        if fixed < EPOCH {
+            return None;
+        }
+
+        let year = ((30 * (fixed - EPOCH) + 10_646).div_euclid(10_631)) as i32;
+        let prior_days = fixed - fixed_from_hijri(year, 1, 1);
+        let month = ((11 * prior_days + 330).div_euclid(325)) as u32;
+        let day = (fixed - fixed_from_hijri(year, month, 1) + 1) as u32;
+
+        Some(HijriDate { year, month, day })
+    }
+
+    /// Returns the Gregorian date falling on the same day, or `None` if it cannot be represented.
+    pub fn to_gregorian(&self) -> Option<NaiveDate> {
+        from_rata_die(fixed_from_hijri(self.year, self.month, self.day))
+    }
+
+    /// Returns the year, counted from the Hijra.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, between 1 and 12.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+}
+
+/// Formats the date as `YYYY-MM-DD`.
+impl fmt::Display for HijriDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Returns whether the Hijri year is a leap year of 355 days.
+pub fn is_leap_year(year: i32) -> bool {
+    (14 + 11 * i64::from(year)).rem_euclid(30) < 11
+}
+
+/// Returns the number of days in the month of the Hijri year.
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    if month % 2 == 1 || (month == DHU_AL_HIJJAH && is_leap_year(year)) {
+        30
+    } else {
+        29
+    }
+}
+
+/// Returns every Gregorian date within the Gregorian year on which the Hijri month and day
+/// fall.
+///
+/// Because Hijri years are about eleven days shorter than Gregorian years, a Hijri date falls
+/// twice within some Gregorian years, roughly once every 33 years.
+#[cfg(feature = "alloc")]
+pub fn dates_in_gregorian_year(month: u32, day: u32, gregorian_year: i32) -> Vec<NaiveDate> {
+    let first_year = NaiveDate::from_ymd_opt(gregorian_year, 1, 1)
+        .and_then(|date| HijriDate::from_gregorian(&date))
+        .map_or(1, |date| date.year());
+
+    (first_year..=first_year + 1)
+        .filter_map(|year| HijriDate::new(year, month, day)?.to_gregorian())
+        .filter(|date| date.year() == gregorian_year)
+        .collect()
+}
+
+/// Returns the first days of Ramadan falling within the Gregorian year.
+#[cfg(feature = "alloc")]
+pub fn ramadan_begins(gregorian_year: i32) -> Vec<NaiveDate> {
+    dates_in_gregorian_year(RAMADAN, 1, gregorian_year)
+}
+
+/// Returns the dates of Eid al-Fitr (1 Shawwal) falling within the Gregorian year.
+#[cfg(feature = "alloc")]
+pub fn eid_al_fitr(gregorian_year: i32) -> Vec<NaiveDate> {
+    dates_in_gregorian_year(SHAWWAL, 1, gregorian_year)
+}
+
+/// Returns the dates of Eid al-Adha (10 Dhu al-Hijjah) falling within the Gregorian year.
+#[cfg(feature = "alloc")]
+pub fn eid_al_adha(gregorian_year: i32) -> Vec<NaiveDate> {
+    dates_in_gregorian_year(DHU_AL_HIJJAH, 10, gregorian_year)
+}
+
+/// Returns the dates of the Islamic New Year (1 Muharram) falling within the Gregorian year.
+#[cfg(feature = "alloc")]
+pub fn new_year(gregorian_year: i32) -> Vec<NaiveDate> {
+    dates_in_gregorian_year(MUHARRAM, 1, gregorian_year)
+}
+
+/// Returns the beginning of the Hijri month containing the provided date.
+pub fn beginning_of_month(date: &NaiveDate) -> Option<NaiveDate> {
+    let hijri = HijriDate::from_gregorian(date)?;
+    HijriDate::new(hijri.year, hijri.month, 1)?.to_gregorian()
+}
+
+/// Returns the end of the Hijri month containing the provided date.
+pub fn end_of_month(date: &NaiveDate) -> Option<NaiveDate> {
+    let hijri = HijriDate::from_gregorian(date)?;
+    let last_day = days_in_month(hijri.year, hijri.month);
+    HijriDate::new(hijri.year, hijri.month, last_day)?.to_gregorian()
+}
+
+/// Returns the beginning of the next Hijri month.
+pub fn next_month(date: &NaiveDate) -> Option<NaiveDate> {
+    end_of_month(date)?.succ_opt()
+}
+
+/// Returns the beginning of the previous Hijri month.
+pub fn previous_month(date: &NaiveDate) -> Option<NaiveDate> {
+    beginning_of_month(&beginning_of_month(date)?.pred_opt()?)
+}
+
+/// Returns the beginning of the Hijri year containing the provided date.
+pub fn beginning_of_year(date: &NaiveDate) -> Option<NaiveDate> {
+    let hijri = HijriDate::from_gregorian(date)?;
+    HijriDate::new(hijri.year, MUHARRAM, 1)?.to_gregorian()
+}
+
+/// Returns the end of the Hijri year containing the provided date.
+pub fn end_of_year(date: &NaiveDate) -> Option<NaiveDate> {
+    let hijri = HijriDate::from_gregorian(date)?;
+    let last_day = days_in_month(hijri.year, DHU_AL_HIJJAH);
+    HijriDate::new(hijri.year, DHU_AL_HIJJAH, last_day)?.to_gregorian()
+}
+
+/// Returns the beginning of the next Hijri year.
+pub fn next_year(date: &NaiveDate) -> Option<NaiveDate> {
+    end_of_year(date)?.succ_opt()
+}
+
+/// Returns the beginning of the previous Hijri year.
+pub fn previous_year(date: &NaiveDate) -> Option<NaiveDate> {
+    beginning_of_year(&beginning_of_year(date)?.pred_opt()?)
+}
+
+fn fixed_from_hijri(year: i32, month: u32, day: u32) -> i64 {
+    let year = i64::from(year);
+    let month = i64::from(month);
+
+    EPOCH - 1
+        + (year - 1) * 354
+        + (3 + 11 * year).div_euclid(30)
+        + 29 * (month - 1)
+        + (6 * month - 1).div_euclid(11)
+        + i64::from(day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn converts_known_dates() {
+        assert_eq!(
+            HijriDate::new(1, MUHARRAM, 1).unwrap().to_gregorian(),
+            Some(ymd(622, 7, 19))
+        );
+        assert_eq!(
+            HijriDate::from_gregorian(&ymd(2000, 1, 1)),
+            HijriDate::new(1420, RAMADAN, 24)
+        );
+        assert_eq!(HijriDate::from_gregorian(&ymd(622, 7, 18)), None);
+    }
+
+    #[test]
+    fn computes_year_structure() {
+        assert!(is_leap_year(1445));
+        assert!(!is_leap_year(1446));
+        assert_eq!(days_in_month(1445, DHU_AL_HIJJAH), 30);
+        assert_eq!(days_in_month(1446, DHU_AL_HIJJAH), 29);
+        assert_eq!(HijriDate::new(1446, DHU_AL_HIJJAH, 30), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn computes_holidays() {
+        assert_eq!(ramadan_begins(2025), vec![ymd(2025, 3, 1)]);
+        assert_eq!(eid_al_adha(2024), vec![ymd(2024, 6, 17)]);
+        assert_eq!(new_year(2024), vec![ymd(2024, 7, 8)]);
+        assert_eq!(eid_al_fitr(2033), vec![ymd(2033, 1, 3), ymd(2033, 12, 23)]);
+    }
+
+    #[test]
+    fn computes_hijri_boundaries() {
+        let date = ymd(2024, 3, 20);
+
+        assert_eq!(beginning_of_month(&date), Some(ymd(2024, 3, 11)));
+        assert_eq!(end_of_month(&date), Some(ymd(2024, 4, 9)));
+        assert_eq!(next_month(&date), Some(ymd(2024, 4, 10)));
+        assert_eq!(previous_month(&date), Some(ymd(2024, 2, 11)));
+        assert_eq!(beginning_of_year(&date), Some(ymd(2023, 7, 19)));
+        assert_eq!(end_of_year(&date), Some(ymd(2024, 7, 7)));
+        assert_eq!(next_year(&date), Some(ymd(2024, 7, 8)));
+        assert_eq!(previous_year(&date), Some(ymd(2022, 7, 30)));
+    }
+
+    #[quickcheck]
+    fn round_trips_through_hijri_dates(date: ArbitraryDate) -> bool {
+        let hijri = HijriDate::from_gregorian(&date.0).unwrap();
+
+        HijriDate::new(hijri.year(), hijri.month(), hijri.day()) == Some(hijri)
+            && hijri.to_gregorian() == Some(date.0)
+    }
+}
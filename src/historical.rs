@@ -0,0 +1,234 @@
+//! Historical dates across the Julian to Gregorian calendar cutover.
+//!
+//! The rest of this crate uses the proleptic Gregorian calendar, which is right for modern
+//! records but mislabels older ones: England dated documents by the Julian calendar until
+//! September 2, 1752, and the next day was September 14. A [`Cutover`] labels days by the Julian
+//! calendar before its first Gregorian day and by the Gregorian calendar from then on, so day
+//! arithmetic on [`HistoricalDate`] values skips the dropped days.
+//!
+//! Days are always labeled with years beginning on January 1, even where the civil year then
+//! began on March 25.
+//!
+//! ```
+//! use date_calculations::historical::Cutover;
+//!
+//! let britain = Cutover::great_britain();
+//! let last_julian = britain.date(1752, 9, 2).unwrap();
+//!
+//! assert_eq!(britain.next_day(&last_julian), britain.date(1752, 9, 14));
+//! assert_eq!(britain.date(1752, 9, 10), None);
+//! assert_eq!(britain.days_between(&last_julian, &britain.date(1752, 10, 2).unwrap()), Some(19));
+//! ```
+
+use crate::julian::JulianDate;
+use chrono::prelude::*;
+use chrono::Duration;
+use core::fmt;
+
+/// A day as written in a historical calendar: Julian before its cutover and Gregorian after.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HistoricalDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl HistoricalDate {
+    /// Returns the year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, between 1 and 12.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+}
+
+/// Formats the date as `YYYY-MM-DD`.
+impl fmt::Display for HistoricalDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// The day on which a country switched from the Julian to the Gregorian calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cutover {
+    first_gregorian_day: NaiveDate,
+    last_julian_day: HistoricalDate,
+}
+
+impl Cutover {
+    /// Builds a cutover whose first Gregorian day is `first_gregorian_day`, the day after the
+    /// last Julian one. Returns `None` for days before the Gregorian calendar's introduction on
+    /// October 15, 1582.
+    pub fn new(first_gregorian_day: NaiveDate) -> Option<Self> {
+        if first_gregorian_day < NaiveDate::from_ymd_opt(1582, 10, 15)? {
+            return None;
+        }
+
+        Some(Cutover {
+            first_gregorian_day,
+            last_julian_day: julian_label(&first_gregorian_day.pred_opt()?),
+        })
+    }
+
+    /// The original cutover in the Papal States, Spain, and Portugal, where October 4, 1582
+    /// was followed by October 15.
+    pub fn papal() -> Self {
+        Cutover::from_ymd(1582, 10, 15)
+    }
+
+    /// The cutover in Great Britain and its colonies, where September 2, 1752 was followed by
+    /// September 14.
+    pub fn great_britain() -> Self {
+        Cutover::from_ymd(1752, 9, 14)
+    }
+
+    /// The cutover in Russia, where January 31, 1918 was followed by February 14.
+    pub fn russia() -> Self {
+        Cutover::from_ymd(1918, 2, 14)
+    }
+
+    /// Returns the first day labeled with the Gregorian calendar.
+    pub fn first_gregorian_day(&self) -> NaiveDate {
+        self.first_gregorian_day
+    }
+
+    /// Returns the historical date with the year, month, and day, or `None` if it does not
+    /// exist in its calendar or was dropped at the cutover.
+    pub fn date(&self, year: i32, month: u32, day: u32) -> Option<HistoricalDate> {
+        let date = HistoricalDate { year, month, day };
+        self.to_gregorian(&date).map(|_| date)
+    }
+
+    /// Returns the historical label of a proleptic Gregorian date.
+    pub fn from_gregorian(&self, date: &NaiveDate) -> HistoricalDate {
+        if *date >= self.first_gregorian_day {
+            HistoricalDate {
+                year: date.year(),
+                month: date.month(),
+                day: date.day(),
+            }
+        } else {
+            julian_label(date)
+        }
+    }
+
+    /// Returns the proleptic Gregorian date of a historical label, or `None` if the label was
+    /// dropped at the cutover.
+    pub fn to_gregorian(&self, date: &HistoricalDate) -> Option<NaiveDate> {
+        if *date > self.last_julian_day {
+            NaiveDate::from_ymd_opt(date.year, date.month, date.day)
+                .filter(|gregorian| *gregorian >= self.first_gregorian_day)
+        } else {
+            JulianDate::new(date.year, date.month, date.day)?.to_gregorian()
+        }
+    }
+
+    /// Returns whether the date is labeled with the Julian calendar.
+    pub fn is_julian(&self, date: &HistoricalDate) -> bool {
+        *date <= self.last_julian_day
+    }
+
+    /// Returns the day after the provided date, skipping days dropped at the cutover.
+    pub fn next_day(&self, date: &HistoricalDate) -> Option<HistoricalDate> {
+        self.add_days(date, 1)
+    }
+
+    /// Returns the day before the provided date, skipping days dropped at the cutover.
+    pub fn previous_day(&self, date: &HistoricalDate) -> Option<HistoricalDate> {
+        self.add_days(date, -1)
+    }
+
+    /// Returns the date a number of elapsed days after the provided date.
+    pub fn add_days(&self, date: &HistoricalDate, days: i64) -> Option<HistoricalDate> {
+        let shifted = self
+            .to_gregorian(date)?
+            .checked_add_signed(Duration::try_days(days)?)?;
+
+        Some(self.from_gregorian(&shifted))
+    }
+
+    /// Returns the number of days elapsed from `start` to `end`, negative if `end` comes first.
+    pub fn days_between(&self, start: &HistoricalDate, end: &HistoricalDate) -> Option<i64> {
+        let start = self.to_gregorian(start)?;
+        let end = self.to_gregorian(end)?;
+
+        Some(end.signed_duration_since(start).num_days())
+    }
+
+    fn from_ymd(year: i32, month: u32, day: u32) -> Self {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(Cutover::new)
+            .expect("cutover dates are valid")
+    }
+}
+
+fn julian_label(date: &NaiveDate) -> HistoricalDate {
+    let julian = JulianDate::from_gregorian(date);
+
+    HistoricalDate {
+        year: julian.year(),
+        month: julian.month(),
+        day: julian.day(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn labels_days_around_the_cutover() {
+        let papal = Cutover::papal();
+        let last_julian = papal.date(1582, 10, 4).unwrap();
+
+        assert!(papal.is_julian(&last_julian));
+        assert_eq!(papal.to_gregorian(&last_julian), Some(ymd(1582, 10, 14)));
+        assert_eq!(
+            papal.from_gregorian(&ymd(1582, 10, 15)),
+            papal.date(1582, 10, 15).unwrap()
+        );
+        assert_eq!(papal.last_julian_day.to_string(), "1582-10-04");
+
+        for day in 5..=14 {
+            assert_eq!(papal.date(1582, 10, day), None, "{}", day);
+        }
+    }
+
+    #[test]
+    fn accepts_julian_leap_days() {
+        let britain = Cutover::great_britain();
+
+        // 1700 was a leap year only in the Julian calendar
+        let leap_day = britain.date(1700, 2, 29).unwrap();
+        assert_eq!(britain.next_day(&leap_day), britain.date(1700, 3, 1));
+        assert_eq!(Cutover::papal().date(1700, 2, 29), None);
+    }
+
+    #[test]
+    fn counts_elapsed_days() {
+        let russia = Cutover::russia();
+        let start = russia.date(1918, 1, 31).unwrap();
+        let end = russia.date(1918, 2, 14).unwrap();
+
+        assert_eq!(russia.days_between(&start, &end), Some(1));
+        assert_eq!(russia.days_between(&end, &start), Some(-1));
+        assert_eq!(russia.previous_day(&end), Some(start));
+        assert_eq!(russia.add_days(&start, 29), russia.date(1918, 3, 14));
+        assert_eq!(russia.add_days(&start, i64::MAX), None);
+        assert_eq!(russia.add_days(&start, i64::MIN), None);
+        assert_eq!(Cutover::new(ymd(1500, 1, 1)), None);
+    }
+}
@@ -0,0 +1,160 @@
+//! Formatting of the relationship between two dates as human-readable phrases.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::humanize::{humanize, Granularity};
+//! use date_calculations::Period;
+//!
+//! let reference = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+//! let later = NaiveDate::from_ymd_opt(2024, 6, 7).unwrap();
+//!
+//! assert_eq!(humanize(&later, &reference, Granularity::Period(Period::Week)), "in 3 weeks");
+//! assert_eq!(humanize(&later, &reference, Granularity::Period(Period::Month)), "next month");
+//! ```
+
+use crate::Period;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use chrono::prelude::*;
+
+/// The unit used to describe the distance between two dates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Granularity {
+    /// Count individual days.
+    Day,
+    /// Count calendar periods (weeks, months, quarters, or years).
+    Period(Period),
+    /// Pick a unit based on how far apart the dates are.
+    Automatic,
+}
+
+/// Describes `date` relative to `reference`, e.g. "in 3 weeks", "2 quarters ago", or "later this
+/// month".
+///
+/// Periods are counted by the boundaries crossed (see [`Period::count_between`]), so a date in
+/// the following calendar month is "next month" even if it is only a day away. Dates within the
+/// same period are described as "earlier this …" or "later this …".
+///
+/// With [`Granularity::Automatic`], dates less than a week apart are counted in days, less than a
+/// month apart in weeks, less than a quarter apart in months, less than a year apart in quarters,
+/// and otherwise in years.
+pub fn humanize(date: &NaiveDate, reference: &NaiveDate, granularity: Granularity) -> String {
+    match granularity {
+        Granularity::Day => humanize_days(date.signed_duration_since(*reference).num_days()),
+        Granularity::Period(period) => humanize_period(date, reference, period),
+        Granularity::Automatic => {
+            let days = date.signed_duration_since(*reference).num_days().abs();
+            let period = match days {
+                0..=6 => return humanize(date, reference, Granularity::Day),
+                7..=30 => Period::Week,
+                31..=91 => Period::Month,
+                92..=365 => Period::Quarter,
+                _ => Period::Year,
+            };
+
+            humanize_period(date, reference, period)
+        }
+    }
+}
+
+fn humanize_days(days: i64) -> String {
+    match days {
+        0 => "today".to_string(),
+        1 => "tomorrow".to_string(),
+        -1 => "yesterday".to_string(),
+        n if n > 0 => format!("in {} days", n),
+        n => format!("{} days ago", -n),
+    }
+}
+
+fn humanize_period(date: &NaiveDate, reference: &NaiveDate, period: Period) -> String {
+    let name = period.name();
+
+    match period.count_between(reference, date).unwrap_or(0) {
+        0 if date == reference => format!("this {}", name),
+        0 if date > reference => format!("later this {}", name),
+        0 => format!("earlier this {}", name),
+        1 => format!("next {}", name),
+        -1 => format!("last {}", name),
+        n if n > 0 => format!("in {} {}s", n, name),
+        n => format!("{} {}s ago", -n, name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn humanizes_days() {
+        let reference = ymd(2024, 5, 17);
+
+        assert_eq!(humanize(&reference, &reference, Granularity::Day), "today");
+        assert_eq!(
+            humanize(&ymd(2024, 5, 18), &reference, Granularity::Day),
+            "tomorrow"
+        );
+        assert_eq!(
+            humanize(&ymd(2024, 5, 16), &reference, Granularity::Day),
+            "yesterday"
+        );
+        assert_eq!(
+            humanize(&ymd(2024, 5, 27), &reference, Granularity::Day),
+            "in 10 days"
+        );
+        assert_eq!(
+            humanize(&ymd(2024, 5, 7), &reference, Granularity::Day),
+            "10 days ago"
+        );
+    }
+
+    #[test]
+    fn humanizes_periods() {
+        let reference = ymd(2024, 5, 17);
+        let quarter = Granularity::Period(Period::Quarter);
+        let month = Granularity::Period(Period::Month);
+
+        assert_eq!(
+            humanize(&ymd(2023, 11, 2), &reference, quarter),
+            "2 quarters ago"
+        );
+        assert_eq!(
+            humanize(&ymd(2024, 5, 30), &reference, month),
+            "later this month"
+        );
+        assert_eq!(
+            humanize(&ymd(2024, 5, 1), &reference, month),
+            "earlier this month"
+        );
+        assert_eq!(humanize(&ymd(2024, 4, 30), &reference, month), "last month");
+        assert_eq!(humanize(&reference, &reference, month), "this month");
+    }
+
+    #[test]
+    fn humanizes_automatically() {
+        let reference = ymd(2024, 5, 17);
+
+        assert_eq!(
+            humanize(&ymd(2024, 5, 20), &reference, Granularity::Automatic),
+            "in 3 days"
+        );
+        assert_eq!(
+            humanize(&ymd(2024, 6, 7), &reference, Granularity::Automatic),
+            "in 3 weeks"
+        );
+        assert_eq!(
+            humanize(&ymd(2024, 3, 1), &reference, Granularity::Automatic),
+            "2 months ago"
+        );
+        assert_eq!(
+            humanize(&ymd(2027, 1, 1), &reference, Granularity::Automatic),
+            "in 3 years"
+        );
+    }
+}
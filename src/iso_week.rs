@@ -0,0 +1,272 @@
+use crate::year_month::DisplayYear;
+use crate::{DateRange, ParseError};
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+use chrono::prelude::*;
+use chrono::IsoWeek;
+use core::fmt;
+use core::str::FromStr;
+
+const EXPECTED: &str = "an ISO 8601 week like \"2024-W15\" or \"2024W15\"";
+const EXPECTED_DATE: &str = "an ISO 8601 week date like \"2024-W15-3\" or \"2024W153\"";
+
+/// A week of an ISO 8601 week-numbering year.
+///
+/// ISO weeks begin on Monday, and week 1 is the week containing the year's first Thursday, so
+/// the week-numbering year can differ from the calendar year near January 1.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::IsoYearWeek;
+///
+/// let week: IsoYearWeek = "2024-W15".parse().unwrap();
+///
+/// assert_eq!(week.first_day(), NaiveDate::from_ymd_opt(2024, 4, 8));
+/// assert_eq!(week.last_day(), NaiveDate::from_ymd_opt(2024, 4, 14));
+/// assert_eq!(week.to_string(), "2024-W15");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IsoYearWeek {
+    year: i32,
+    week: u32,
+}
+
+impl IsoYearWeek {
+    /// Builds a week from an ISO week-numbering year and a week number, returning `None` if the
+    /// year has no such week.
+    pub fn new(year: i32, week: u32) -> Option<Self> {
+        NaiveDate::from_isoywd_opt(year, week, Weekday::Mon).map(|_| IsoYearWeek { year, week })
+    }
+
+    /// Returns the ISO week containing the provided date.
+    pub fn from_date(date: &NaiveDate) -> Self {
+        IsoYearWeek::from(date.iso_week())
+    }
+
+    /// Returns the ISO week-numbering year, which may differ from the calendar year of the
+    /// week's days.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the week number, between 1 and 53.
+    pub fn week(&self) -> u32 {
+        self.week
+    }
+
+    /// Returns the day of the week falling on the weekday.
+    pub fn day(&self, weekday: Weekday) -> Option<NaiveDate> {
+        NaiveDate::from_isoywd_opt(self.year, self.week, weekday)
+    }
+
+    /// Returns the first day of the week, a Monday.
+    pub fn first_day(&self) -> Option<NaiveDate> {
+        self.day(Weekday::Mon)
+    }
+
+    /// Returns the last day of the week, a Sunday.
+    pub fn last_day(&self) -> Option<NaiveDate> {
+        self.day(Weekday::Sun)
+    }
+
+    /// Returns the range of dates within the week.
+    pub fn range(&self) -> Option<DateRange> {
+        DateRange::new(self.first_day()?, self.last_day()?)
+    }
+}
+
+impl From<IsoWeek> for IsoYearWeek {
+    fn from(week: IsoWeek) -> Self {
+        IsoYearWeek {
+            year: week.year(),
+            week: week.week(),
+        }
+    }
+}
+
+/// Formats the week as `2024-W15`.
+impl fmt::Display for IsoYearWeek {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-W{:02}", DisplayYear(self.year), self.week)
+    }
+}
+
+/// Parses weeks written in the extended (`2024-W15`) or basic (`2024W15`) ISO 8601 formats.
+impl FromStr for IsoYearWeek {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || ParseError::new(input, EXPECTED);
+        let (week, weekday) = split_week_date(input.trim()).ok_or_else(error)?;
+
+        match weekday {
+            None => Ok(week),
+            Some(_) => Err(error()),
+        }
+    }
+}
+
+/// Formats the date as an ISO 8601 week date, like `2024-W15-3`.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::format_week_date;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+///
+/// assert_eq!(format_week_date(&date), "2025-W01-1");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn format_week_date(date: &NaiveDate) -> String {
+    format!(
+        "{}-{}",
+        IsoYearWeek::from_date(date),
+        date.weekday().number_from_monday()
+    )
+}
+
+/// Parses an ISO 8601 week date in the extended (`2024-W15-3`) or basic (`2024W153`) format,
+/// where the final digit is the weekday counting Monday as 1.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::parse_week_date;
+///
+/// assert_eq!(parse_week_date("2024-W15-3"), Ok(NaiveDate::from_ymd_opt(2024, 4, 10).unwrap()));
+/// assert_eq!(parse_week_date("2024W153"), parse_week_date("2024-W15-3"));
+/// ```
+pub fn parse_week_date(input: &str) -> Result<NaiveDate, ParseError> {
+    let error = || ParseError::new(input, EXPECTED_DATE);
+
+    match split_week_date(input.trim()) {
+        Some((week, Some(weekday))) => week.day(weekday).ok_or_else(error),
+        _ => Err(error()),
+    }
+}
+
+// Splits `YYYY-Www[-D]` or `YYYYWww[D]` into its week and optional weekday; the separators must
+// be used consistently
+fn split_week_date(input: &str) -> Option<(IsoYearWeek, Option<Weekday>)> {
+    let (year, rest) = input.split_at(input.find(['W', 'w'])?);
+    let rest = &rest[1..];
+    if !rest.is_ascii() {
+        return None;
+    }
+
+    let (year, extended) = match year.strip_suffix('-') {
+        Some(year) => (year, true),
+        None => (year, false),
+    };
+
+    let (week, weekday) = match (extended, rest.len()) {
+        (_, 2) => (rest, None),
+        (true, 4) if rest.as_bytes()[2] == b'-' => (&rest[..2], Some(&rest[3..])),
+        (false, 3) => (&rest[..2], Some(&rest[2..])),
+        _ => return None,
+    };
+
+    if year.len() != 4 || !is_digits(year) || !is_digits(week) {
+        return None;
+    }
+
+    let week = IsoYearWeek::new(year.parse().ok()?, week.parse().ok()?)?;
+    let weekday = match weekday {
+        Some(digit) => Some(weekday_from_number(digit)?),
+        None => None,
+    };
+
+    Some((week, weekday))
+}
+
+fn weekday_from_number(digit: &str) -> Option<Weekday> {
+    match digit {
+        "1" => Some(Weekday::Mon),
+        "2" => Some(Weekday::Tue),
+        "3" => Some(Weekday::Wed),
+        "4" => Some(Weekday::Thu),
+        "5" => Some(Weekday::Fri),
+        "6" => Some(Weekday::Sat),
+        "7" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn is_digits(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use crate::testing::ArbitraryDate;
+    #[cfg(feature = "alloc")]
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn parses_week_labels() {
+        let expected = IsoYearWeek::new(2020, 53);
+
+        assert_eq!("2020-W53".parse().ok(), expected);
+        assert_eq!("2020W53".parse().ok(), expected);
+        assert_eq!(" 2020-w53 ".parse().ok(), expected);
+    }
+
+    #[test]
+    fn rejects_invalid_weeks() {
+        for input in &[
+            "2021-W53",
+            "2024-W00",
+            "2024-W5",
+            "24-W15",
+            "2024-W15-3",
+            "2024W",
+            "W15",
+            "",
+        ] {
+            assert!(input.parse::<IsoYearWeek>().is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn parses_week_dates() {
+        assert_eq!(parse_week_date("2009-W01-1"), Ok(ymd(2008, 12, 29)));
+        assert_eq!(parse_week_date("2009W537"), Ok(ymd(2010, 1, 3)));
+
+        for input in &[
+            "2024-W15",
+            "2024-W15-8",
+            "2024-W15-0",
+            "2024W15-3",
+            "2024-W153",
+            "2024-W15-33",
+            "2024W1é",
+        ] {
+            assert!(parse_week_date(input).is_err(), "{}", input);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn builds_from_dates() {
+        let week = IsoYearWeek::from_date(&ymd(2027, 1, 1));
+
+        assert_eq!(week, IsoYearWeek::new(2026, 53).unwrap());
+        assert_eq!(week.first_day(), Some(ymd(2026, 12, 28)));
+        assert_eq!(week.last_day(), Some(ymd(2027, 1, 3)));
+        assert_eq!(format_week_date(&ymd(2027, 1, 1)), "2026-W53-5");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[quickcheck]
+    fn week_dates_round_trip(date: ArbitraryDate) -> bool {
+        let week = IsoYearWeek::from_date(&date.0);
+
+        parse_week_date(&format_week_date(&date.0)) == Ok(date.0)
+            && week.to_string().parse() == Ok(week)
+    }
+}
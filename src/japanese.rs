@@ -0,0 +1,300 @@
+//! Japanese era (nengō) year handling for the eras since the Meiji Restoration.
+//!
+//! An era year counts from 1 on the day the era begins, and the following year 2 begins on
+//! January 1, so the first and last years of an era are usually partial: 2019 was both Heisei 31
+//! (through April 30) and Reiwa 1 (from May 1).
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::japanese::{Era, EraYear};
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+//! let era_year = EraYear::from_date(&date).unwrap();
+//!
+//! assert_eq!(era_year, EraYear::new(Era::Reiwa, 6).unwrap());
+//! assert_eq!(era_year.to_string(), "Reiwa 6");
+//! assert_eq!("平成31年".parse::<EraYear>().unwrap().gregorian_year(), 2019);
+//! ```
+
+use crate::ParseError;
+use chrono::prelude::*;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+const EXPECTED: &str = "an era year like \"Reiwa 6\", \"R6\", or \"令和6年\"";
+
+/// A Japanese era.
+///
+/// Meiji began before Japan adopted the Gregorian calendar in 1873; its start is given as the
+/// Gregorian equivalent of the proclamation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Era {
+    /// Meiji (明治), from October 23, 1868.
+    Meiji,
+    /// Taishō (大正), from July 30, 1912.
+    Taisho,
+    /// Shōwa (昭和), from December 25, 1926.
+    Showa,
+    /// Heisei (平成), from January 8, 1989.
+    Heisei,
+    /// Reiwa (令和), from May 1, 2019.
+    Reiwa,
+}
+
+const ERAS: [Era; 5] = [Era::Meiji, Era::Taisho, Era::Showa, Era::Heisei, Era::Reiwa];
+
+impl Era {
+    /// Returns the era containing the date, or `None` for dates before the Meiji era.
+    pub fn from_date(date: &NaiveDate) -> Option<Self> {
+        ERAS.iter().rev().copied().find(|era| era.start() <= *date)
+    }
+
+    /// Returns the first day of the era.
+    pub fn start(self) -> NaiveDate {
+        let (year, month, day) = match self {
+            Era::Meiji => (1868, 10, 23),
+            Era::Taisho => (1912, 7, 30),
+            Era::Showa => (1926, 12, 25),
+            Era::Heisei => (1989, 1, 8),
+            Era::Reiwa => (2019, 5, 1),
+        };
+
+        NaiveDate::from_ymd_opt(year, month, day).expect("era start dates are valid")
+    }
+
+    /// Returns the last day of the era, or `None` for the current era.
+    pub fn end(self) -> Option<NaiveDate> {
+        self.successor()?.start().pred_opt()
+    }
+
+    /// Returns the following era, or `None` for the current era.
+    pub fn successor(self) -> Option<Self> {
+        ERAS.get(self as usize + 1).copied()
+    }
+
+    /// Returns the era's name in kanji.
+    pub fn kanji(self) -> &'static str {
+        match self {
+            Era::Meiji => "明治",
+            Era::Taisho => "大正",
+            Era::Showa => "昭和",
+            Era::Heisei => "平成",
+            Era::Reiwa => "令和",
+        }
+    }
+
+    /// Returns the single-letter abbreviation used on forms: M, T, S, H, or R.
+    pub fn abbreviation(self) -> char {
+        self.name().chars().next().expect("era names are not empty")
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Era::Meiji => "Meiji",
+            Era::Taisho => "Taisho",
+            Era::Showa => "Showa",
+            Era::Heisei => "Heisei",
+            Era::Reiwa => "Reiwa",
+        }
+    }
+}
+
+/// Formats the era's romanized name, e.g. `Heisei`.
+impl fmt::Display for Era {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A year of a Japanese era.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EraYear {
+    era: Era,
+    year: u32,
+}
+
+impl EraYear {
+    /// Builds an era year, returning `None` if the year is zero or falls after the era ended.
+    pub fn new(era: Era, year: u32) -> Option<Self> {
+        let last_year = era.end().map_or(i32::MAX, |end| end.year());
+
+        match gregorian_year(era, year) {
+            Some(gregorian) if year >= 1 && gregorian <= last_year => Some(EraYear { era, year }),
+            _ => None,
+        }
+    }
+
+    /// Returns the era year containing the date, or `None` for dates before the Meiji era.
+    pub fn from_date(date: &NaiveDate) -> Option<Self> {
+        let era = Era::from_date(date)?;
+
+        Some(EraYear {
+            era,
+            year: (date.year() - era.start().year() + 1) as u32,
+        })
+    }
+
+    /// Returns the era.
+    pub fn era(&self) -> Era {
+        self.era
+    }
+
+    /// Returns the year within the era, counting from 1.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// Returns the Gregorian year in which the era year falls.
+    pub fn gregorian_year(&self) -> i32 {
+        gregorian_year(self.era, self.year).expect("era years fit within an i32")
+    }
+
+    /// Returns the first day of the era year: the era's start for year 1, and January 1 otherwise.
+    pub fn first_day(&self) -> Option<NaiveDate> {
+        if self.year == 1 {
+            Some(self.era.start())
+        } else {
+            NaiveDate::from_ymd_opt(self.gregorian_year(), 1, 1)
+        }
+    }
+
+    /// Returns the last day of the era year: the era's end for its final year, and December 31
+    /// otherwise.
+    pub fn last_day(&self) -> Option<NaiveDate> {
+        match self.era.end() {
+            Some(end) if end.year() == self.gregorian_year() => Some(end),
+            _ => NaiveDate::from_ymd_opt(self.gregorian_year(), 12, 31),
+        }
+    }
+}
+
+/// Formats the era year as `Reiwa 6`.
+impl fmt::Display for EraYear {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.era, self.year)
+    }
+}
+
+/// Parses era years written with romanized names (`Reiwa 6`, `Shōwa 64`), single-letter
+/// abbreviations (`R6`, `H31`), or kanji (`令和6年`, with `元年` for the first year).
+impl FromStr for EraYear {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || ParseError::new(input, EXPECTED);
+        let trimmed = input.trim().trim_end_matches('年');
+
+        let (era, rest) = ERAS
+            .iter()
+            .find_map(|era| {
+                let mut abbreviation = [0; 4];
+                let names = [
+                    era.kanji(),
+                    era.name(),
+                    era.abbreviation().encode_utf8(&mut abbreviation),
+                ];
+                names
+                    .iter()
+                    .find_map(|name| strip_name(trimmed, name))
+                    .map(|rest| (*era, rest.trim_start()))
+            })
+            .ok_or_else(error)?;
+
+        let year = match rest {
+            "元" => 1,
+            digits if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) => {
+                digits.parse().map_err(|_| error())?
+            }
+            _ => return Err(error()),
+        };
+
+        EraYear::new(era, year).ok_or_else(error)
+    }
+}
+
+fn gregorian_year(era: Era, year: u32) -> Option<i32> {
+    (era.start().year() - 1).checked_add(i32::try_from(year).ok()?)
+}
+
+// Strips an era name from the start of the input, ignoring case and the macron in `Shōwa`
+fn strip_name<'a>(input: &'a str, name: &str) -> Option<&'a str> {
+    let fold = |c: char| match c {
+        'ō' | 'Ō' => 'o',
+        other => other.to_ascii_lowercase(),
+    };
+    let mut chars = input.chars();
+
+    for expected in name.chars() {
+        if fold(chars.next()?) != fold(expected) {
+            return None;
+        }
+    }
+    Some(chars.as_str())
+}
+
+/// Returns the first day of the era containing the provided date.
+pub fn beginning_of_era(date: &NaiveDate) -> Option<NaiveDate> {
+    Some(Era::from_date(date)?.start())
+}
+
+/// Returns the era year containing the provided date.
+pub fn era_year(date: &NaiveDate) -> Option<EraYear> {
+    EraYear::from_date(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn finds_eras_around_boundaries() {
+        assert_eq!(era_year(&ymd(2019, 4, 30)), EraYear::new(Era::Heisei, 31));
+        assert_eq!(era_year(&ymd(2019, 5, 1)), EraYear::new(Era::Reiwa, 1));
+        assert_eq!(era_year(&ymd(1989, 1, 7)), EraYear::new(Era::Showa, 64));
+        assert_eq!(era_year(&ymd(1868, 10, 22)), None);
+        assert_eq!(beginning_of_era(&ymd(2000, 6, 1)), Some(ymd(1989, 1, 8)));
+        assert_eq!(Era::Showa.end(), Some(ymd(1989, 1, 7)));
+        assert_eq!(Era::Reiwa.end(), None);
+    }
+
+    #[test]
+    fn computes_era_year_spans() {
+        let heisei_31 = EraYear::new(Era::Heisei, 31).unwrap();
+        assert_eq!(heisei_31.first_day(), Some(ymd(2019, 1, 1)));
+        assert_eq!(heisei_31.last_day(), Some(ymd(2019, 4, 30)));
+
+        let reiwa_1 = EraYear::new(Era::Reiwa, 1).unwrap();
+        assert_eq!(reiwa_1.first_day(), Some(ymd(2019, 5, 1)));
+        assert_eq!(reiwa_1.last_day(), Some(ymd(2019, 12, 31)));
+
+        assert_eq!(EraYear::new(Era::Heisei, 32), None);
+        assert_eq!(EraYear::new(Era::Reiwa, 0), None);
+        assert_eq!(EraYear::new(Era::Reiwa, u32::MAX), None);
+        assert_eq!(EraYear::new(Era::Reiwa, i32::MAX as u32), None);
+        assert_eq!(
+            EraYear::new(Era::Reiwa, i32::MAX as u32 - 2018).map(|year| year.gregorian_year()),
+            Some(i32::MAX)
+        );
+    }
+
+    #[test]
+    fn parses_era_years() {
+        let expected = EraYear::new(Era::Showa, 64);
+
+        assert_eq!("Showa 64".parse().ok(), expected);
+        assert_eq!("Shōwa 64".parse().ok(), expected);
+        assert_eq!("S64".parse().ok(), expected);
+        assert_eq!("昭和64年".parse().ok(), expected);
+        assert_eq!("令和元年".parse().ok(), EraYear::new(Era::Reiwa, 1));
+        assert_eq!("taisho15".parse().ok(), EraYear::new(Era::Taisho, 15));
+
+        for input in &["Showa 65", "R", "Reiwa six", "X5", ""] {
+            assert!(input.parse::<EraYear>().is_err(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Conversion between proleptic Gregorian and proleptic Julian dates, and period boundaries in
+//! the Julian calendar.
+//!
+//! Years use astronomical numbering, so 1 BC is year 0, matching chrono. The boundary functions
+//! take and return Gregorian [`NaiveDate`] values, so they can be mixed freely with the rest of
+//! this crate.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::julian::{self, JulianDate};
+//!
+//! // Orthodox Christmas, December 25 in the Julian calendar
+//! let christmas = JulianDate::new(2024, 12, 25).unwrap();
+//! assert_eq!(christmas.to_gregorian(), NaiveDate::from_ymd_opt(2025, 1, 7));
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 5, 20).unwrap();
+//! assert_eq!(julian::beginning_of_month(&date), NaiveDate::from_ymd_opt(2024, 5, 14));
+//! ```
+
+use crate::day_number::{from_julian_day_number, to_julian_day_number};
+use chrono::prelude::*;
+use core::fmt;
+
+/// A date in the proleptic Julian calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JulianDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl JulianDate {
+    /// Builds a Julian date, returning `None` if the month or day is out of range.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month) {
+            Some(JulianDate { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the Julian date falling on the same day as the Gregorian date.
+    pub fn from_gregorian(date: &NaiveDate) -> Self {
+        let c = to_julian_day_number(date) + 32082;
+        let d = (4 * c + 3).div_euclid(1461);
+        let e = c - (1461 * d).div_euclid(4);
+        let m = (5 * e + 2).div_euclid(153);
+
+        JulianDate {
+            year: (d - 4800 + m / 10) as i32,
+            month: (m + 3 - 12 * (m / 10)) as u32,
+            day: (e - (153 * m + 2).div_euclid(5) + 1) as u32,
+        }
+    }
+
+    /// Returns the Gregorian date falling on the same day, or `None` if it cannot be represented.
+    pub fn to_gregorian(&self) -> Option<NaiveDate> {
+        let a = (14 - i64::from(self.month)) / 12;
+        let y = i64::from(self.year) + 4800 - a;
+        let m = i64::from(self.month) + 12 * a - 3;
+        let jdn =
+            i64::from(self.day) + (153 * m + 2).div_euclid(5) + 365 * y + y.div_euclid(4) - 32083;
+
+        from_julian_day_number(jdn)
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, between 1 and 12.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+}
+
+/// Formats the date as `YYYY-MM-DD`.
+impl fmt::Display for JulianDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Returns whether the year is a leap year in the Julian calendar.
+pub fn is_leap_year(year: i32) -> bool {
+    year.rem_euclid(4) == 0
+}
+
+/// Returns the number of days in the month of the Julian calendar.
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the beginning of the Julian month containing the provided date.
+pub fn beginning_of_month(date: &NaiveDate) -> Option<NaiveDate> {
+    let julian = JulianDate::from_gregorian(date);
+    JulianDate::new(julian.year, julian.month, 1)?.to_gregorian()
+}
+
+/// Returns the end of the Julian month containing the provided date.
+pub fn end_of_month(date: &NaiveDate) -> Option<NaiveDate> {
+    let julian = JulianDate::from_gregorian(date);
+    let last_day = days_in_month(julian.year, julian.month);
+    JulianDate::new(julian.year, julian.month, last_day)?.to_gregorian()
+}
+
+/// Returns the beginning of the next Julian month.
+pub fn next_month(date: &NaiveDate) -> Option<NaiveDate> {
+    end_of_month(date)?.succ_opt()
+}
+
+/// Returns the beginning of the previous Julian month.
+pub fn previous_month(date: &NaiveDate) -> Option<NaiveDate> {
+    beginning_of_month(&beginning_of_month(date)?.pred_opt()?)
+}
+
+/// Returns the beginning of the Julian year containing the provided date.
+pub fn beginning_of_year(date: &NaiveDate) -> Option<NaiveDate> {
+    let julian = JulianDate::from_gregorian(date);
+    JulianDate::new(julian.year, 1, 1)?.to_gregorian()
+}
+
+/// Returns the end of the Julian year containing the provided date.
+pub fn end_of_year(date: &NaiveDate) -> Option<NaiveDate> {
+    let julian = JulianDate::from_gregorian(date);
+    JulianDate::new(julian.year, 12, 31)?.to_gregorian()
+}
+
+/// Returns the beginning of the next Julian year.
+pub fn next_year(date: &NaiveDate) -> Option<NaiveDate> {
+    end_of_year(date)?.succ_opt()
+}
+
+/// Returns the beginning of the previous Julian year.
+pub fn previous_year(date: &NaiveDate) -> Option<NaiveDate> {
+    beginning_of_year(&beginning_of_year(date)?.pred_opt()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn converts_known_dates() {
+        // the Gregorian reform: Julian Thursday October 4 was followed by Gregorian Friday
+        // October 15, 1582
+        assert_eq!(
+            JulianDate::new(1582, 10, 5).unwrap().to_gregorian(),
+            Some(ymd(1582, 10, 15))
+        );
+        assert_eq!(
+            JulianDate::from_gregorian(&ymd(2024, 3, 13)),
+            JulianDate::new(2024, 2, 29).unwrap()
+        );
+        // the calendars agree through the third century
+        assert_eq!(
+            JulianDate::from_gregorian(&ymd(250, 6, 1)),
+            JulianDate::new(250, 6, 1).unwrap()
+        );
+        assert_eq!(
+            JulianDate::new(-44, 3, 15).unwrap().to_gregorian(),
+            Some(ymd(-44, 3, 13))
+        );
+    }
+
+    #[test]
+    fn validates_julian_dates() {
+        assert!(JulianDate::new(1900, 2, 29).is_some());
+        assert!(JulianDate::new(1901, 2, 29).is_none());
+        assert!(JulianDate::new(2024, 13, 1).is_none());
+        assert!(JulianDate::new(2024, 4, 31).is_none());
+        assert_eq!(
+            JulianDate::new(988, 7, 28).unwrap().to_string(),
+            "0988-07-28"
+        );
+    }
+
+    #[test]
+    fn computes_julian_boundaries() {
+        let date = ymd(2024, 1, 10);
+
+        assert_eq!(beginning_of_month(&date), Some(ymd(2023, 12, 14)));
+        assert_eq!(end_of_month(&date), Some(ymd(2024, 1, 13)));
+        assert_eq!(next_month(&date), Some(ymd(2024, 1, 14)));
+        assert_eq!(previous_month(&date), Some(ymd(2023, 11, 14)));
+        assert_eq!(beginning_of_year(&date), Some(ymd(2023, 1, 14)));
+        assert_eq!(end_of_year(&date), Some(ymd(2024, 1, 13)));
+        assert_eq!(next_year(&date), Some(ymd(2024, 1, 14)));
+        assert_eq!(previous_year(&date), Some(ymd(2022, 1, 14)));
+    }
+
+    #[quickcheck]
+    fn round_trips_through_julian_dates(date: ArbitraryDate) -> bool {
+        let julian = JulianDate::from_gregorian(&date.0);
+
+        JulianDate::new(julian.year(), julian.month(), julian.day()) == Some(julian)
+            && julian.to_gregorian() == Some(date.0)
+    }
+}
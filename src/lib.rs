@@ -1,4 +1,5 @@
 #![deny(missing_docs)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
 //! This crate provides helper functions for calculating shifts in Chrono's NaiveDate values for
 //! various periods (week, month, quarter, year) for common shifts in direction (beginning_of_*,
@@ -6,6 +7,17 @@
 //!
 //! The dates passed to these functions should be Gregorian dates to ensure proper calcuation.
 //!
+//! Years before 1 follow chrono's proleptic Gregorian calendar with astronomical numbering: year
+//! 0 is 1 BCE, year -1 is 2 BCE, and so on. Every rule applies unchanged across year 0, which is
+//! a leap year like any other year divisible by 400, and years print as chrono prints them
+//! (`-0001-03` for March of 2 BCE).
+//!
+//! Boundaries within the current month, quarter, or year always exist, so those functions return
+//! a `NaiveDate` directly. Week boundaries and shifts to the next or previous period can fall
+//! outside `NaiveDate::MIN..=NaiveDate::MAX` for dates at chrono's limits; those return an
+//! `Option` that is `None` only there. [`Period`]'s `*_with` methods take a [`RangePolicy`] to
+//! either report those cases as errors or saturate at the limit instead.
+//!
 //! ```
 //! use chrono::prelude::*;
 //! use date_calculations::*;
@@ -23,151 +35,710 @@
 
 use chrono::prelude::*;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+pub mod academic;
+pub mod age;
+pub mod aging;
+mod aligned;
+#[cfg(feature = "std")]
+mod astronomy;
+#[cfg(feature = "alloc")]
+pub mod batch;
+pub mod billing;
+mod boundaries;
+#[cfg(feature = "alloc")]
+pub mod buddhist;
+pub mod business;
+#[cfg(feature = "std")]
+pub mod chinese;
+pub mod civil;
+#[cfg(feature = "std")]
+pub mod csv;
+#[cfg(feature = "std")]
+pub mod current;
+#[cfg(feature = "alloc")]
+pub mod custom_period;
+pub mod day_number;
+pub mod dekad;
+mod duration;
+mod error;
+pub mod ethiopian;
+pub mod fiscal;
+#[cfg(feature = "alloc")]
+pub mod format;
+#[cfg(feature = "alloc")]
+pub mod heatmap;
+pub mod hebrew;
+pub mod hijri;
+pub mod historical;
+#[cfg(feature = "alloc")]
+pub mod humanize;
+mod iso_week;
+pub mod japanese;
+pub mod julian;
+pub mod liturgical;
+#[cfg(feature = "locale")]
+pub mod locale;
+#[cfg(feature = "std")]
+pub mod moon;
+#[cfg(feature = "alloc")]
+pub mod natural;
+mod offset;
+pub mod ordinal;
+#[cfg(feature = "packed")]
+pub mod packed;
+pub mod pattern;
+pub mod payroll;
+mod period;
+mod period_index;
+pub mod persian;
+mod policy;
+mod quarter;
+mod range;
+pub mod schedule;
+#[cfg(feature = "std")]
+pub mod season;
+#[cfg(feature = "alloc")]
+pub mod sec;
+#[cfg(feature = "alloc")]
+pub mod shorthand;
+#[cfg(feature = "alloc")]
+pub mod sql;
+#[cfg(feature = "alloc")]
+pub mod terms;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod thirteen_period;
+#[cfg(feature = "alloc")]
+pub mod ticks;
+pub mod trimester;
+mod year_month;
+pub mod year_over_year;
+
+pub use aligned::{Aligned, MonthStart, QuarterStart, WeekStart, YearStart};
+pub use boundaries::PeriodBoundaries;
+pub use duration::{
+    advance, calendar_diff, Advance, CalendarDiff, CalendarDuration, MonthOverflow,
+};
+pub use error::ParseError;
+#[cfg(feature = "alloc")]
+pub use iso_week::format_week_date;
+pub use iso_week::{parse_week_date, IsoYearWeek};
+pub use period::{EndBound, Period, PeriodStarts};
+pub use period_index::PeriodIndex;
+pub use policy::{OutOfRange, RangePolicy};
+pub use quarter::Quarter;
+pub use range::{DateRange, DateRangeIter, DayCount};
+pub use year_month::YearMonth;
+
 // weeks
 
 /// Returns the beginning of the week relative to the provided date.
 ///
-/// Weeks begin on Sunday.
+/// Weeks begin on Sunday. Returns `None` if that Sunday precedes `NaiveDate::MIN`.
 pub fn beginning_of_week(date: &NaiveDate) -> Option<NaiveDate> {
-    if date.weekday() == Weekday::Sun {
-        Some(date.clone())
-    } else {
-        NaiveDate::from_isoywd_opt(date.iso_week().year(), date.iso_week().week(), Weekday::Sun)
-            .map(|d| d - chrono::Duration::weeks(1))
-    }
+    offset::shift(date, offset::beginning_of_week(date))
 }
 
 /// Returns the end of the week relative to the provided date.
 ///
-/// Weeks end on Saturday.
+/// Weeks end on Saturday. Returns `None` if that Saturday follows `NaiveDate::MAX`.
 pub fn end_of_week(date: &NaiveDate) -> Option<NaiveDate> {
-    beginning_of_week(date).map(|d| d + chrono::Duration::days(6))
+    offset::shift(date, offset::end_of_week(date))
 }
 
 /// Returns the beginning of the next week.
 ///
-/// Weeks begin on Sunday.
+/// Weeks begin on Sunday. Returns `None` if the next week begins after `NaiveDate::MAX`.
 pub fn next_week(date: &NaiveDate) -> Option<NaiveDate> {
-    beginning_of_week(date).map(|d| d + chrono::Duration::weeks(1))
+    offset::shift(date, offset::next_week(date))
 }
 
-/// Returns the end of the next week.
+/// Returns the beginning of the previous week.
 ///
-/// Weeks end on Saturday.
+/// Weeks begin on Sunday. Returns `None` if the previous week begins before `NaiveDate::MIN`.
 pub fn previous_week(date: &NaiveDate) -> Option<NaiveDate> {
-    beginning_of_week(date).map(|d| d - chrono::Duration::weeks(1))
+    offset::shift(date, offset::previous_week(date))
+}
+
+/// Returns the beginning of the week relative to the provided date, for weeks beginning on
+/// `start`.
+pub fn beginning_of_week_starting_on(date: &NaiveDate, start: Weekday) -> Option<NaiveDate> {
+    let days_since_start =
+        (date.weekday().num_days_from_monday() + 7 - start.num_days_from_monday()) % 7;
+
+    date.checked_sub_signed(chrono::Duration::days(days_since_start.into()))
+}
+
+/// Returns the end of the week relative to the provided date, for weeks beginning on `start`.
+pub fn end_of_week_starting_on(date: &NaiveDate, start: Weekday) -> Option<NaiveDate> {
+    let days_until_end =
+        (start.num_days_from_monday() + 13 - date.weekday().num_days_from_monday()) % 7;
+
+    date.checked_add_signed(chrono::Duration::days(days_until_end.into()))
+}
+
+/// Returns the beginning of the next week, for weeks beginning on `start`.
+pub fn next_week_starting_on(date: &NaiveDate, start: Weekday) -> Option<NaiveDate> {
+    end_of_week_starting_on(date, start)?.succ_opt()
+}
+
+/// Returns the beginning of the previous week, for weeks beginning on `start`.
+pub fn previous_week_starting_on(date: &NaiveDate, start: Weekday) -> Option<NaiveDate> {
+    beginning_of_week_starting_on(date, start)?.checked_sub_signed(chrono::Duration::weeks(1))
 }
 
 /// Returns the first day of the current month and year.
-pub fn beginning_of_month(date: &NaiveDate) -> Option<NaiveDate> {
-    date.with_day(1)
+pub fn beginning_of_month(date: &NaiveDate) -> NaiveDate {
+    offset::shift_within_year(date, offset::beginning_of_month(date))
 }
 
 /// Returns the last day of the current month and year.
-pub fn end_of_month(date: &NaiveDate) -> Option<NaiveDate> {
-    next_month(date).map(|d| d - chrono::Duration::days(1))
+pub fn end_of_month(date: &NaiveDate) -> NaiveDate {
+    offset::shift_within_year(date, offset::end_of_month(date))
 }
 
 /// Returns the first day of the next month.
 ///
-/// If the current month is December, this will shift to the next year.
+/// If the current month is December, this will shift to the next year. Returns `None` if the
+/// next month is after `NaiveDate::MAX`.
 pub fn next_month(date: &NaiveDate) -> Option<NaiveDate> {
-    if date.month() == 12 {
-        next_year(date)
-    } else {
-        beginning_of_month(date)?.with_month(date.month() + 1)
-    }
+    offset::shift(date, offset::next_month(date))
 }
 
 /// Returns the first day of the previous month.
 ///
-/// If the current month is January, this will shift to the previous year.
+/// If the current month is January, this will shift to the previous year. Returns `None` if the
+/// previous month is before `NaiveDate::MIN`.
 pub fn previous_month(date: &NaiveDate) -> Option<NaiveDate> {
-    if date.month() == 1 {
-        beginning_of_month(date)?
-            .with_month(12)?
-            .with_year(date.year() - 1)
-    } else {
-        beginning_of_month(date)?.with_month(date.month() - 1)
-    }
+    offset::shift(date, offset::previous_month(date))
 }
 
 /// Returns the first day of the current quarter and year.
 ///
 /// This will either be January 1, April 1, July 1, or October 1 of the current year.
-pub fn beginning_of_quarter(date: &NaiveDate) -> Option<NaiveDate> {
-    beginning_of_month(date)?.with_month(quarter_month(date))
+pub fn beginning_of_quarter(date: &NaiveDate) -> NaiveDate {
+    offset::shift_within_year(date, offset::beginning_of_quarter(date))
 }
 
 /// Returns the last day of the current quarter and year.
 ///
 /// This will either be March 31, June 30, September 30, or December 31 of the current year.
-pub fn end_of_quarter(date: &NaiveDate) -> Option<NaiveDate> {
-    next_quarter(date).map(|d| d - chrono::Duration::days(1))
+pub fn end_of_quarter(date: &NaiveDate) -> NaiveDate {
+    offset::shift_within_year(date, offset::end_of_quarter(date))
 }
 
 /// Returns the first day of the next quarter.
 ///
 /// If the current date falls in the last quarter of the year, this will shift to the first quarter
-/// of the next year.
+/// of the next year. Returns `None` if the next quarter begins after `NaiveDate::MAX`.
 pub fn next_quarter(date: &NaiveDate) -> Option<NaiveDate> {
-    if date.month() >= 10 {
-        beginning_of_year(date)?.with_year(date.year() + 1)
-    } else {
-        beginning_of_month(date)?.with_month(quarter_month(date) + 3)
-    }
+    offset::shift(date, offset::next_quarter(date))
 }
 
 /// Returns the first day of the previous quarter.
 ///
 /// If the current date falls in the first quarter of the year, this will shift to the last quarter
-/// of the previous year.
+/// of the previous year. Returns `None` if the previous quarter begins before `NaiveDate::MIN`.
 pub fn previous_quarter(date: &NaiveDate) -> Option<NaiveDate> {
-    if date.month() < 4 {
-        beginning_of_month(date)?
-            .with_year(date.year() - 1)?
-            .with_month(10)
-    } else {
-        beginning_of_month(date)?.with_month(quarter_month(date) - 3)
-    }
-}
-
-fn quarter_month(date: &NaiveDate) -> u32 {
-    1 + 3 * ((date.month() - 1) / 3)
+    offset::shift(date, offset::previous_quarter(date))
 }
 
 /// Returns the first day of the year (January 1) of the current year.
-pub fn beginning_of_year(date: &NaiveDate) -> Option<NaiveDate> {
-    beginning_of_month(date)?.with_month(1)
+pub fn beginning_of_year(date: &NaiveDate) -> NaiveDate {
+    offset::shift_within_year(date, offset::beginning_of_year(date))
 }
 
 /// Returns the last day of the year (December 31) of the current year.
-pub fn end_of_year(date: &NaiveDate) -> Option<NaiveDate> {
-    date.with_month(12)?.with_day(31)
+pub fn end_of_year(date: &NaiveDate) -> NaiveDate {
+    offset::shift_within_year(date, offset::end_of_year(date))
 }
 
 /// Returns the first day of the year (January 1) of the next year.
+///
+/// Returns `None` if the next year is after `NaiveDate::MAX`.
 pub fn next_year(date: &NaiveDate) -> Option<NaiveDate> {
-    beginning_of_year(date)?.with_year(date.year() + 1)
+    offset::shift(date, offset::next_year(date))
 }
 
 /// Returns the first day of the year (January 1) of the previous year.
+///
+/// Returns `None` if the previous year is before `NaiveDate::MIN`.
 pub fn previous_year(date: &NaiveDate) -> Option<NaiveDate> {
-    beginning_of_year(date)?.with_year(date.year() - 1)
+    offset::shift(date, offset::previous_year(date))
+}
+
+// ends of adjacent periods
+//
+// The beginnings of adjacent periods are `next_week`, `previous_month`, and so on; these return
+// the other end without chaining two calls
+
+/// Returns the last day (Saturday) of the previous week.
+///
+/// Returns `None` if the previous week ends before `NaiveDate::MIN`.
+pub fn end_of_previous_week(date: &NaiveDate) -> Option<NaiveDate> {
+    offset::shift(date, offset::beginning_of_week(date) - 1)
+}
+
+/// Returns the last day (Saturday) of the next week.
+///
+/// Returns `None` if the next week ends after `NaiveDate::MAX`.
+pub fn end_of_next_week(date: &NaiveDate) -> Option<NaiveDate> {
+    offset::shift(date, offset::next_week(date) + 6)
+}
+
+/// Returns the last day of the previous month.
+///
+/// Returns `None` if the previous month is before `NaiveDate::MIN`.
+pub fn end_of_previous_month(date: &NaiveDate) -> Option<NaiveDate> {
+    offset::shift(date, offset::beginning_of_month(date) - 1)
+}
+
+/// Returns the last day of the next month.
+///
+/// Returns `None` if the next month is after `NaiveDate::MAX`.
+pub fn end_of_next_month(date: &NaiveDate) -> Option<NaiveDate> {
+    next_month(date).map(|next| end_of_month(&next))
+}
+
+/// Returns the last day of the previous quarter.
+///
+/// Returns `None` if the previous quarter is before `NaiveDate::MIN`.
+pub fn end_of_previous_quarter(date: &NaiveDate) -> Option<NaiveDate> {
+    offset::shift(date, offset::beginning_of_quarter(date) - 1)
+}
+
+/// Returns the last day of the next quarter.
+///
+/// Returns `None` if the next quarter is after `NaiveDate::MAX`.
+pub fn end_of_next_quarter(date: &NaiveDate) -> Option<NaiveDate> {
+    next_quarter(date).map(|next| end_of_quarter(&next))
+}
+
+/// Returns the last day of the year (December 31) of the previous year.
+///
+/// Returns `None` if the previous year is before `NaiveDate::MIN`.
+pub fn end_of_previous_year(date: &NaiveDate) -> Option<NaiveDate> {
+    offset::shift(date, offset::beginning_of_year(date) - 1)
+}
+
+/// Returns the last day of the year (December 31) of the next year.
+///
+/// Returns `None` if the next year is after `NaiveDate::MAX`.
+pub fn end_of_next_year(date: &NaiveDate) -> Option<NaiveDate> {
+    next_year(date).map(|next| end_of_year(&next))
+}
+
+// periods to date
+//
+// Ranges from the beginning of the date's period through the date, like year-to-date figures
+
+/// Returns the range from the beginning of the week (Sunday) containing the date through the
+/// date, or the day before it when `end` is exclusive.
+///
+/// Returns `None` if the week begins before `NaiveDate::MIN` or the exclusive range is empty.
+pub fn week_to_date(date: &NaiveDate, end: EndBound) -> Option<DateRange> {
+    Period::Week.to_date(date, end)
+}
+
+/// Returns the range from the first day of the month through the date, or the day before it
+/// when `end` is exclusive.
+///
+/// Returns `None` if the exclusive range is empty.
+pub fn month_to_date(date: &NaiveDate, end: EndBound) -> Option<DateRange> {
+    Period::Month.to_date(date, end)
+}
+
+/// Returns the range from the first day of the quarter through the date, or the day before it
+/// when `end` is exclusive.
+///
+/// Returns `None` if the exclusive range is empty.
+pub fn quarter_to_date(date: &NaiveDate, end: EndBound) -> Option<DateRange> {
+    Period::Quarter.to_date(date, end)
+}
+
+/// Returns the range from January 1 through the date, or the day before it when `end` is
+/// exclusive.
+///
+/// Returns `None` if the exclusive range is empty.
+pub fn year_to_date(date: &NaiveDate, end: EndBound) -> Option<DateRange> {
+    Period::Year.to_date(date, end)
+}
+
+// complete periods before the date's
+//
+// Report windows like "the last three complete months" leave out the partial period containing
+// the date
+
+/// Returns the range of the `count` whole weeks (Sunday through Saturday) before the week
+/// containing the date.
+///
+/// Returns `None` if `count` is zero or the range begins before `NaiveDate::MIN`.
+pub fn previous_n_complete_weeks(date: &NaiveDate, count: u32) -> Option<DateRange> {
+    Period::Week.previous_complete(date, count)
+}
+
+/// Returns the range of the `count` whole months before the month containing the date.
+///
+/// Returns `None` if `count` is zero or the range begins before `NaiveDate::MIN`.
+pub fn previous_n_complete_months(date: &NaiveDate, count: u32) -> Option<DateRange> {
+    Period::Month.previous_complete(date, count)
+}
+
+/// Returns the range of the `count` whole quarters before the quarter containing the date.
+///
+/// Returns `None` if `count` is zero or the range begins before `NaiveDate::MIN`.
+pub fn previous_n_complete_quarters(date: &NaiveDate, count: u32) -> Option<DateRange> {
+    Period::Quarter.previous_complete(date, count)
+}
+
+/// Returns the range of the `count` whole years before the year containing the date.
+///
+/// Returns `None` if `count` is zero or the range begins before `NaiveDate::MIN`.
+pub fn previous_n_complete_years(date: &NaiveDate, count: u32) -> Option<DateRange> {
+    Period::Year.previous_complete(date, count)
+}
+
+// shifts by periods
+
+/// Shifts the date by `count` periods, keeping its position within its period, forward when
+/// `count` is positive and backward when negative.
+///
+/// Unlike [`next_quarter`] and the other functions landing on the beginning of a period, this
+/// answers "this time next quarter": weeks keep the weekday, months and years keep the day of the
+/// month, and quarters keep the day of the quarter, each clamped to the end of a shorter period.
+///
+/// Returns `None` if the result is outside the range chrono can represent.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::{shift_preserving_position, Period};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap();
+///
+/// assert_eq!(
+///     shift_preserving_position(&date, Period::Quarter, 1),
+///     NaiveDate::from_ymd_opt(2024, 5, 16)
+/// );
+/// assert_eq!(
+///     shift_preserving_position(&date, Period::Month, -1),
+///     NaiveDate::from_ymd_opt(2024, 1, 15)
+/// );
+/// ```
+pub fn shift_preserving_position(
+    date: &NaiveDate,
+    period: Period,
+    count: i32,
+) -> Option<NaiveDate> {
+    let count = i64::from(count);
+
+    match period {
+        Period::Week => date.checked_add_signed(chrono::Duration::weeks(count)),
+        Period::Month => duration::shift_months(date, count, MonthOverflow::Clamp),
+        Period::Quarter => {
+            let beginning = beginning_of_quarter(date);
+            let day = date.signed_duration_since(beginning).num_days();
+            let target = duration::shift_months(&beginning, count * 3, MonthOverflow::Clamp)?;
+            let last = i64::from(days_in_quarter(&target)) - 1;
+
+            target.checked_add_signed(chrono::Duration::days(day.min(last)))
+        }
+        Period::Year => duration::shift_months(date, count * 12, MonthOverflow::Clamp),
+    }
+}
+
+// closest dates
+
+/// Returns the candidate nearest the target, preferring the earlier of two equally near.
+///
+/// Returns `None` if there are no candidates.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::{closest, closest_not_after, closest_within_same_month};
+///
+/// let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// let statements = [ymd(2024, 4, 28), ymd(2024, 5, 3), ymd(2024, 5, 31)];
+///
+/// assert_eq!(closest(&ymd(2024, 5, 1), &statements), Some(ymd(2024, 5, 3)));
+/// assert_eq!(closest_not_after(&ymd(2024, 5, 1), &statements), Some(ymd(2024, 4, 28)));
+/// assert_eq!(closest_within_same_month(&ymd(2024, 4, 5), &statements), Some(ymd(2024, 4, 28)));
+/// ```
+pub fn closest(target: &NaiveDate, candidates: &[NaiveDate]) -> Option<NaiveDate> {
+    closest_matching(target, candidates, |_| true)
+}
+
+/// Returns the candidate nearest the target that does not fall after it.
+///
+/// Returns `None` if every candidate falls after the target.
+pub fn closest_not_after(target: &NaiveDate, candidates: &[NaiveDate]) -> Option<NaiveDate> {
+    closest_matching(target, candidates, |candidate| candidate <= target)
+}
+
+/// Returns the candidate nearest the target within the target's month, preferring the earlier
+/// of two equally near.
+///
+/// Returns `None` if no candidate falls in the target's month.
+pub fn closest_within_same_month(
+    target: &NaiveDate,
+    candidates: &[NaiveDate],
+) -> Option<NaiveDate> {
+    closest_matching(target, candidates, |candidate| {
+        (candidate.year(), candidate.month()) == (target.year(), target.month())
+    })
+}
+
+fn closest_matching(
+    target: &NaiveDate,
+    candidates: &[NaiveDate],
+    allowed: impl Fn(&NaiveDate) -> bool,
+) -> Option<NaiveDate> {
+    candidates
+        .iter()
+        .filter(|candidate| allowed(candidate))
+        .min_by_key(|candidate| {
+            (
+                candidate.signed_duration_since(*target).num_days().abs(),
+                **candidate,
+            )
+        })
+        .copied()
+}
+
+// expiries
+//
+// Cards, certificates, and similar documents printed with only a month and year stay valid
+// through the last day of that month
+
+/// Returns the last day of the month `months` months after the date's month, the expiry of
+/// something valid for `months` months from the date.
+///
+/// Returns `None` if the month is after `NaiveDate::MAX`.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::expiry_after_months;
+///
+/// let issued = NaiveDate::from_ymd_opt(2023, 8, 31).unwrap();
+///
+/// assert_eq!(expiry_after_months(&issued, 6), NaiveDate::from_ymd_opt(2024, 2, 29));
+/// ```
+pub fn expiry_after_months(date: &NaiveDate, months: u32) -> Option<NaiveDate> {
+    let month = duration::shift_months(
+        &beginning_of_month(date),
+        i64::from(months),
+        MonthOverflow::Clamp,
+    )?;
+
+    Some(end_of_month(&month))
+}
+
+/// Returns whether something expiring in `expiry_month` of `expiry_year` has expired by `on`,
+/// that is, whether `on` falls in a later month.
+pub fn is_expired(expiry_year: i32, expiry_month: u32, on: &NaiveDate) -> bool {
+    (on.year(), on.month()) > (expiry_year, expiry_month)
+}
+
+// lengths
+
+/// Returns the number of days in the month of the provided date.
+pub fn days_in_month(date: &NaiveDate) -> u32 {
+    civil::days_in_month(date.year(), date.month()).expect("months are valid")
+}
+
+/// Returns the number of days in the quarter of the provided date, from 90 through 92.
+pub fn days_in_quarter(date: &NaiveDate) -> u32 {
+    civil::days_in_quarter(date.year(), 1 + date.month0() / 3).expect("quarters are valid")
+}
+
+/// Returns the number of days in the year of the provided date, 365 or 366.
+pub fn days_in_year(date: &NaiveDate) -> u32 {
+    civil::days_in_year(date.year())
+}
+
+/// Counts each weekday from `start` through `end`, inclusive, indexed by
+/// `Weekday::num_days_from_monday`.
+///
+/// The counts take constant time however far apart the dates are, and are all zero when `end`
+/// precedes `start`.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::{beginning_of_quarter, end_of_quarter, weekday_counts};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 8, 1).unwrap();
+/// let counts = weekday_counts(&beginning_of_quarter(&date), &end_of_quarter(&date));
+///
+/// assert_eq!(counts[Weekday::Mon.num_days_from_monday() as usize], 14);
+/// assert_eq!(counts.iter().sum::<u32>(), 92);
+/// ```
+pub fn weekday_counts(start: &NaiveDate, end: &NaiveDate) -> [u32; 7] {
+    let days = end.signed_duration_since(*start).num_days() + 1;
+    if days <= 0 {
+        return [0; 7];
+    }
+
+    let (weeks, extra) = ((days / 7) as u32, (days % 7) as u32);
+    let first = start.weekday().num_days_from_monday();
+    let mut counts = [weeks; 7];
+    for day in 0..extra {
+        counts[((first + day) % 7) as usize] += 1;
+    }
+
+    counts
+}
+
+// leap years
+
+/// Returns whether the year, numbered astronomically, is a leap year in the proleptic Gregorian
+/// calendar.
+pub fn is_leap_year(year: i32) -> bool {
+    civil::is_leap_year(year)
+}
+
+/// Returns January 1 of the first leap year after the date's year.
+///
+/// Returns `None` if that year is after `NaiveDate::MAX`.
+pub fn next_leap_year(date: &NaiveDate) -> Option<NaiveDate> {
+    leap_year_from(date.year(), 1)
+}
+
+/// Returns January 1 of the last leap year before the date's year.
+///
+/// Returns `None` if that year is before `NaiveDate::MIN`.
+pub fn previous_leap_year(date: &NaiveDate) -> Option<NaiveDate> {
+    leap_year_from(date.year(), -1)
+}
+
+/// Returns the February 29 nearest to the date, preferring the earlier when two are equally far.
+///
+/// Returns the date itself when it is a February 29, and `None` only when no February 29 lies
+/// within chrono's range on either side.
+pub fn nearest_feb_29(date: &NaiveDate) -> Option<NaiveDate> {
+    let feb_29 = |year: i32| NaiveDate::from_ymd_opt(year, 2, 29);
+    let this_year = feb_29(date.year());
+
+    let on_or_before = this_year
+        .filter(|d| d <= date)
+        .or_else(|| feb_29(previous_leap_year(date)?.year()));
+    let on_or_after = this_year
+        .filter(|d| d >= date)
+        .or_else(|| feb_29(next_leap_year(date)?.year()));
+
+    match (on_or_before, on_or_after) {
+        (Some(before), Some(after))
+            if after.signed_duration_since(*date) < date.signed_duration_since(before) =>
+        {
+            Some(after)
+        }
+        (Some(before), _) => Some(before),
+        (None, after) => after,
+    }
+}
+
+/// Counts the leap years from the year of `start` through the year of `end`, inclusive.
+///
+/// The count is negative when `end` precedes `start`, and is computed arithmetically however far
+/// apart the dates are.
+pub fn leap_years_between(start: &NaiveDate, end: &NaiveDate) -> i64 {
+    if end < start {
+        return -leap_years_between(end, start);
+    }
+
+    leap_years_through(end.year()) - leap_years_through(start.year() - 1)
+}
+
+/// Counts the February 29s after `start` up to and including `end`.
+///
+/// The count is negative when `end` precedes `start`, and is computed arithmetically however far
+/// apart the dates are.
+pub fn leap_days_between(start: &NaiveDate, end: &NaiveDate) -> i64 {
+    leap_days_through(end) - leap_days_through(start)
+}
+
+// The leap years after year 0 through the year, negative for years before 0
+fn leap_years_through(year: i32) -> i64 {
+    let year = i64::from(year);
+
+    year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)
+}
+
+// The February 29s after year 0 through the date, negative before then
+fn leap_days_through(date: &NaiveDate) -> i64 {
+    let this_year = date.leap_year() && date.ordinal() >= 60;
+
+    leap_years_through(date.year() - 1) + this_year as i64
+}
+
+// Jan 1 of the nearest leap year after (step 1) or before (step -1) the year; leap years are
+// never more than eight years apart
+fn leap_year_from(year: i32, step: i32) -> Option<NaiveDate> {
+    let leap = (1..=8)
+        .filter_map(|n| year.checked_add(n * step))
+        .find(|year| civil::is_leap_year(*year))?;
+
+    NaiveDate::from_ymd_opt(leap, 1, 1)
+}
+
+/// Returns the number of weeks in the ISO 8601 week-numbering year, 52 or 53.
+///
+/// See [`is_long_iso_week_year`].
+pub fn weeks_in_iso_year(year: i32) -> u32 {
+    52 + is_long_iso_week_year(year) as u32
+}
+
+/// Returns whether the ISO 8601 week-numbering year has a week 53.
+///
+/// Long years begin on a Thursday, or on a Wednesday in leap years, and the days of their week
+/// 53 are given by [`week`]`(year, 53)`.
+pub fn is_long_iso_week_year(year: i32) -> bool {
+    let january_1 = civil::to_rata_die((year, 1, 1)).expect("January 1 is valid");
+
+    match civil::days_from_sunday(january_1) {
+        4 => true,
+        3 => civil::is_leap_year(year),
+        _ => false,
+    }
+}
+
+// periods by identifier
+
+/// Returns the range of the ISO 8601 week with the number in the ISO week-numbering year.
+///
+/// Unlike [`beginning_of_week`], ISO weeks run Monday through Sunday; see [`IsoYearWeek`].
+pub fn week(year: i32, number: u32) -> Option<DateRange> {
+    IsoYearWeek::new(year, number)?.range()
+}
+
+/// Returns the range of the month (1 through 12) of the year.
+pub fn month(year: i32, number: u32) -> Option<DateRange> {
+    YearMonth::new(year, number)?.range()
+}
+
+/// Returns the range of the quarter (1 through 4) of the year.
+pub fn quarter(year: i32, number: u32) -> Option<DateRange> {
+    Quarter::new(year, number)?.range()
+}
+
+/// Returns the range of the year, January 1 through December 31.
+pub fn year(year: i32) -> Option<DateRange> {
+    DateRange::new(
+        NaiveDate::from_ymd_opt(year, 1, 1)?,
+        NaiveDate::from_ymd_opt(year, 12, 31)?,
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use num::clamp;
-    use quickcheck::{Arbitrary, Gen};
+    use crate::testing::ArbitraryDate;
     use quickcheck_macros::quickcheck;
 
-    #[derive(Clone, Debug)]
-    struct NaiveDateWrapper(NaiveDate);
-
     #[quickcheck]
-    fn beginning_of_week_works(d: NaiveDateWrapper) -> bool {
+    fn beginning_of_week_works(d: ArbitraryDate) -> bool {
         let since = d.0.signed_duration_since(beginning_of_week(&d.0).unwrap());
 
         beginning_of_week(&d.0).unwrap().weekday() == Weekday::Sun
@@ -175,13 +746,79 @@ mod tests {
             && since.num_days() < 7
     }
 
+    #[test]
+    fn beginning_of_week_crosses_year_boundaries() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // ISO week 2020-W53 runs into 2021, and 2021-01-03 is a Sunday in ISO week 2020-W53
+        assert_eq!(beginning_of_week(&ymd(2021, 1, 1)), Some(ymd(2020, 12, 27)));
+        assert_eq!(beginning_of_week(&ymd(2021, 1, 3)), Some(ymd(2021, 1, 3)));
+        // 2024-12-30 falls in ISO week 2025-W01
+        assert_eq!(
+            beginning_of_week(&ymd(2024, 12, 31)),
+            Some(ymd(2024, 12, 29))
+        );
+        assert_eq!(beginning_of_week(&ymd(2022, 1, 1)), Some(ymd(2021, 12, 26)));
+        assert_eq!(end_of_week(&ymd(2022, 12, 31)), Some(ymd(2022, 12, 31)));
+        assert_eq!(next_week(&ymd(2026, 12, 31)), Some(ymd(2027, 1, 3)));
+        assert_eq!(previous_week(&ymd(2027, 1, 2)), Some(ymd(2026, 12, 20)));
+    }
+
+    #[test]
+    fn week_boundaries_near_date_limits() {
+        assert_eq!(beginning_of_week(&NaiveDate::MIN), None);
+        assert_eq!(end_of_week(&NaiveDate::MAX), None);
+        assert!(end_of_week(&NaiveDate::MIN).is_some());
+        assert!(next_week(&NaiveDate::MIN).is_some());
+        assert!(end_of_week_starting_on(&NaiveDate::MIN, Weekday::Mon).is_some());
+        assert_eq!(
+            beginning_of_week(&NaiveDate::MAX),
+            NaiveDate::MAX.checked_sub_signed(chrono::Duration::days(
+                NaiveDate::MAX.weekday().num_days_from_sunday().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn boundaries_before_the_common_era() {
+        let ymd = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+
+        assert_eq!(previous_year(&ymd(1, 6, 1)), Some(ymd(0, 1, 1)));
+        assert_eq!(next_month(&ymd(-1, 12, 5)), Some(ymd(0, 1, 1)));
+        assert_eq!(previous_quarter(&ymd(0, 2, 10)), Some(ymd(-1, 10, 1)));
+        assert_eq!(beginning_of_quarter(&ymd(-1, 5, 17)), ymd(-1, 4, 1));
+        assert_eq!(end_of_quarter(&ymd(-1, 5, 17)), ymd(-1, 6, 30));
+        assert_eq!(end_of_month(&ymd(0, 2, 1)), ymd(0, 2, 29));
+        assert_eq!(end_of_month(&ymd(-100, 2, 1)), ymd(-100, 2, 28));
+        assert_eq!(end_of_month(&ymd(-4, 2, 1)), ymd(-4, 2, 29));
+        assert_eq!(days_in_year(&ymd(-400, 1, 1)), 366);
+        assert_eq!(days_in_year(&ymd(-1, 1, 1)), 365);
+        assert_eq!(ymd(0, 1, 1).weekday(), Weekday::Sat);
+        assert_eq!(beginning_of_week(&ymd(0, 1, 1)), Some(ymd(-1, 12, 26)));
+        assert_eq!(next_week(&ymd(0, 1, 1)), Some(ymd(0, 1, 2)));
+        assert_eq!(YearMonth::from_date(&ymd(-1, 3, 1)).to_string(), "-0001-03");
+        assert_eq!(Quarter::from_date(&ymd(-1, 3, 1)).to_string(), "-0001-Q1");
+        assert_eq!(YearMonth::from_date(&ymd(5, 3, 1)).to_string(), "0005-03");
+    }
+
+    #[test]
+    fn period_boundaries_near_date_limits() {
+        assert_eq!(end_of_year(&NaiveDate::MAX), NaiveDate::MAX);
+        assert_eq!(beginning_of_year(&NaiveDate::MIN), NaiveDate::MIN);
+        assert_eq!(end_of_month(&NaiveDate::MAX), NaiveDate::MAX);
+        assert_eq!(next_month(&NaiveDate::MAX), None);
+        assert_eq!(previous_quarter(&NaiveDate::MIN), None);
+        assert_eq!(next_year(&NaiveDate::MAX), None);
+        assert_eq!(previous_year(&NaiveDate::MIN), None);
+    }
+
     #[quickcheck]
-    fn end_of_week_works(d: NaiveDateWrapper) -> bool {
+    fn end_of_week_works(d: ArbitraryDate) -> bool {
         end_of_week(&d.0).unwrap().weekday() == Weekday::Sat
     }
 
     #[quickcheck]
-    fn next_week_works(d: NaiveDateWrapper) -> bool {
+    fn next_week_works(d: ArbitraryDate) -> bool {
         let since = next_week(&d.0).unwrap().signed_duration_since(d.0);
         next_week(&d.0).unwrap().weekday() == Weekday::Sun
             && since.num_days() > 0
@@ -189,7 +826,7 @@ mod tests {
     }
 
     #[quickcheck]
-    fn previous_week_works(d: NaiveDateWrapper) -> bool {
+    fn previous_week_works(d: ArbitraryDate) -> bool {
         let since = previous_week(&d.0).unwrap().signed_duration_since(d.0);
         previous_week(&d.0).unwrap().weekday() == Weekday::Sun
             && since.num_days() <= -7
@@ -197,67 +834,298 @@ mod tests {
     }
 
     #[quickcheck]
-    fn beginning_of_month_works(d: NaiveDateWrapper) -> bool {
-        beginning_of_month(&d.0).unwrap().day() == 1
-            && beginning_of_month(&d.0).unwrap().month() == d.0.month()
-            && beginning_of_month(&d.0).unwrap().year() == d.0.year()
+    fn beginning_of_week_starting_on_works(d: ArbitraryDate) -> bool {
+        [Weekday::Mon, Weekday::Sat, Weekday::Sun]
+            .iter()
+            .all(|start| {
+                let beginning = beginning_of_week_starting_on(&d.0, *start).unwrap();
+                let since = d.0.signed_duration_since(beginning);
+
+                beginning.weekday() == *start
+                    && since.num_days() >= 0
+                    && since.num_days() < 7
+                    && end_of_week_starting_on(&d.0, *start).unwrap().succ_opt()
+                        == next_week_starting_on(&d.0, *start)
+            })
+    }
+
+    #[quickcheck]
+    fn sunday_weeks_match_the_default_week(d: ArbitraryDate) -> bool {
+        beginning_of_week_starting_on(&d.0, Weekday::Sun) == beginning_of_week(&d.0)
+            && previous_week_starting_on(&d.0, Weekday::Sun) == previous_week(&d.0)
+    }
+
+    #[quickcheck]
+    fn ends_of_adjacent_periods_match_chained_calls(d: ArbitraryDate) -> bool {
+        let d = d.0;
+
+        end_of_previous_week(&d) == previous_week(&d).and_then(|p| end_of_week(&p))
+            && end_of_next_week(&d) == next_week(&d).and_then(|n| end_of_week(&n))
+            && end_of_previous_month(&d) == previous_month(&d).map(|p| end_of_month(&p))
+            && end_of_next_month(&d) == next_month(&d).map(|n| end_of_month(&n))
+            && end_of_previous_quarter(&d) == previous_quarter(&d).map(|p| end_of_quarter(&p))
+            && end_of_next_quarter(&d) == next_quarter(&d).map(|n| end_of_quarter(&n))
+            && end_of_previous_year(&d) == previous_year(&d).map(|p| end_of_year(&p))
+            && end_of_next_year(&d) == next_year(&d).map(|n| end_of_year(&n))
+    }
+
+    #[test]
+    fn ends_of_adjacent_periods_at_the_limits() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(
+            end_of_previous_month(&ymd(2024, 3, 15)),
+            Some(ymd(2024, 2, 29))
+        );
+        assert_eq!(
+            end_of_next_quarter(&ymd(2024, 11, 30)),
+            Some(ymd(2025, 3, 31))
+        );
+        assert_eq!(end_of_previous_year(&NaiveDate::MIN), None);
+        assert_eq!(end_of_next_month(&NaiveDate::MAX), None);
+        assert_eq!(
+            end_of_previous_month(&NaiveDate::MAX),
+            Some(ymd(NaiveDate::MAX.year(), 11, 30))
+        );
+    }
+
+    #[quickcheck]
+    fn position_preserving_shifts_stay_in_the_shifted_period(d: ArbitraryDate, count: i8) -> bool {
+        let count = i32::from(count);
+
+        [Period::Week, Period::Month, Period::Quarter, Period::Year]
+            .iter()
+            .all(
+                |period| match shift_preserving_position(&d.0, *period, count) {
+                    Some(shifted) => period.count_between(&d.0, &shifted) == Some(i64::from(count)),
+                    None => false,
+                },
+            )
+    }
+
+    #[test]
+    fn position_preserving_shifts_clamp_to_shorter_periods() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(
+            shift_preserving_position(&ymd(2024, 1, 31), Period::Month, 1),
+            Some(ymd(2024, 2, 29))
+        );
+        // the 92nd day of Q3 has no counterpart in the 91 days of Q2
+        assert_eq!(
+            shift_preserving_position(&ymd(2024, 9, 30), Period::Quarter, -1),
+            Some(ymd(2024, 6, 30))
+        );
+        assert_eq!(
+            shift_preserving_position(&ymd(2024, 5, 17), Period::Week, -2),
+            Some(ymd(2024, 5, 3))
+        );
+        assert_eq!(
+            shift_preserving_position(&ymd(2024, 2, 29), Period::Year, 1),
+            Some(ymd(2025, 2, 28))
+        );
+        assert_eq!(
+            shift_preserving_position(&NaiveDate::MAX, Period::Month, 1),
+            None
+        );
+    }
+
+    #[test]
+    fn closest_dates_prefer_the_earlier_of_a_tie() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let candidates = [ymd(2024, 6, 3), ymd(2024, 5, 30), ymd(2024, 6, 10)];
+
+        assert_eq!(
+            closest(&ymd(2024, 6, 1), &candidates),
+            Some(ymd(2024, 5, 30))
+        );
+        assert_eq!(closest(&ymd(2024, 6, 1), &[]), None);
+        assert_eq!(
+            closest_not_after(&ymd(2024, 6, 9), &candidates),
+            Some(ymd(2024, 6, 3))
+        );
+        assert_eq!(closest_not_after(&ymd(2024, 5, 29), &candidates), None);
+        assert_eq!(
+            closest_within_same_month(&ymd(2024, 6, 1), &candidates),
+            Some(ymd(2024, 6, 3))
+        );
+        assert_eq!(
+            closest_within_same_month(&ymd(2024, 7, 1), &candidates),
+            None
+        );
+    }
+
+    #[test]
+    fn expiries_last_through_the_end_of_the_month() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(
+            expiry_after_months(&ymd(2024, 1, 1), 0),
+            Some(ymd(2024, 1, 31))
+        );
+        assert_eq!(
+            expiry_after_months(&ymd(2024, 1, 31), 1),
+            Some(ymd(2024, 2, 29))
+        );
+        assert_eq!(
+            expiry_after_months(&ymd(2024, 11, 15), 36),
+            Some(ymd(2027, 11, 30))
+        );
+        assert_eq!(expiry_after_months(&NaiveDate::MAX, 1), None);
+
+        assert!(!is_expired(2027, 11, &ymd(2027, 11, 30)));
+        assert!(is_expired(2027, 11, &ymd(2027, 12, 1)));
+        assert!(!is_expired(2028, 1, &ymd(2027, 12, 31)));
+    }
+
+    #[quickcheck]
+    fn beginning_of_month_works(d: ArbitraryDate) -> bool {
+        beginning_of_month(&d.0).day() == 1
+            && beginning_of_month(&d.0).month() == d.0.month()
+            && beginning_of_month(&d.0).year() == d.0.year()
     }
 
     #[quickcheck]
-    fn end_of_month_works(d: NaiveDateWrapper) -> bool {
-        end_of_month(&d.0).unwrap().month() == d.0.month()
-            && end_of_month(&d.0).unwrap().year() == d.0.year()
-            && (end_of_month(&d.0).unwrap() + chrono::Duration::days(1))
-                == next_month(&d.0).unwrap()
+    fn end_of_month_works(d: ArbitraryDate) -> bool {
+        end_of_month(&d.0).month() == d.0.month()
+            && end_of_month(&d.0).year() == d.0.year()
+            && (end_of_month(&d.0) + chrono::Duration::days(1)) == next_month(&d.0).unwrap()
     }
 
     #[quickcheck]
-    fn beginning_of_year_works(d: NaiveDateWrapper) -> bool {
-        beginning_of_year(&d.0).unwrap().month() == 1
-            && beginning_of_year(&d.0).unwrap().day() == 1
-            && beginning_of_year(&d.0).unwrap().year() == d.0.year()
+    fn beginning_of_year_works(d: ArbitraryDate) -> bool {
+        beginning_of_year(&d.0).month() == 1
+            && beginning_of_year(&d.0).day() == 1
+            && beginning_of_year(&d.0).year() == d.0.year()
     }
 
     #[quickcheck]
-    fn end_of_year_works(d: NaiveDateWrapper) -> bool {
-        end_of_year(&d.0).unwrap().month() == 12
-            && end_of_year(&d.0).unwrap().day() == 31
-            && end_of_year(&d.0).unwrap().year() == d.0.year()
+    fn end_of_year_works(d: ArbitraryDate) -> bool {
+        end_of_year(&d.0).month() == 12
+            && end_of_year(&d.0).day() == 31
+            && end_of_year(&d.0).year() == d.0.year()
     }
 
     #[quickcheck]
-    fn next_year_works(d: NaiveDateWrapper) -> bool {
+    fn next_year_works(d: ArbitraryDate) -> bool {
         next_year(&d.0).unwrap().month() == 1
             && next_year(&d.0).unwrap().day() == 1
             && next_year(&d.0).unwrap().year() == d.0.year() + 1
     }
 
     #[quickcheck]
-    fn previous_year_works(d: NaiveDateWrapper) -> bool {
+    fn previous_year_works(d: ArbitraryDate) -> bool {
         previous_year(&d.0).unwrap().month() == 1
             && previous_year(&d.0).unwrap().day() == 1
             && previous_year(&d.0).unwrap().year() == d.0.year() - 1
     }
 
     #[quickcheck]
-    fn beginning_of_quarter_works(d: NaiveDateWrapper) -> bool {
-        [1, 4, 7, 10].contains(&beginning_of_quarter(&d.0).unwrap().month())
-            && beginning_of_quarter(&d.0).unwrap().day() == 1
-            && beginning_of_quarter(&d.0).unwrap().year() == d.0.year()
+    fn beginning_of_quarter_works(d: ArbitraryDate) -> bool {
+        [1, 4, 7, 10].contains(&beginning_of_quarter(&d.0).month())
+            && beginning_of_quarter(&d.0).day() == 1
+            && beginning_of_quarter(&d.0).year() == d.0.year()
     }
 
     #[quickcheck]
-    fn end_of_quarter_works(d: NaiveDateWrapper) -> bool {
-        [3, 6, 9, 12].contains(&end_of_quarter(&d.0).unwrap().month())
-            && end_of_quarter(&d.0)
-                .map(|x| x + chrono::Duration::days(1))
-                .unwrap()
-                == next_quarter(&d.0).unwrap()
-            && end_of_quarter(&d.0).unwrap().year() == d.0.year()
+    fn end_of_quarter_works(d: ArbitraryDate) -> bool {
+        [3, 6, 9, 12].contains(&end_of_quarter(&d.0).month())
+            && end_of_quarter(&d.0) + chrono::Duration::days(1) == next_quarter(&d.0).unwrap()
+            && end_of_quarter(&d.0).year() == d.0.year()
     }
 
     #[quickcheck]
-    fn next_quarter_works(d: NaiveDateWrapper) -> bool {
+    fn weekday_counts_match_iterating(start: ArbitraryDate, span: u16) -> bool {
+        let start = start.0;
+        let end = start + chrono::Duration::days(i64::from(span % 1000));
+        let mut iterated = [0; 7];
+        for date in start.iter_days().take_while(|d| d <= &end) {
+            iterated[date.weekday().num_days_from_monday() as usize] += 1;
+        }
+
+        weekday_counts(&start, &end) == iterated
+            && (start == end || weekday_counts(&end, &start) == [0; 7])
+    }
+
+    #[test]
+    fn navigates_leap_years() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert!(is_leap_year(2000) && !is_leap_year(1900) && is_leap_year(-4));
+        assert_eq!(next_leap_year(&ymd(2024, 3, 1)), Some(ymd(2028, 1, 1)));
+        assert_eq!(next_leap_year(&ymd(1897, 3, 1)), Some(ymd(1904, 1, 1)));
+        assert_eq!(previous_leap_year(&ymd(2024, 1, 1)), Some(ymd(2020, 1, 1)));
+        assert_eq!(next_leap_year(&NaiveDate::MAX), None);
+
+        assert_eq!(nearest_feb_29(&ymd(2024, 2, 29)), Some(ymd(2024, 2, 29)));
+        assert_eq!(nearest_feb_29(&ymd(2025, 3, 1)), Some(ymd(2024, 2, 29)));
+        assert_eq!(nearest_feb_29(&ymd(2027, 3, 1)), Some(ymd(2028, 2, 29)));
+        assert_eq!(nearest_feb_29(&ymd(1900, 2, 28)), Some(ymd(1896, 2, 29)));
+        assert_eq!(nearest_feb_29(&ymd(1902, 3, 1)), Some(ymd(1904, 2, 29)));
+    }
+
+    #[quickcheck]
+    fn leap_counts_match_iterating(start: ArbitraryDate, end: ArbitraryDate) -> bool {
+        let (start, end) = (start.0, end.0);
+        let (first, last) = (start.min(end), start.max(end));
+        let sign = if end < start { -1 } else { 1 };
+
+        let years = (first.year()..=last.year())
+            .filter(|y| is_leap_year(*y))
+            .count() as i64;
+        let days = (first.year()..=last.year())
+            .filter_map(|y| NaiveDate::from_ymd_opt(y, 2, 29))
+            .filter(|d| first < *d && *d <= last)
+            .count() as i64;
+
+        leap_years_between(&start, &end) == sign * years
+            && leap_days_between(&start, &end) == sign * days
+    }
+
+    #[test]
+    fn counts_leap_days_at_the_edges() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(leap_days_between(&ymd(2024, 2, 28), &ymd(2024, 2, 29)), 1);
+        assert_eq!(leap_days_between(&ymd(2024, 2, 29), &ymd(2028, 2, 28)), 0);
+        assert_eq!(leap_days_between(&ymd(1899, 1, 1), &ymd(1905, 1, 1)), 1);
+        assert_eq!(leap_years_between(&ymd(-4, 6, 1), &ymd(4, 1, 1)), 3);
+        assert_eq!(
+            leap_days_between(&NaiveDate::MIN, &NaiveDate::MAX),
+            leap_years_between(&NaiveDate::MIN, &NaiveDate::MAX)
+        );
+    }
+
+    #[test]
+    fn counts_weeks_in_iso_years() {
+        let long: Vec<i32> = (2000..2040).filter(|y| is_long_iso_week_year(*y)).collect();
+
+        assert_eq!(long, vec![2004, 2009, 2015, 2020, 2026, 2032, 2037]);
+        assert_eq!(weeks_in_iso_year(2026), 53);
+        assert_eq!(weeks_in_iso_year(2025), 52);
+        assert_eq!(
+            week(2026, 53).map(|w| (w.start(), w.end())),
+            Some((
+                NaiveDate::from_ymd_opt(2026, 12, 28).unwrap(),
+                NaiveDate::from_ymd_opt(2027, 1, 3).unwrap()
+            ))
+        );
+        assert!((-500..3000)
+            .all(|y| { (weeks_in_iso_year(y) == 53) == IsoYearWeek::new(y, 53).is_some() }));
+    }
+
+    #[quickcheck]
+    fn lengths_match_boundaries(d: ArbitraryDate) -> bool {
+        let days = |from: NaiveDate, to: NaiveDate| to.signed_duration_since(from).num_days() + 1;
+
+        i64::from(days_in_month(&d.0)) == days(beginning_of_month(&d.0), end_of_month(&d.0))
+            && i64::from(days_in_quarter(&d.0))
+                == days(beginning_of_quarter(&d.0), end_of_quarter(&d.0))
+            && i64::from(days_in_year(&d.0)) == days(beginning_of_year(&d.0), end_of_year(&d.0))
+    }
+
+    #[quickcheck]
+    fn next_quarter_works(d: ArbitraryDate) -> bool {
         let current_month = d.0.month();
         let year = if current_month >= 10 {
             d.0.year() + 1
@@ -271,7 +1139,7 @@ mod tests {
     }
 
     #[quickcheck]
-    fn previous_quarter_works(d: NaiveDateWrapper) -> bool {
+    fn previous_quarter_works(d: ArbitraryDate) -> bool {
         let current_month = d.0.month();
         let year = if current_month <= 3 {
             d.0.year() - 1
@@ -284,28 +1152,54 @@ mod tests {
             && previous_quarter(&d.0).unwrap().year() == year
     }
 
-    impl Arbitrary for NaiveDateWrapper {
-        fn arbitrary<G: Gen>(g: &mut G) -> NaiveDateWrapper {
-            let year = clamp(i32::arbitrary(g), 1584, 2800);
-            let month = 1 + u32::arbitrary(g) % 12;
-            let day = 1 + u32::arbitrary(g) % 31;
-
-            let first_date = NaiveDate::from_ymd_opt(year, month, day);
-            if day > 27 {
-                let result = vec![
-                    first_date,
-                    NaiveDate::from_ymd_opt(year, month, day - 1),
-                    NaiveDate::from_ymd_opt(year, month, day - 2),
-                ]
-                .into_iter()
-                .filter_map(|v| v)
-                .nth(0)
-                .unwrap();
-
-                NaiveDateWrapper(result)
-            } else {
-                NaiveDateWrapper(first_date.unwrap())
-            }
-        }
+    #[test]
+    fn month_and_quarter_steps_cross_years() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(next_month(&ymd(2023, 12, 31)), Some(ymd(2024, 1, 1)));
+        assert_eq!(previous_month(&ymd(2024, 1, 15)), Some(ymd(2023, 12, 1)));
+        assert_eq!(next_quarter(&ymd(2023, 11, 5)), Some(ymd(2024, 1, 1)));
+        assert_eq!(previous_quarter(&ymd(2024, 2, 29)), Some(ymd(2023, 10, 1)));
+        // Years before 1 BCE still hold whole quarters
+        assert_eq!(previous_month(&ymd(0, 1, 1)), Some(ymd(-1, 12, 1)));
+        assert_eq!(previous_quarter(&ymd(-5, 5, 1)), Some(ymd(-5, 1, 1)));
+        assert_eq!(next_quarter(&ymd(-1, 12, 31)), Some(ymd(0, 1, 1)));
+        assert_eq!(next_month(&NaiveDate::MAX), None);
+        assert_eq!(previous_quarter(&NaiveDate::MIN), None);
+    }
+
+    #[quickcheck]
+    fn period_constructors_contain_their_dates(d: ArbitraryDate) -> bool {
+        let iso_week = d.0.iso_week();
+
+        week(iso_week.year(), iso_week.week())
+            .unwrap()
+            .contains(&d.0)
+            && month(d.0.year(), d.0.month()).unwrap().contains(&d.0)
+            && quarter(d.0.year(), 1 + d.0.month0() / 3)
+                .unwrap()
+                .contains(&d.0)
+            && year(d.0.year()).unwrap().contains(&d.0)
+    }
+
+    #[test]
+    fn period_constructors_validate_identifiers() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(
+            week(2020, 53),
+            DateRange::new(ymd(2020, 12, 28), ymd(2021, 1, 3))
+        );
+        assert_eq!(week(2021, 53), None);
+        assert_eq!(
+            month(2024, 2),
+            DateRange::new(ymd(2024, 2, 1), ymd(2024, 2, 29))
+        );
+        assert_eq!(month(2024, 13), None);
+        assert_eq!(
+            quarter(2024, 4),
+            DateRange::new(ymd(2024, 10, 1), ymd(2024, 12, 31))
+        );
+        assert_eq!(quarter(2024, 0), None);
     }
 }
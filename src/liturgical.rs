@@ -0,0 +1,240 @@
+//! Seasons of the Western (Roman Rite) liturgical year, derived from the Gregorian Easter
+//! computus.
+//!
+//! The liturgical year begins on the First Sunday of Advent. Christmastide runs from Christmas
+//! through the Baptism of the Lord, the Sunday after January 6; Lent runs from Ash Wednesday
+//! through Holy Saturday, folding in the Paschal Triduum; and Eastertide runs from Easter Sunday
+//! through Pentecost. The remaining days are Ordinary Time.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::liturgical::{self, Season};
+//!
+//! assert_eq!(liturgical::easter(2024), NaiveDate::from_ymd_opt(2024, 3, 31));
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+//! assert_eq!(liturgical::season(&date), Some(Season::Lent));
+//! assert_eq!(liturgical::beginning_of_season(&date), NaiveDate::from_ymd_opt(2024, 2, 14));
+//! assert_eq!(liturgical::end_of_season(&date), NaiveDate::from_ymd_opt(2024, 3, 30));
+//! ```
+
+use chrono::prelude::*;
+use chrono::Duration;
+use core::fmt;
+
+/// A season of the liturgical year.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Season {
+    /// From the First Sunday of Advent through Christmas Eve.
+    Advent,
+    /// From Christmas through the Baptism of the Lord.
+    Christmastide,
+    /// From Ash Wednesday through Holy Saturday.
+    Lent,
+    /// From Easter Sunday through Pentecost.
+    Eastertide,
+    /// The weeks between Christmastide and Lent, and between Pentecost and Advent.
+    OrdinaryTime,
+}
+
+/// Formats the season's name, e.g. `Ordinary Time`.
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Season::Advent => "Advent",
+            Season::Christmastide => "Christmastide",
+            Season::Lent => "Lent",
+            Season::Eastertide => "Eastertide",
+            Season::OrdinaryTime => "Ordinary Time",
+        })
+    }
+}
+
+/// Returns Easter Sunday of the year, using the Gregorian computus.
+pub fn easter(year: i32) -> Option<NaiveDate> {
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let (d, e) = (b.div_euclid(4), b.rem_euclid(4));
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let n = h + l - 7 * m + 114;
+
+    NaiveDate::from_ymd_opt(year, (n / 31) as u32, (n % 31 + 1) as u32)
+}
+
+/// Returns Ash Wednesday, the first day of Lent, 46 days before Easter.
+pub fn ash_wednesday(year: i32) -> Option<NaiveDate> {
+    easter(year)?.checked_sub_signed(Duration::days(46))
+}
+
+/// Returns Pentecost, the last day of Eastertide, 49 days after Easter.
+pub fn pentecost(year: i32) -> Option<NaiveDate> {
+    easter(year)?.checked_add_signed(Duration::days(49))
+}
+
+/// Returns the First Sunday of Advent, the fourth Sunday before Christmas.
+pub fn first_sunday_of_advent(year: i32) -> Option<NaiveDate> {
+    let christmas = NaiveDate::from_ymd_opt(year, 12, 25)?;
+    let days_since_sunday = match christmas.weekday().num_days_from_sunday() {
+        0 => 7,
+        days => days,
+    };
+
+    christmas.checked_sub_signed(Duration::days(i64::from(days_since_sunday) + 21))
+}
+
+/// Returns the Baptism of the Lord, the Sunday after January 6, which closes Christmastide.
+pub fn baptism_of_the_lord(year: i32) -> Option<NaiveDate> {
+    let epiphany = NaiveDate::from_ymd_opt(year, 1, 6)?;
+    let days_until_sunday = 7 - epiphany.weekday().num_days_from_sunday();
+
+    epiphany.checked_add_signed(Duration::days(i64::from(days_until_sunday)))
+}
+
+/// Returns the liturgical season containing the provided date.
+pub fn season(date: &NaiveDate) -> Option<Season> {
+    Some(season_span(date)?.0)
+}
+
+/// Returns the first day of the liturgical season containing the provided date.
+pub fn beginning_of_season(date: &NaiveDate) -> Option<NaiveDate> {
+    Some(season_span(date)?.1)
+}
+
+/// Returns the last day of the liturgical season containing the provided date.
+pub fn end_of_season(date: &NaiveDate) -> Option<NaiveDate> {
+    next_season(date)?.pred_opt()
+}
+
+/// Returns the first day of the next liturgical season.
+pub fn next_season(date: &NaiveDate) -> Option<NaiveDate> {
+    Some(season_span(date)?.2)
+}
+
+/// Returns the first day of the previous liturgical season.
+pub fn previous_season(date: &NaiveDate) -> Option<NaiveDate> {
+    beginning_of_season(&beginning_of_season(date)?.pred_opt()?)
+}
+
+/// Returns the First Sunday of Advent beginning the liturgical year containing the provided date.
+pub fn beginning_of_liturgical_year(date: &NaiveDate) -> Option<NaiveDate> {
+    let advent = first_sunday_of_advent(date.year())?;
+
+    if advent <= *date {
+        Some(advent)
+    } else {
+        first_sunday_of_advent(date.year() - 1)
+    }
+}
+
+// The season containing the date, its first day, and the first day of the following season
+fn season_span(date: &NaiveDate) -> Option<(Season, NaiveDate, NaiveDate)> {
+    let mut starts = [(Season::Advent, NaiveDate::MIN); 18];
+    for (starts, year) in starts.chunks_mut(6).zip(date.year() - 1..) {
+        starts.copy_from_slice(&season_starts(year)?);
+    }
+
+    let index = starts.iter().rposition(|(_, start)| start <= date)?;
+    let (season, start) = starts[index];
+    let (_, next) = *starts.get(index + 1)?;
+
+    Some((season, start, next))
+}
+
+fn season_starts(year: i32) -> Option<[(Season, NaiveDate); 6]> {
+    Some([
+        (Season::OrdinaryTime, baptism_of_the_lord(year)?.succ_opt()?),
+        (Season::Lent, ash_wednesday(year)?),
+        (Season::Eastertide, easter(year)?),
+        (Season::OrdinaryTime, pentecost(year)?.succ_opt()?),
+        (Season::Advent, first_sunday_of_advent(year)?),
+        (
+            Season::Christmastide,
+            NaiveDate::from_ymd_opt(year, 12, 25)?,
+        ),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn computes_easter() {
+        for &(year, month, day) in &[
+            (1818, 3, 22),
+            (1943, 4, 25),
+            (2000, 4, 23),
+            (2019, 4, 21),
+            (2024, 3, 31),
+            (2025, 4, 20),
+            (2038, 4, 25),
+        ] {
+            assert_eq!(easter(year), Some(ymd(year, month, day)), "{}", year);
+        }
+    }
+
+    #[test]
+    fn computes_feasts() {
+        assert_eq!(ash_wednesday(2025), Some(ymd(2025, 3, 5)));
+        assert_eq!(pentecost(2025), Some(ymd(2025, 6, 8)));
+        assert_eq!(first_sunday_of_advent(2024), Some(ymd(2024, 12, 1)));
+        assert_eq!(first_sunday_of_advent(2022), Some(ymd(2022, 11, 27)));
+        assert_eq!(first_sunday_of_advent(2023), Some(ymd(2023, 12, 3)));
+        assert_eq!(baptism_of_the_lord(2024), Some(ymd(2024, 1, 7)));
+        assert_eq!(baptism_of_the_lord(2019), Some(ymd(2019, 1, 13)));
+    }
+
+    #[test]
+    fn finds_seasons() {
+        assert_eq!(season(&ymd(2024, 12, 24)), Some(Season::Advent));
+        assert_eq!(season(&ymd(2024, 12, 25)), Some(Season::Christmastide));
+        assert_eq!(season(&ymd(2025, 1, 12)), Some(Season::Christmastide));
+        assert_eq!(season(&ymd(2025, 1, 13)), Some(Season::OrdinaryTime));
+        assert_eq!(season(&ymd(2025, 4, 19)), Some(Season::Lent));
+        assert_eq!(season(&ymd(2025, 6, 8)), Some(Season::Eastertide));
+        assert_eq!(season(&ymd(2025, 6, 9)), Some(Season::OrdinaryTime));
+        assert_eq!(Season::OrdinaryTime.to_string(), "Ordinary Time");
+    }
+
+    #[test]
+    fn computes_season_boundaries() {
+        let christmas = ymd(2024, 12, 31);
+
+        assert_eq!(beginning_of_season(&christmas), Some(ymd(2024, 12, 25)));
+        assert_eq!(end_of_season(&christmas), Some(ymd(2025, 1, 12)));
+        assert_eq!(next_season(&christmas), Some(ymd(2025, 1, 13)));
+        assert_eq!(previous_season(&christmas), Some(ymd(2024, 12, 1)));
+
+        let summer = ymd(2024, 8, 15);
+        assert_eq!(beginning_of_season(&summer), Some(ymd(2024, 5, 20)));
+        assert_eq!(end_of_season(&summer), Some(ymd(2024, 11, 30)));
+        assert_eq!(
+            beginning_of_liturgical_year(&summer),
+            Some(ymd(2023, 12, 3))
+        );
+        assert_eq!(
+            beginning_of_liturgical_year(&christmas),
+            Some(ymd(2024, 12, 1))
+        );
+    }
+
+    #[quickcheck]
+    fn seasons_contain_their_dates(date: ArbitraryDate) -> bool {
+        let start = beginning_of_season(&date.0).unwrap();
+        let end = end_of_season(&date.0).unwrap();
+
+        start <= date.0 && date.0 <= end && season(&start) == season(&end)
+    }
+}
@@ -0,0 +1,423 @@
+//! Locale-specific week conventions, enabled with the `locale` feature.
+//!
+//! The first day of the week and the weekend for each region follow the `weekData` supplemental
+//! data of the Unicode CLDR, embedded in this crate so no data files are needed at runtime.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::locale::week_data;
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+//!
+//! assert_eq!(week_data("en-US").first_day(), Weekday::Sun);
+//! assert_eq!(week_data("de-DE").first_day(), Weekday::Mon);
+//! assert_eq!(
+//!     week_data("ar-EG").beginning_of_week(&date),
+//!     NaiveDate::from_ymd_opt(2024, 5, 11)
+//! );
+//! assert!(week_data("ar-EG").is_weekend(Weekday::Fri));
+//! ```
+//!
+//! Month names for English, Spanish, German, and French are also embedded for parsing months:
+//!
+//! ```
+//! use date_calculations::locale::parse_year_month;
+//! use date_calculations::YearMonth;
+//!
+//! assert_eq!(parse_year_month("März 2024", "de-DE"), Ok(YearMonth::new(2024, 3).unwrap()));
+//! assert_eq!(parse_year_month("2024 févr.", "fr"), Ok(YearMonth::new(2024, 2).unwrap()));
+//! ```
+//!
+//! and for labeling months, quarters, and ISO weeks:
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::locale::label;
+//! use date_calculations::{Quarter, YearMonth};
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 8, 28).unwrap();
+//!
+//! assert_eq!(label(Quarter::from_date(&date), "en-US"), "Q3 2024");
+//! assert_eq!(label(date.iso_week(), "de-DE"), "KW 35/2024");
+//! assert_eq!(label(YearMonth::new(2024, 7).unwrap(), "fr-FR"), "juillet 2024");
+//! ```
+
+use crate::business::Weekend;
+use crate::year_month::{parse_with_names, MONTH_NAMES};
+use crate::{
+    beginning_of_week_starting_on, end_of_week_starting_on, next_week_starting_on,
+    previous_week_starting_on, ParseError, Quarter, YearMonth,
+};
+use alloc::{format, string::String};
+use chrono::prelude::*;
+use chrono::IsoWeek;
+
+/// The week conventions of a region: the day weeks begin and the days of the weekend.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WeekData {
+    first_day: Weekday,
+    weekend_start: Weekday,
+    weekend_end: Weekday,
+}
+
+impl WeekData {
+    /// Returns the day weeks begin on.
+    pub fn first_day(&self) -> Weekday {
+        self.first_day
+    }
+
+    /// Returns the first day of the weekend.
+    pub fn weekend_start(&self) -> Weekday {
+        self.weekend_start
+    }
+
+    /// Returns the last day of the weekend, which is the same as the first for single-day
+    /// weekends.
+    pub fn weekend_end(&self) -> Weekday {
+        self.weekend_end
+    }
+
+    /// Returns whether the weekday falls within the weekend.
+    pub fn is_weekend(&self, weekday: Weekday) -> bool {
+        let offset = |day: Weekday| {
+            (day.num_days_from_monday() + 7 - self.weekend_start.num_days_from_monday()) % 7
+        };
+
+        offset(weekday) <= offset(self.weekend_end)
+    }
+
+    /// Returns the weekend, for business-day calculations.
+    pub fn weekend(&self) -> Weekend {
+        let mut days = [self.weekend_start; 7];
+        let mut day = self.weekend_start;
+        let mut len = 1;
+        while day != self.weekend_end {
+            day = day.succ();
+            days[len] = day;
+            len += 1;
+        }
+
+        Weekend::new(&days[..len]).expect("weekends leave business days")
+    }
+
+    /// Returns the beginning of the week relative to the provided date.
+    pub fn beginning_of_week(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        beginning_of_week_starting_on(date, self.first_day)
+    }
+
+    /// Returns the end of the week relative to the provided date.
+    pub fn end_of_week(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        end_of_week_starting_on(date, self.first_day)
+    }
+
+    /// Returns the beginning of the next week.
+    pub fn next_week(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        next_week_starting_on(date, self.first_day)
+    }
+
+    /// Returns the beginning of the previous week.
+    pub fn previous_week(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        previous_week_starting_on(date, self.first_day)
+    }
+}
+
+/// Returns the week conventions for a locale identifier such as `en-US`, `de_DE`, or `ar-EG`.
+///
+/// Conventions are determined by the region subtag. Identifiers without a recognized region,
+/// such as `en`, use the CLDR world default: weeks beginning Monday with a Saturday–Sunday
+/// weekend.
+pub fn week_data(locale: &str) -> WeekData {
+    let region = locale
+        .split(['-', '_'])
+        .skip(1)
+        .find(|subtag| subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+        .map(|subtag| subtag.to_ascii_uppercase());
+
+    region
+        .map(|region| region_week_data(&region))
+        .unwrap_or(WORLD)
+}
+
+/// Parses a year and month written with the locale's month names, in the layouts accepted by
+/// [`YearMonth`]'s `FromStr` implementation.
+///
+/// Names match regardless of case and accents and may be abbreviated to any unambiguous prefix
+/// of at least three letters. Locales whose language has no embedded names use English.
+pub fn parse_year_month(input: &str, locale: &str) -> Result<YearMonth, ParseError> {
+    parse_with_names(
+        input,
+        "a month like \"2024-03\" or a localized \"Mar 2024\"",
+        month_names(locale),
+    )
+}
+
+/// A month, quarter, or ISO week to [`label`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PeriodValue {
+    /// A calendar month.
+    Month(YearMonth),
+    /// A calendar quarter.
+    Quarter(Quarter),
+    /// An ISO 8601 week.
+    IsoWeek(IsoWeek),
+}
+
+impl From<YearMonth> for PeriodValue {
+    fn from(month: YearMonth) -> Self {
+        PeriodValue::Month(month)
+    }
+}
+
+impl From<Quarter> for PeriodValue {
+    fn from(quarter: Quarter) -> Self {
+        PeriodValue::Quarter(quarter)
+    }
+}
+
+impl From<IsoWeek> for PeriodValue {
+    fn from(week: IsoWeek) -> Self {
+        PeriodValue::IsoWeek(week)
+    }
+}
+
+/// Returns a short human label for the period in the locale's language, suitable for chart
+/// axes.
+///
+/// | Language | Month          | Quarter   | ISO week     |
+/// |----------|----------------|-----------|--------------|
+/// | English  | `July 2024`    | `Q3 2024` | `W35 2024`   |
+/// | Spanish  | `julio 2024`   | `T3 2024` | `S35 2024`   |
+/// | German   | `Juli 2024`    | `Q3 2024` | `KW 35/2024` |
+/// | French   | `juillet 2024` | `T3 2024` | `S35 2024`   |
+///
+/// Locales whose language has no embedded labels use English.
+pub fn label<V: Into<PeriodValue>>(value: V, locale: &str) -> String {
+    let language = primary_language(locale);
+
+    match value.into() {
+        PeriodValue::Month(month) => format!(
+            "{} {}",
+            month_names(locale)[month.month() as usize - 1],
+            month.year()
+        ),
+        PeriodValue::Quarter(quarter) => {
+            let prefix = match language.as_str() {
+                "es" | "fr" => "T",
+                _ => "Q",
+            };
+            format!("{}{} {}", prefix, quarter.quarter(), quarter.year())
+        }
+        PeriodValue::IsoWeek(week) => match language.as_str() {
+            "de" => format!("KW {}/{}", week.week(), week.year()),
+            "es" | "fr" => format!("S{} {}", week.week(), week.year()),
+            _ => format!("W{} {}", week.week(), week.year()),
+        },
+    }
+}
+
+fn primary_language(locale: &str) -> String {
+    locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+pub(crate) fn month_names(locale: &str) -> &'static [&'static str; 12] {
+    match primary_language(locale).as_str() {
+        "es" => &SPANISH_MONTHS,
+        "de" => &GERMAN_MONTHS,
+        "fr" => &FRENCH_MONTHS,
+        _ => &MONTH_NAMES,
+    }
+}
+
+const SPANISH_MONTHS: [&str; 12] = [
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+
+const GERMAN_MONTHS: [&str; 12] = [
+    "Januar",
+    "Februar",
+    "März",
+    "April",
+    "Mai",
+    "Juni",
+    "Juli",
+    "August",
+    "September",
+    "Oktober",
+    "November",
+    "Dezember",
+];
+
+const FRENCH_MONTHS: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+
+const WORLD: WeekData = WeekData {
+    first_day: Weekday::Mon,
+    weekend_start: Weekday::Sat,
+    weekend_end: Weekday::Sun,
+};
+
+const FIRST_DAY_FRIDAY: &[&str] = &["MV"];
+
+const FIRST_DAY_SATURDAY: &[&str] = &[
+    "AE", "AF", "BH", "DJ", "DZ", "EG", "IQ", "IR", "JO", "KW", "LY", "OM", "QA", "SD", "SY",
+];
+
+const FIRST_DAY_SUNDAY: &[&str] = &[
+    "AG", "AS", "BD", "BR", "BS", "BT", "BW", "BZ", "CA", "CN", "CO", "DM", "DO", "ET", "GT", "GU",
+    "HK", "HN", "ID", "IL", "IN", "JM", "JP", "KE", "KH", "KR", "LA", "MH", "MM", "MO", "MT", "MX",
+    "MZ", "NI", "NP", "PA", "PE", "PH", "PK", "PR", "PT", "PY", "SA", "SG", "SV", "TH", "TT", "TW",
+    "UM", "US", "VE", "VI", "WS", "YE", "ZA", "ZW",
+];
+
+const WEEKEND_FRIDAY_SATURDAY: &[&str] = &[
+    "BH", "DZ", "EG", "IL", "IQ", "JO", "KW", "LY", "OM", "QA", "SA", "SD", "SY", "YE",
+];
+
+fn region_week_data(region: &str) -> WeekData {
+    let first_day = if FIRST_DAY_FRIDAY.contains(&region) {
+        Weekday::Fri
+    } else if FIRST_DAY_SATURDAY.contains(&region) {
+        Weekday::Sat
+    } else if FIRST_DAY_SUNDAY.contains(&region) {
+        Weekday::Sun
+    } else {
+        Weekday::Mon
+    };
+
+    let (weekend_start, weekend_end) = match region {
+        "AF" => (Weekday::Thu, Weekday::Fri),
+        "IR" => (Weekday::Fri, Weekday::Fri),
+        "IN" | "UG" => (Weekday::Sun, Weekday::Sun),
+        r if WEEKEND_FRIDAY_SATURDAY.contains(&r) => (Weekday::Fri, Weekday::Sat),
+        _ => (Weekday::Sat, Weekday::Sun),
+    };
+
+    WeekData {
+        first_day,
+        weekend_start,
+        weekend_end,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_first_days_by_region() {
+        assert_eq!(week_data("en-US").first_day(), Weekday::Sun);
+        assert_eq!(week_data("en-GB").first_day(), Weekday::Mon);
+        assert_eq!(week_data("de_DE").first_day(), Weekday::Mon);
+        assert_eq!(week_data("ar-EG").first_day(), Weekday::Sat);
+        assert_eq!(week_data("dv-MV").first_day(), Weekday::Fri);
+        assert_eq!(week_data("zh-Hant-tw").first_day(), Weekday::Sun);
+    }
+
+    #[test]
+    fn falls_back_to_the_world_default() {
+        assert_eq!(week_data("en"), WORLD);
+        assert_eq!(week_data(""), WORLD);
+        assert_eq!(week_data("en-419"), WORLD);
+    }
+
+    #[test]
+    fn resolves_weekends_by_region() {
+        let israel = week_data("he-IL");
+        assert!(israel.is_weekend(Weekday::Fri) && israel.is_weekend(Weekday::Sat));
+        assert!(!israel.is_weekend(Weekday::Sun));
+
+        let iran = week_data("fa-IR");
+        assert!(iran.is_weekend(Weekday::Fri));
+        assert!(!iran.is_weekend(Weekday::Sat) && !iran.is_weekend(Weekday::Thu));
+
+        let germany = week_data("de-DE");
+        assert!(germany.is_weekend(Weekday::Sat) && germany.is_weekend(Weekday::Sun));
+        assert!(!germany.is_weekend(Weekday::Mon) && !germany.is_weekend(Weekday::Fri));
+
+        assert_eq!(israel.weekend(), Weekend::FRIDAY_SATURDAY);
+        assert_eq!(iran.weekend(), Weekend::single(Weekday::Fri));
+        assert_eq!(germany.weekend(), Weekend::default());
+    }
+
+    #[test]
+    fn parses_localized_month_names() {
+        let parse = |input, locale| parse_year_month(input, locale).ok();
+
+        assert_eq!(parse("enero 2024", "es-MX"), YearMonth::new(2024, 1));
+        assert_eq!(parse("2024-dic", "es"), YearMonth::new(2024, 12));
+        assert_eq!(parse("Maerz 2024", "de"), None);
+        assert_eq!(parse("MÄR 2024", "de-AT"), YearMonth::new(2024, 3));
+        assert_eq!(parse("Okt. 2024", "de"), YearMonth::new(2024, 10));
+        assert_eq!(parse("aout 2024", "fr-CA"), YearMonth::new(2024, 8));
+        assert_eq!(parse("juil 2024", "fr"), YearMonth::new(2024, 7));
+        assert_eq!(parse("jui 2024", "fr"), None);
+        assert_eq!(parse("2024/03", "fr"), YearMonth::new(2024, 3));
+        assert_eq!(parse("Oct 2024", "ja-JP"), YearMonth::new(2024, 10));
+    }
+
+    #[test]
+    fn labels_periods() {
+        let week = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap().iso_week();
+        let quarter = Quarter::new(2024, 1).unwrap();
+        let month = YearMonth::new(2024, 3).unwrap();
+
+        assert_eq!(label(week, "en"), "W1 2025");
+        assert_eq!(label(week, "de_CH"), "KW 1/2025");
+        assert_eq!(label(week, "es-ES"), "S1 2025");
+        assert_eq!(label(quarter, "fr"), "T1 2024");
+        assert_eq!(label(quarter, "pt-BR"), "Q1 2024");
+        assert_eq!(label(month, "es"), "marzo 2024");
+        assert_eq!(label(month, "de"), "März 2024");
+        assert_eq!(label(month, "EN-gb"), "March 2024");
+    }
+
+    #[test]
+    fn computes_week_boundaries() {
+        let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+        let germany = week_data("de-DE");
+
+        assert_eq!(
+            germany.beginning_of_week(&date),
+            NaiveDate::from_ymd_opt(2024, 5, 13)
+        );
+        assert_eq!(
+            germany.end_of_week(&date),
+            NaiveDate::from_ymd_opt(2024, 5, 19)
+        );
+        assert_eq!(
+            germany.next_week(&date),
+            NaiveDate::from_ymd_opt(2024, 5, 20)
+        );
+        assert_eq!(
+            germany.previous_week(&date),
+            NaiveDate::from_ymd_opt(2024, 5, 6)
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! Dates of the moon's phases.
+//!
+//! Phases are computed with Meeus's lunar series, omitting its planetary terms, which places each
+//! principal phase within a few minutes for the years 1900 through 2100. Dates are reckoned in
+//! Universal Time, so a phase within minutes of midnight UTC may be assigned to the neighbouring
+//! day, and local calendars east or west of Greenwich can list it a day later or earlier.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::moon::{self, Phase};
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+//!
+//! assert_eq!(moon::next_new_moon(&date), NaiveDate::from_ymd_opt(2024, 4, 8));
+//! assert_eq!(moon::next_full_moon(&date), NaiveDate::from_ymd_opt(2024, 4, 23));
+//! assert_eq!(moon::phase(&date), Phase::WaningGibbous);
+//! ```
+
+use crate::astronomy::{lunar_phase_at_or_after, LunarPhase};
+use crate::day_number::{from_rata_die, to_rata_die};
+use chrono::prelude::*;
+use core::fmt;
+
+/// A phase of the moon.
+///
+/// The four principal phases are instants, so a day has a principal phase only when that instant
+/// falls within it; the days in between take the intermediate phase in progress.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// The moon is in conjunction with the sun.
+    NewMoon,
+    /// Less than half the disc is lit, and growing.
+    WaxingCrescent,
+    /// Half the disc is lit, and growing.
+    FirstQuarter,
+    /// More than half the disc is lit, and growing.
+    WaxingGibbous,
+    /// The moon is opposite the sun.
+    FullMoon,
+    /// More than half the disc is lit, and shrinking.
+    WaningGibbous,
+    /// Half the disc is lit, and shrinking.
+    LastQuarter,
+    /// Less than half the disc is lit, and shrinking.
+    WaningCrescent,
+}
+
+/// Formats the phase's name, e.g. `Waxing Crescent`.
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Phase::NewMoon => "New Moon",
+            Phase::WaxingCrescent => "Waxing Crescent",
+            Phase::FirstQuarter => "First Quarter",
+            Phase::WaxingGibbous => "Waxing Gibbous",
+            Phase::FullMoon => "Full Moon",
+            Phase::WaningGibbous => "Waning Gibbous",
+            Phase::LastQuarter => "Last Quarter",
+            Phase::WaningCrescent => "Waning Crescent",
+        })
+    }
+}
+
+const PRINCIPAL: [(LunarPhase, Phase, Phase); 4] = [
+    (LunarPhase::New, Phase::NewMoon, Phase::WaningCrescent),
+    (
+        LunarPhase::FirstQuarter,
+        Phase::FirstQuarter,
+        Phase::WaxingCrescent,
+    ),
+    (LunarPhase::Full, Phase::FullMoon, Phase::WaxingGibbous),
+    (
+        LunarPhase::LastQuarter,
+        Phase::LastQuarter,
+        Phase::WaningGibbous,
+    ),
+];
+
+/// Returns the moon's phase on the provided date.
+pub fn phase(date: &NaiveDate) -> Phase {
+    let midnight = to_rata_die(date) as f64;
+
+    let (moment, principal, leading_up) = PRINCIPAL
+        .iter()
+        .map(|&(lunar, principal, leading_up)| {
+            (
+                lunar_phase_at_or_after(lunar, midnight),
+                principal,
+                leading_up,
+            )
+        })
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .expect("there are four principal phases");
+
+    if moment < midnight + 1.0 {
+        principal
+    } else {
+        leading_up
+    }
+}
+
+/// Returns the date of the first new moon after the provided date.
+pub fn next_new_moon(date: &NaiveDate) -> Option<NaiveDate> {
+    next(LunarPhase::New, date)
+}
+
+/// Returns the date of the first full moon after the provided date.
+pub fn next_full_moon(date: &NaiveDate) -> Option<NaiveDate> {
+    next(LunarPhase::Full, date)
+}
+
+/// Returns the date of the first first-quarter moon after the provided date.
+pub fn next_first_quarter(date: &NaiveDate) -> Option<NaiveDate> {
+    next(LunarPhase::FirstQuarter, date)
+}
+
+/// Returns the date of the first last-quarter moon after the provided date.
+pub fn next_last_quarter(date: &NaiveDate) -> Option<NaiveDate> {
+    next(LunarPhase::LastQuarter, date)
+}
+
+fn next(phase: LunarPhase, date: &NaiveDate) -> Option<NaiveDate> {
+    let midnight = (to_rata_die(date) + 1) as f64;
+    from_rata_die(lunar_phase_at_or_after(phase, midnight).floor() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn finds_principal_phases() {
+        // Published UTC dates for 2024
+        let date = ymd(2024, 1, 1);
+
+        assert_eq!(next_new_moon(&date), Some(ymd(2024, 1, 11)));
+        assert_eq!(next_first_quarter(&date), Some(ymd(2024, 1, 18)));
+        assert_eq!(next_full_moon(&date), Some(ymd(2024, 1, 25)));
+        assert_eq!(next_last_quarter(&date), Some(ymd(2024, 1, 4)));
+        assert_eq!(next_full_moon(&ymd(2024, 8, 19)), Some(ymd(2024, 9, 18)));
+        assert_eq!(next_new_moon(&ymd(2000, 1, 1)), Some(ymd(2000, 1, 6)));
+    }
+
+    #[test]
+    fn names_phases_on_dates() {
+        assert_eq!(phase(&ymd(2024, 4, 8)), Phase::NewMoon);
+        assert_eq!(phase(&ymd(2024, 4, 10)), Phase::WaxingCrescent);
+        assert_eq!(phase(&ymd(2024, 4, 15)), Phase::FirstQuarter);
+        assert_eq!(phase(&ymd(2024, 4, 20)), Phase::WaxingGibbous);
+        assert_eq!(phase(&ymd(2024, 4, 23)), Phase::FullMoon);
+        assert_eq!(phase(&ymd(2024, 4, 27)), Phase::WaningGibbous);
+        assert_eq!(phase(&ymd(2024, 5, 1)), Phase::LastQuarter);
+        assert_eq!(Phase::WaxingGibbous.to_string(), "Waxing Gibbous");
+    }
+}
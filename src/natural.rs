@@ -0,0 +1,693 @@
+//! Parsing of natural-language date expressions relative to a reference date.
+//!
+//! Expressions resolve using this crate's own period semantics, so "next week" is the Sunday
+//! beginning the following week and "next Tuesday" is the Tuesday within that week.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::natural::parse_relative;
+//!
+//! let reference = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+//!
+//! assert_eq!(
+//!     parse_relative("next Tuesday", &reference).unwrap(),
+//!     NaiveDate::from_ymd_opt(2024, 5, 21).unwrap()
+//! );
+//! assert_eq!(
+//!     parse_relative("end of next quarter", &reference).unwrap(),
+//!     NaiveDate::from_ymd_opt(2024, 9, 30).unwrap()
+//! );
+//! ```
+
+use crate::year_month::fold_accent;
+use crate::{beginning_of_week, ParseError, Period};
+use alloc::{string::String, vec::Vec};
+use chrono::prelude::*;
+use chrono::Months;
+
+const EXPECTED: &str = "a relative date expression like \"next tuesday\" or \"3 months ago\"";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Direction {
+    This,
+    Next,
+    Last,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Unit {
+    Day,
+    Period(Period),
+}
+
+/// A language whose vocabulary [`parse_relative_in`] understands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// English, as accepted by [`parse_relative`].
+    English,
+    /// Spanish, e.g. `próximo martes` or `hace 3 meses`.
+    Spanish,
+    /// German, e.g. `nächsten Dienstag` or `vor 3 Monaten`.
+    German,
+    /// French, e.g. `mardi prochain` or `il y a 3 mois`.
+    French,
+}
+
+impl Language {
+    /// Returns the language for a locale identifier such as `es-MX` or `de_AT`, based on its
+    /// primary language subtag.
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        let language = locale.split(['-', '_']).next()?.to_ascii_lowercase();
+
+        match language.as_str() {
+            "en" => Some(Language::English),
+            "es" => Some(Language::Spanish),
+            "de" => Some(Language::German),
+            "fr" => Some(Language::French),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Expression {
+    Day(i64),
+    Weekday(Direction, Weekday),
+    Beginning(Direction, Period),
+    End(Direction, Period),
+    Shift(Unit, u32, i64),
+}
+
+/// Parses a relative date expression, resolving it against the reference date.
+///
+/// Supported forms (case-insensitive):
+///
+/// * `today`, `tomorrow`, `yesterday`
+/// * `[this|next|last] <weekday>`, where the weekday is found within the current, next, or
+///   previous week (weeks begin on Sunday); a bare weekday refers to the current week
+/// * `this|next|last <period>`, returning the beginning of that period
+/// * `beginning of|start of|end of [this|next|last] <period>`
+/// * `<n> <unit> ago`, `in <n> <unit>`, and `<n> <unit> from now`, where the unit is days,
+///   weeks, months, quarters, or years; month-based shifts clamp to the end of shorter months
+pub fn parse_relative(input: &str, reference: &NaiveDate) -> Result<NaiveDate, ParseError> {
+    parse_relative_in(input, reference, Language::English)
+}
+
+/// Parses a relative date expression in the given language, resolving it against the reference
+/// date with the same semantics as [`parse_relative`].
+///
+/// Other languages support the equivalents of today, tomorrow, and yesterday; a weekday or
+/// period with a direction (`próxima semana`, `letzten Freitag`, `le mois dernier`); and offsets
+/// into the past or future (`en 3 días`, `vor zwei Wochen`, `dans 3 jours`). Accents are
+/// optional and articles are ignored.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::natural::{parse_relative_in, Language};
+///
+/// let reference = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+/// let german = Language::from_locale("de-DE").unwrap();
+///
+/// assert_eq!(
+///     parse_relative_in("vor 3 Monaten", &reference, german).unwrap(),
+///     NaiveDate::from_ymd_opt(2024, 2, 17).unwrap()
+/// );
+/// ```
+pub fn parse_relative_in(
+    input: &str,
+    reference: &NaiveDate,
+    language: Language,
+) -> Result<NaiveDate, ParseError> {
+    let lowered = input.trim().to_lowercase();
+    let error = || ParseError::new(input, EXPECTED);
+
+    let expression = if language == Language::English {
+        let words: Vec<&str> = lowered.split_whitespace().collect();
+        english(&words)
+    } else {
+        let folded: String = lowered.chars().map(fold_accent).collect();
+        let words: Vec<&str> = folded
+            .split_whitespace()
+            .map(|word| word.strip_prefix("l'").unwrap_or(word))
+            .filter(|word| !is_article(word, language))
+            .collect();
+
+        match language {
+            Language::Spanish => spanish(&words),
+            Language::German => german(&words),
+            Language::French => french(&words),
+            Language::English => None,
+        }
+    };
+
+    expression
+        .and_then(|expression| resolve(reference, expression))
+        .ok_or_else(error)
+}
+
+fn resolve(reference: &NaiveDate, expression: Expression) -> Option<NaiveDate> {
+    match expression {
+        Expression::Day(days) => reference.checked_add_signed(chrono::Duration::days(days)),
+        Expression::Weekday(direction, weekday) => {
+            weekday_in_direction(reference, weekday, direction)
+        }
+        Expression::Beginning(direction, period) => {
+            period_in_direction(reference, period, direction)
+        }
+        Expression::End(direction, period) => {
+            period_in_direction(reference, period, direction).and_then(|d| period.end(&d))
+        }
+        Expression::Shift(unit, amount, sign) => shift(reference, unit, amount, sign),
+    }
+}
+
+fn english(words: &[&str]) -> Option<Expression> {
+    match words {
+        ["today"] | ["now"] => Some(Expression::Day(0)),
+        ["tomorrow"] => Some(Expression::Day(1)),
+        ["yesterday"] => Some(Expression::Day(-1)),
+        ["beginning", "of", rest @ ..] | ["start", "of", rest @ ..] => {
+            let (direction, period) = parse_period_reference(rest)?;
+            Some(Expression::Beginning(direction, period))
+        }
+        ["end", "of", rest @ ..] => {
+            let (direction, period) = parse_period_reference(rest)?;
+            Some(Expression::End(direction, period))
+        }
+        ["in", amount, unit] | [amount, unit, "from", "now"] => Some(Expression::Shift(
+            parse_unit(unit)?,
+            parse_amount(amount)?,
+            1,
+        )),
+        [amount, unit, "ago"] => Some(Expression::Shift(
+            parse_unit(unit)?,
+            parse_amount(amount)?,
+            -1,
+        )),
+        [weekday] => Some(Expression::Weekday(
+            Direction::This,
+            parse_weekday(weekday)?,
+        )),
+        [direction, target] => directed(
+            parse_direction(direction)?,
+            target,
+            parse_weekday,
+            parse_period,
+        ),
+        _ => None,
+    }
+}
+
+fn spanish(words: &[&str]) -> Option<Expression> {
+    let direction = |word: &str| match word {
+        "este" | "esta" => Some(Direction::This),
+        "proximo" | "proxima" | "siguiente" => Some(Direction::Next),
+        "pasado" | "pasada" | "ultimo" | "ultima" | "anterior" => Some(Direction::Last),
+        _ => None,
+    };
+    let period = |word: &str| match word {
+        "semana" => Some(Period::Week),
+        "mes" => Some(Period::Month),
+        "trimestre" => Some(Period::Quarter),
+        "ano" => Some(Period::Year),
+        _ => None,
+    };
+    let unit = |word: &str| match word {
+        "dia" | "dias" => Some(Unit::Day),
+        "semanas" => Some(Unit::Period(Period::Week)),
+        "meses" => Some(Unit::Period(Period::Month)),
+        "trimestres" => Some(Unit::Period(Period::Quarter)),
+        "anos" => Some(Unit::Period(Period::Year)),
+        other => period(other).map(Unit::Period),
+    };
+    let amount = |word: &str| {
+        number_word(
+            word,
+            &[
+                &["un", "uno", "una"],
+                &["dos"],
+                &["tres"],
+                &["cuatro"],
+                &["cinco"],
+                &["seis"],
+                &["siete"],
+                &["ocho"],
+                &["nueve"],
+                &["diez"],
+            ],
+        )
+    };
+    let weekday = |word: &str| match word {
+        "domingo" => Some(Weekday::Sun),
+        "lunes" => Some(Weekday::Mon),
+        "martes" => Some(Weekday::Tue),
+        "miercoles" => Some(Weekday::Wed),
+        "jueves" => Some(Weekday::Thu),
+        "viernes" => Some(Weekday::Fri),
+        "sabado" => Some(Weekday::Sat),
+        _ => None,
+    };
+
+    match words {
+        ["hoy"] => Some(Expression::Day(0)),
+        ["manana"] => Some(Expression::Day(1)),
+        ["ayer"] => Some(Expression::Day(-1)),
+        ["hace", n, u] => Some(Expression::Shift(unit(u)?, amount(n)?, -1)),
+        ["en", n, u] | ["dentro", "de", n, u] => Some(Expression::Shift(unit(u)?, amount(n)?, 1)),
+        [target, "que", "viene"] => directed(Direction::Next, target, weekday, period),
+        [day] => Some(Expression::Weekday(Direction::This, weekday(day)?)),
+        [first, second] => match direction(first) {
+            Some(d) => directed(d, second, weekday, period),
+            None => directed(direction(second)?, first, weekday, period),
+        },
+        _ => None,
+    }
+}
+
+fn german(words: &[&str]) -> Option<Expression> {
+    let direction = |word: &str| {
+        let stem = word
+            .strip_suffix(['n', 's', 'r'])
+            .filter(|stem| stem.ends_with('e'))
+            .unwrap_or(word);
+
+        match stem {
+            "diese" => Some(Direction::This),
+            "nachste" | "kommende" => Some(Direction::Next),
+            "letzte" | "vorige" | "vergangene" => Some(Direction::Last),
+            _ => None,
+        }
+    };
+    let period = |word: &str| match word {
+        "woche" => Some(Period::Week),
+        "monat" => Some(Period::Month),
+        "quartal" => Some(Period::Quarter),
+        "jahr" => Some(Period::Year),
+        _ => None,
+    };
+    let unit = |word: &str| match word {
+        "tag" | "tage" | "tagen" => Some(Unit::Day),
+        "wochen" => Some(Unit::Period(Period::Week)),
+        "monate" | "monaten" => Some(Unit::Period(Period::Month)),
+        "quartale" | "quartalen" => Some(Unit::Period(Period::Quarter)),
+        "jahre" | "jahren" => Some(Unit::Period(Period::Year)),
+        other => period(other).map(Unit::Period),
+    };
+    let amount = |word: &str| {
+        number_word(
+            word,
+            &[
+                &["ein", "eine", "einem", "einer", "einen", "eins"],
+                &["zwei"],
+                &["drei"],
+                &["vier"],
+                &["funf"],
+                &["sechs"],
+                &["sieben"],
+                &["acht"],
+                &["neun"],
+                &["zehn"],
+            ],
+        )
+    };
+    let weekday = |word: &str| match word {
+        "sonntag" => Some(Weekday::Sun),
+        "montag" => Some(Weekday::Mon),
+        "dienstag" => Some(Weekday::Tue),
+        "mittwoch" => Some(Weekday::Wed),
+        "donnerstag" => Some(Weekday::Thu),
+        "freitag" => Some(Weekday::Fri),
+        "samstag" | "sonnabend" => Some(Weekday::Sat),
+        _ => None,
+    };
+
+    match words {
+        ["heute"] => Some(Expression::Day(0)),
+        ["morgen"] => Some(Expression::Day(1)),
+        ["gestern"] => Some(Expression::Day(-1)),
+        ["vor", n, u] => Some(Expression::Shift(unit(u)?, amount(n)?, -1)),
+        ["in", n, u] => Some(Expression::Shift(unit(u)?, amount(n)?, 1)),
+        [day] => Some(Expression::Weekday(Direction::This, weekday(day)?)),
+        [d, target] => directed(direction(d)?, target, weekday, period),
+        _ => None,
+    }
+}
+
+fn french(words: &[&str]) -> Option<Expression> {
+    let direction = |word: &str| match word {
+        "prochain" | "prochaine" | "suivant" | "suivante" => Some(Direction::Next),
+        "dernier" | "derniere" | "passe" | "passee" | "precedent" | "precedente" => {
+            Some(Direction::Last)
+        }
+        _ => None,
+    };
+    let period = |word: &str| match word {
+        "semaine" => Some(Period::Week),
+        "mois" => Some(Period::Month),
+        "trimestre" => Some(Period::Quarter),
+        "an" | "annee" => Some(Period::Year),
+        _ => None,
+    };
+    let unit = |word: &str| match word.strip_suffix('s').unwrap_or(word) {
+        "jour" => Some(Unit::Day),
+        // "mois" is both singular and plural
+        "moi" => Some(Unit::Period(Period::Month)),
+        other => period(other).map(Unit::Period),
+    };
+    let amount = |word: &str| {
+        number_word(
+            word,
+            &[
+                &["un", "une"],
+                &["deux"],
+                &["trois"],
+                &["quatre"],
+                &["cinq"],
+                &["six"],
+                &["sept"],
+                &["huit"],
+                &["neuf"],
+                &["dix"],
+            ],
+        )
+    };
+    let weekday = |word: &str| match word {
+        "dimanche" => Some(Weekday::Sun),
+        "lundi" => Some(Weekday::Mon),
+        "mardi" => Some(Weekday::Tue),
+        "mercredi" => Some(Weekday::Wed),
+        "jeudi" => Some(Weekday::Thu),
+        "vendredi" => Some(Weekday::Fri),
+        "samedi" => Some(Weekday::Sat),
+        _ => None,
+    };
+
+    match words {
+        ["aujourd'hui"] => Some(Expression::Day(0)),
+        ["demain"] => Some(Expression::Day(1)),
+        ["hier"] => Some(Expression::Day(-1)),
+        ["il", "y", "a", n, u] => Some(Expression::Shift(unit(u)?, amount(n)?, -1)),
+        ["dans", n, u] => Some(Expression::Shift(unit(u)?, amount(n)?, 1)),
+        [day] => Some(Expression::Weekday(Direction::This, weekday(day)?)),
+        ["ce", target] | ["cet", target] | ["cette", target] => {
+            directed(Direction::This, target, weekday, period)
+        }
+        [target, d] => directed(direction(d)?, target, weekday, period),
+        _ => None,
+    }
+}
+
+fn directed(
+    direction: Direction,
+    target: &str,
+    weekday: impl Fn(&str) -> Option<Weekday>,
+    period: impl Fn(&str) -> Option<Period>,
+) -> Option<Expression> {
+    match weekday(target) {
+        Some(weekday) => Some(Expression::Weekday(direction, weekday)),
+        None => Some(Expression::Beginning(direction, period(target)?)),
+    }
+}
+
+fn number_word(word: &str, words: &[&[&str]]) -> Option<u32> {
+    match words.iter().position(|forms| forms.contains(&word)) {
+        Some(index) => Some(index as u32 + 1),
+        None => word.parse().ok(),
+    }
+}
+
+fn is_article(word: &str, language: Language) -> bool {
+    match language {
+        Language::Spanish => matches!(word, "el" | "la" | "los" | "las"),
+        Language::German => matches!(word, "am"),
+        Language::French => matches!(word, "le" | "la" | "les"),
+        Language::English => false,
+    }
+}
+
+fn parse_period_reference(words: &[&str]) -> Option<(Direction, Period)> {
+    match words {
+        [period] => Some((Direction::This, parse_period(period)?)),
+        [direction, period] => Some((parse_direction(direction)?, parse_period(period)?)),
+        _ => None,
+    }
+}
+
+fn period_in_direction(
+    reference: &NaiveDate,
+    period: Period,
+    direction: Direction,
+) -> Option<NaiveDate> {
+    match direction {
+        Direction::This => period.beginning(reference),
+        Direction::Next => period.next(reference),
+        Direction::Last => period.previous(reference),
+    }
+}
+
+fn weekday_in_direction(
+    reference: &NaiveDate,
+    weekday: Weekday,
+    direction: Direction,
+) -> Option<NaiveDate> {
+    let week = match direction {
+        Direction::This => beginning_of_week(reference)?,
+        Direction::Next => Period::Week.next(reference)?,
+        Direction::Last => Period::Week.previous(reference)?,
+    };
+
+    week.checked_add_signed(chrono::Duration::days(
+        weekday.num_days_from_sunday().into(),
+    ))
+}
+
+fn shift(reference: &NaiveDate, unit: Unit, amount: u32, sign: i64) -> Option<NaiveDate> {
+    let months = |count: u32| {
+        if sign < 0 {
+            reference.checked_sub_months(Months::new(count))
+        } else {
+            reference.checked_add_months(Months::new(count))
+        }
+    };
+
+    match unit {
+        Unit::Day => reference.checked_add_signed(chrono::Duration::days(sign * i64::from(amount))),
+        Unit::Period(Period::Week) => {
+            reference.checked_add_signed(chrono::Duration::weeks(sign * i64::from(amount)))
+        }
+        Unit::Period(Period::Month) => months(amount),
+        Unit::Period(Period::Quarter) => months(amount.checked_mul(3)?),
+        Unit::Period(Period::Year) => months(amount.checked_mul(12)?),
+    }
+}
+
+fn parse_direction(word: &str) -> Option<Direction> {
+    match word {
+        "this" | "current" => Some(Direction::This),
+        "next" | "following" => Some(Direction::Next),
+        "last" | "previous" | "prior" => Some(Direction::Last),
+        _ => None,
+    }
+}
+
+fn parse_period(word: &str) -> Option<Period> {
+    match word {
+        "week" => Some(Period::Week),
+        "month" => Some(Period::Month),
+        "quarter" => Some(Period::Quarter),
+        "year" => Some(Period::Year),
+        _ => None,
+    }
+}
+
+fn parse_unit(word: &str) -> Option<Unit> {
+    match word.strip_suffix('s').unwrap_or(word) {
+        "day" => Some(Unit::Day),
+        other => parse_period(other).map(Unit::Period),
+    }
+}
+
+fn parse_amount(word: &str) -> Option<u32> {
+    match word {
+        "a" | "an" | "one" => Some(1),
+        "two" => Some(2),
+        "three" => Some(3),
+        "four" => Some(4),
+        "five" => Some(5),
+        "six" => Some(6),
+        "seven" => Some(7),
+        "eight" => Some(8),
+        "nine" => Some(9),
+        "ten" => Some(10),
+        digits => digits.parse().ok(),
+    }
+}
+
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    match word {
+        "sunday" | "sun" => Some(Weekday::Sun),
+        "monday" | "mon" => Some(Weekday::Mon),
+        "tuesday" | "tue" | "tues" => Some(Weekday::Tue),
+        "wednesday" | "wed" => Some(Weekday::Wed),
+        "thursday" | "thu" | "thurs" => Some(Weekday::Thu),
+        "friday" | "fri" => Some(Weekday::Fri),
+        "saturday" | "sat" => Some(Weekday::Sat),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn parses_simple_days() {
+        let reference = ymd(2024, 5, 17);
+
+        assert_eq!(parse_relative("today", &reference), Ok(reference));
+        assert_eq!(parse_relative("Tomorrow", &reference), Ok(ymd(2024, 5, 18)));
+        assert_eq!(
+            parse_relative("yesterday", &reference),
+            Ok(ymd(2024, 5, 16))
+        );
+    }
+
+    #[test]
+    fn parses_weekdays_using_sunday_weeks() {
+        // Friday, May 17 2024; the week runs Sunday May 12 through Saturday May 18
+        let reference = ymd(2024, 5, 17);
+
+        assert_eq!(parse_relative("tuesday", &reference), Ok(ymd(2024, 5, 14)));
+        assert_eq!(
+            parse_relative("this sunday", &reference),
+            Ok(ymd(2024, 5, 12))
+        );
+        assert_eq!(
+            parse_relative("next Tuesday", &reference),
+            Ok(ymd(2024, 5, 21))
+        );
+        assert_eq!(
+            parse_relative("last saturday", &reference),
+            Ok(ymd(2024, 5, 11))
+        );
+    }
+
+    #[test]
+    fn parses_periods() {
+        let reference = ymd(2024, 5, 17);
+
+        assert_eq!(
+            parse_relative("next week", &reference),
+            Ok(ymd(2024, 5, 19))
+        );
+        assert_eq!(
+            parse_relative("last month", &reference),
+            Ok(ymd(2024, 4, 1))
+        );
+        assert_eq!(
+            parse_relative("this quarter", &reference),
+            Ok(ymd(2024, 4, 1))
+        );
+        assert_eq!(
+            parse_relative("end of next quarter", &reference),
+            Ok(ymd(2024, 9, 30))
+        );
+        assert_eq!(
+            parse_relative("beginning of last year", &reference),
+            Ok(ymd(2023, 1, 1))
+        );
+        assert_eq!(
+            parse_relative("end of month", &reference),
+            Ok(ymd(2024, 5, 31))
+        );
+    }
+
+    #[test]
+    fn parses_offsets() {
+        let reference = ymd(2024, 5, 31);
+
+        assert_eq!(
+            parse_relative("3 months ago", &reference),
+            Ok(ymd(2024, 2, 29))
+        );
+        assert_eq!(
+            parse_relative("in 2 weeks", &reference),
+            Ok(ymd(2024, 6, 14))
+        );
+        assert_eq!(
+            parse_relative("a day ago", &reference),
+            Ok(ymd(2024, 5, 30))
+        );
+        assert_eq!(
+            parse_relative("two quarters from now", &reference),
+            Ok(ymd(2024, 11, 30))
+        );
+        assert_eq!(
+            parse_relative("1 year ago", &reference),
+            Ok(ymd(2023, 5, 31))
+        );
+    }
+
+    #[test]
+    fn parses_spanish() {
+        let reference = ymd(2024, 5, 17);
+        let parse = |input| parse_relative_in(input, &reference, Language::Spanish);
+
+        assert_eq!(parse("mañana"), Ok(ymd(2024, 5, 18)));
+        assert_eq!(parse("próximo martes"), Ok(ymd(2024, 5, 21)));
+        assert_eq!(parse("el martes que viene"), Ok(ymd(2024, 5, 21)));
+        assert_eq!(parse("la semana pasada"), Ok(ymd(2024, 5, 5)));
+        assert_eq!(parse("este año"), Ok(ymd(2024, 1, 1)));
+        assert_eq!(parse("hace 3 meses"), Ok(ymd(2024, 2, 17)));
+        assert_eq!(parse("dentro de dos dias"), Ok(ymd(2024, 5, 19)));
+    }
+
+    #[test]
+    fn parses_german() {
+        let reference = ymd(2024, 5, 17);
+        let parse = |input| parse_relative_in(input, &reference, Language::German);
+
+        assert_eq!(parse("gestern"), Ok(ymd(2024, 5, 16)));
+        assert_eq!(parse("nächsten Dienstag"), Ok(ymd(2024, 5, 21)));
+        assert_eq!(parse("am letzten Samstag"), Ok(ymd(2024, 5, 11)));
+        assert_eq!(parse("nächstes Quartal"), Ok(ymd(2024, 7, 1)));
+        assert_eq!(parse("vor 3 Monaten"), Ok(ymd(2024, 2, 17)));
+        assert_eq!(parse("in einer Woche"), Ok(ymd(2024, 5, 24)));
+    }
+
+    #[test]
+    fn parses_french() {
+        let reference = ymd(2024, 5, 17);
+        let parse = |input| parse_relative_in(input, &reference, Language::French);
+
+        assert_eq!(parse("aujourd’hui"), Ok(reference));
+        assert_eq!(parse("mardi prochain"), Ok(ymd(2024, 5, 21)));
+        assert_eq!(parse("le mois dernier"), Ok(ymd(2024, 4, 1)));
+        assert_eq!(parse("l'année prochaine"), Ok(ymd(2025, 1, 1)));
+        assert_eq!(parse("cette semaine"), Ok(ymd(2024, 5, 12)));
+        assert_eq!(parse("il y a 2 mois"), Ok(ymd(2024, 3, 17)));
+        assert_eq!(parse("dans trois jours"), Ok(ymd(2024, 5, 20)));
+    }
+
+    #[test]
+    fn selects_languages_by_locale() {
+        assert_eq!(Language::from_locale("es-MX"), Some(Language::Spanish));
+        assert_eq!(Language::from_locale("de_AT"), Some(Language::German));
+        assert_eq!(Language::from_locale("FR"), Some(Language::French));
+        assert_eq!(Language::from_locale("en-GB"), Some(Language::English));
+        assert_eq!(Language::from_locale("ja-JP"), None);
+    }
+
+    #[test]
+    fn rejects_unknown_expressions() {
+        let reference = ymd(2024, 5, 17);
+
+        assert!(parse_relative("next fortnight", &reference).is_err());
+        assert!(parse_relative("end of tuesday", &reference).is_err());
+        assert!(parse_relative("", &reference).is_err());
+    }
+}
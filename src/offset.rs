@@ -0,0 +1,113 @@
+// Boundaries as a number of days from the date, computed from the cumulative month lengths in
+// `civil` so each boundary takes a single shift instead of chrono's validating setters. Both the
+// root boundary functions and their batch variants are built on these.
+
+use crate::civil::DAYS_BEFORE_MONTH;
+use chrono::prelude::*;
+use chrono::Days;
+
+pub(crate) fn beginning_of_week(date: &NaiveDate) -> i32 {
+    -(date.weekday().num_days_from_sunday() as i32)
+}
+
+pub(crate) fn end_of_week(date: &NaiveDate) -> i32 {
+    beginning_of_week(date) + 6
+}
+
+pub(crate) fn next_week(date: &NaiveDate) -> i32 {
+    beginning_of_week(date) + 7
+}
+
+pub(crate) fn previous_week(date: &NaiveDate) -> i32 {
+    beginning_of_week(date) - 7
+}
+
+pub(crate) fn beginning_of_month(date: &NaiveDate) -> i32 {
+    -(date.day0() as i32)
+}
+
+pub(crate) fn end_of_month(date: &NaiveDate) -> i32 {
+    next_month(date) - 1
+}
+
+pub(crate) fn next_month(date: &NaiveDate) -> i32 {
+    start_of_month(date, date.month0() as usize + 1)
+}
+
+pub(crate) fn previous_month(date: &NaiveDate) -> i32 {
+    let month0 = date.month0() as usize;
+    let previous = (month0 + 11) % 12;
+
+    beginning_of_month(date) - month_length(previous) - leap_day(date) * (month0 == 2) as i32
+}
+
+pub(crate) fn beginning_of_quarter(date: &NaiveDate) -> i32 {
+    start_of_month(date, quarter0(date) * 3)
+}
+
+pub(crate) fn end_of_quarter(date: &NaiveDate) -> i32 {
+    next_quarter(date) - 1
+}
+
+pub(crate) fn next_quarter(date: &NaiveDate) -> i32 {
+    start_of_month(date, quarter0(date) * 3 + 3)
+}
+
+pub(crate) fn previous_quarter(date: &NaiveDate) -> i32 {
+    let quarter0 = quarter0(date);
+    let previous = (quarter0 + 3) % 4;
+    let length = DAYS_BEFORE_MONTH[previous * 3 + 3] - DAYS_BEFORE_MONTH[previous * 3];
+
+    beginning_of_quarter(date) - length as i32 - leap_day(date) * (quarter0 == 1) as i32
+}
+
+pub(crate) fn beginning_of_year(date: &NaiveDate) -> i32 {
+    -(date.ordinal0() as i32)
+}
+
+pub(crate) fn end_of_year(date: &NaiveDate) -> i32 {
+    next_year(date) - 1
+}
+
+pub(crate) fn next_year(date: &NaiveDate) -> i32 {
+    beginning_of_year(date) + 365 + leap_day(date)
+}
+
+pub(crate) fn previous_year(date: &NaiveDate) -> i32 {
+    beginning_of_year(date) - 365 - crate::civil::is_leap_year(date.year() - 1) as i32
+}
+
+// The first day of a month of the date's year, counting from 0 and allowing 12 for January of
+// the next year
+fn start_of_month(date: &NaiveDate, month0: usize) -> i32 {
+    beginning_of_year(date)
+        + DAYS_BEFORE_MONTH[month0] as i32
+        + leap_day(date) * (month0 >= 2) as i32
+}
+
+// The length of a month in a common year
+fn month_length(month0: usize) -> i32 {
+    (DAYS_BEFORE_MONTH[month0 + 1] - DAYS_BEFORE_MONTH[month0]) as i32
+}
+
+fn quarter0(date: &NaiveDate) -> usize {
+    date.month0() as usize / 3
+}
+
+fn leap_day(date: &NaiveDate) -> i32 {
+    date.leap_year() as i32
+}
+
+// Shifting by days stays within the date's year when it can, skipping chrono's conversion
+// through 400-year cycles
+pub(crate) fn shift(date: &NaiveDate, days: i32) -> Option<NaiveDate> {
+    if days < 0 {
+        date.checked_sub_days(Days::new(u64::from(days.unsigned_abs())))
+    } else {
+        date.checked_add_days(Days::new(days as u64))
+    }
+}
+
+pub(crate) fn shift_within_year(date: &NaiveDate, days: i32) -> NaiveDate {
+    shift(date, days).expect("every day of a representable year is representable")
+}
@@ -0,0 +1,131 @@
+//! Ordinal (day-of-year) dates, numbering each day of a year from 1 on January 1 through 365 or
+//! 366 on December 31.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::ordinal;
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+//!
+//! assert_eq!(ordinal::day_of_year(&date), 61);
+//! assert_eq!(ordinal::from_year_and_ordinal(2024, 61), Some(date));
+//! assert_eq!(ordinal::ordinal_days(2023).unwrap().count(), 365);
+//! ```
+
+use crate::{DateRange, DateRangeIter};
+use chrono::prelude::*;
+
+/// Returns the day of the year of the provided date, starting at 1 on January 1.
+pub fn day_of_year(date: &NaiveDate) -> u32 {
+    date.ordinal()
+}
+
+/// Returns the date falling on the day of the year, or `None` if the year has no such day.
+pub fn from_year_and_ordinal(year: i32, ordinal: u32) -> Option<NaiveDate> {
+    NaiveDate::from_yo_opt(year, ordinal)
+}
+
+/// Returns the first day of the year containing an ordinal counted from January 1 of `year`,
+/// which may run past the end of the year or before its start.
+///
+/// Datasets spanning year boundaries often keep counting days (day 366 of a common year, or day
+/// 0), and this finds the year in which such a day actually falls.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::ordinal;
+///
+/// assert_eq!(ordinal::beginning_of_year_for_ordinal(2023, 366), NaiveDate::from_ymd_opt(2024, 1, 1));
+/// assert_eq!(ordinal::beginning_of_year_for_ordinal(2024, 366), NaiveDate::from_ymd_opt(2024, 1, 1));
+/// assert_eq!(ordinal::beginning_of_year_for_ordinal(2024, 0), NaiveDate::from_ymd_opt(2023, 1, 1));
+/// ```
+pub fn beginning_of_year_for_ordinal(year: i32, ordinal: i64) -> Option<NaiveDate> {
+    let date = NaiveDate::from_yo_opt(year, 1)?
+        .checked_add_signed(chrono::Duration::try_days(ordinal.checked_sub(1)?)?)?;
+
+    NaiveDate::from_yo_opt(date.year(), 1)
+}
+
+/// Returns an iterator over the days of the year paired with their ordinals.
+pub fn ordinal_days(year: i32) -> Option<OrdinalDays> {
+    let range = DateRange::new(
+        NaiveDate::from_ymd_opt(year, 1, 1)?,
+        NaiveDate::from_ymd_opt(year, 12, 31)?,
+    )?;
+
+    Some(OrdinalDays {
+        dates: range.iter(),
+    })
+}
+
+/// An iterator over the days of a year and their ordinals, returned by [`ordinal_days`].
+#[derive(Clone, Debug)]
+pub struct OrdinalDays {
+    dates: DateRangeIter,
+}
+
+impl Iterator for OrdinalDays {
+    type Item = (u32, NaiveDate);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.dates.next().map(|date| (date.ordinal(), date))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn converts_ordinals() {
+        assert_eq!(day_of_year(&ymd(2023, 12, 31)), 365);
+        assert_eq!(day_of_year(&ymd(2024, 12, 31)), 366);
+        assert_eq!(from_year_and_ordinal(2023, 366), None);
+        assert_eq!(from_year_and_ordinal(2024, 0), None);
+        assert_eq!(from_year_and_ordinal(2024, 366), Some(ymd(2024, 12, 31)));
+    }
+
+    #[test]
+    fn finds_years_for_overflowing_ordinals() {
+        assert_eq!(
+            beginning_of_year_for_ordinal(2023, 1),
+            Some(ymd(2023, 1, 1))
+        );
+        assert_eq!(
+            beginning_of_year_for_ordinal(2023, 732),
+            Some(ymd(2025, 1, 1))
+        );
+        assert_eq!(
+            beginning_of_year_for_ordinal(2023, 731),
+            Some(ymd(2024, 1, 1))
+        );
+        assert_eq!(
+            beginning_of_year_for_ordinal(2024, -365),
+            Some(ymd(2022, 1, 1))
+        );
+        assert_eq!(beginning_of_year_for_ordinal(2024, i64::MAX), None);
+        assert_eq!(beginning_of_year_for_ordinal(2024, i64::MIN + 1), None);
+        assert_eq!(beginning_of_year_for_ordinal(2024, i64::MIN), None);
+    }
+
+    #[test]
+    fn iterates_ordinal_days() {
+        let days: Vec<(u32, NaiveDate)> = ordinal_days(2024).unwrap().collect();
+
+        assert_eq!(days.len(), 366);
+        assert_eq!(days[0], (1, ymd(2024, 1, 1)));
+        assert_eq!(days[59], (60, ymd(2024, 2, 29)));
+        assert_eq!(days[365], (366, ymd(2024, 12, 31)));
+    }
+
+    #[quickcheck]
+    fn ordinals_round_trip(date: ArbitraryDate) -> bool {
+        from_year_and_ordinal(date.0.year(), day_of_year(&date.0)) == Some(date.0)
+    }
+}
@@ -0,0 +1,329 @@
+//! A compact date packed into a `u32`, enabled with the `packed` feature.
+//!
+//! [`PackedDate`] stores the year, month, and day in separate bit fields, so its boundaries and
+//! shifts are integer operations on the fields (through [`civil`](crate::civil)) without
+//! building a `NaiveDate`. The packed value orders the same way as the dates, which suits sorted
+//! columns and embedded tables.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::packed::PackedDate;
+//!
+//! let date = PackedDate::new(2024, 5, 17).unwrap();
+//!
+//! assert_eq!(date.end_of_quarter(), PackedDate::new(2024, 6, 30).unwrap());
+//! assert_eq!(date.next_year(), PackedDate::new(2025, 1, 1));
+//! assert_eq!(PackedDate::from_bits(date.to_bits()), Some(date));
+//! assert_eq!(date.to_naive_date(), NaiveDate::from_ymd_opt(2024, 5, 17));
+//! ```
+
+use crate::civil::{self, Ymd};
+use crate::year_month::DisplayYear;
+use chrono::prelude::*;
+use core::convert::TryFrom;
+use core::fmt;
+
+const DAY_BITS: u32 = 5;
+const MONTH_BITS: u32 = 4;
+
+// Years are stored offset by this bias so the packed values of negative years sort first
+const YEAR_BIAS: i32 = 1 << 22;
+
+/// The earliest year a [`PackedDate`] can hold.
+pub const MIN_YEAR: i32 = -YEAR_BIAS;
+
+/// The latest year a [`PackedDate`] can hold.
+pub const MAX_YEAR: i32 = YEAR_BIAS - 1;
+
+/// A proleptic Gregorian date packed into a `u32` as 23 bits of year, 4 of month, and 5 of day.
+///
+/// Its years span [`MIN_YEAR`] through [`MAX_YEAR`], wider than chrono's, so converting to a
+/// `NaiveDate` can fail while converting from one cannot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackedDate(u32);
+
+impl PackedDate {
+    /// Packs a date, returning `None` if the triple names no date or the year is out of range.
+    pub const fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if year < MIN_YEAR || year > MAX_YEAR || !civil::is_valid((year, month, day)) {
+            return None;
+        }
+
+        let year = (year + YEAR_BIAS) as u32;
+        Some(PackedDate(
+            year << (MONTH_BITS + DAY_BITS) | month << DAY_BITS | day,
+        ))
+    }
+
+    /// Unpacks a value from [`to_bits`](Self::to_bits), returning `None` if its fields name no
+    /// date.
+    pub const fn from_bits(bits: u32) -> Option<Self> {
+        let date = PackedDate(bits);
+        let (year, month, day) = date.to_ymd();
+
+        PackedDate::new(year, month, day)
+    }
+
+    /// Returns the packed value.
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the year.
+    pub const fn year(self) -> i32 {
+        (self.0 >> (MONTH_BITS + DAY_BITS)) as i32 - YEAR_BIAS
+    }
+
+    /// Returns the month, between 1 and 12.
+    pub const fn month(self) -> u32 {
+        (self.0 >> DAY_BITS) & ((1 << MONTH_BITS) - 1)
+    }
+
+    /// Returns the day of the month.
+    pub const fn day(self) -> u32 {
+        self.0 & ((1 << DAY_BITS) - 1)
+    }
+
+    /// Returns the date as a `(year, month, day)` triple.
+    pub const fn to_ymd(self) -> Ymd {
+        (self.year(), self.month(), self.day())
+    }
+
+    /// Returns the day of the week.
+    pub fn weekday(self) -> Weekday {
+        let rata_die = civil::to_rata_die(self.to_ymd()).expect("packed dates are valid");
+
+        match civil::days_from_sunday(rata_die) {
+            0 => Weekday::Sun,
+            1 => Weekday::Mon,
+            2 => Weekday::Tue,
+            3 => Weekday::Wed,
+            4 => Weekday::Thu,
+            5 => Weekday::Fri,
+            _ => Weekday::Sat,
+        }
+    }
+
+    /// Returns the `NaiveDate` falling on the same day, or `None` if chrono cannot represent it.
+    pub fn to_naive_date(self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year(), self.month(), self.day())
+    }
+
+    /// Shifts the date by `days`, forward when positive and backward when negative.
+    pub fn checked_add_days(self, days: i64) -> Option<Self> {
+        let rata_die = civil::to_rata_die(self.to_ymd())?.checked_add(days)?;
+
+        Self::from_ymd(civil::from_rata_die(rata_die))
+    }
+
+    /// Shifts the date by `months`, clamping the day to the end of shorter months.
+    pub fn checked_add_months(self, months: i32) -> Option<Self> {
+        let index = i64::from(self.year()) * 12 + i64::from(self.month()) - 1 + i64::from(months);
+        let year = i32::try_from(index.div_euclid(12)).ok()?;
+        let month = index.rem_euclid(12) as u32 + 1;
+        let day = self.day().min(civil::days_in_month(year, month)?);
+
+        PackedDate::new(year, month, day)
+    }
+
+    // weeks
+
+    /// Returns the beginning of the week (Sunday) containing the date.
+    pub fn beginning_of_week(self) -> Option<Self> {
+        Self::from_ymd(civil::beginning_of_week(self.to_ymd()))
+    }
+
+    /// Returns the end of the week (Saturday) containing the date.
+    pub fn end_of_week(self) -> Option<Self> {
+        Self::from_ymd(civil::end_of_week(self.to_ymd()))
+    }
+
+    /// Returns the beginning of the next week.
+    pub fn next_week(self) -> Option<Self> {
+        Self::from_ymd(civil::next_week(self.to_ymd()))
+    }
+
+    /// Returns the beginning of the previous week.
+    pub fn previous_week(self) -> Option<Self> {
+        Self::from_ymd(civil::previous_week(self.to_ymd()))
+    }
+
+    // months
+
+    /// Returns the first day of the date's month.
+    pub fn beginning_of_month(self) -> Self {
+        self.within_year(civil::beginning_of_month(self.to_ymd()))
+    }
+
+    /// Returns the last day of the date's month.
+    pub fn end_of_month(self) -> Self {
+        self.within_year(civil::end_of_month(self.to_ymd()))
+    }
+
+    /// Returns the first day of the next month.
+    pub fn next_month(self) -> Option<Self> {
+        Self::from_ymd(civil::next_month(self.to_ymd()))
+    }
+
+    /// Returns the first day of the previous month.
+    pub fn previous_month(self) -> Option<Self> {
+        Self::from_ymd(civil::previous_month(self.to_ymd()))
+    }
+
+    // quarters
+
+    /// Returns the first day of the date's quarter.
+    pub fn beginning_of_quarter(self) -> Self {
+        self.within_year(civil::beginning_of_quarter(self.to_ymd()))
+    }
+
+    /// Returns the last day of the date's quarter.
+    pub fn end_of_quarter(self) -> Self {
+        self.within_year(civil::end_of_quarter(self.to_ymd()))
+    }
+
+    /// Returns the first day of the next quarter.
+    pub fn next_quarter(self) -> Option<Self> {
+        Self::from_ymd(civil::next_quarter(self.to_ymd()))
+    }
+
+    /// Returns the first day of the previous quarter.
+    pub fn previous_quarter(self) -> Option<Self> {
+        Self::from_ymd(civil::previous_quarter(self.to_ymd()))
+    }
+
+    // years
+
+    /// Returns January 1 of the date's year.
+    pub fn beginning_of_year(self) -> Self {
+        self.within_year(civil::beginning_of_year(self.to_ymd()))
+    }
+
+    /// Returns December 31 of the date's year.
+    pub fn end_of_year(self) -> Self {
+        self.within_year(civil::end_of_year(self.to_ymd()))
+    }
+
+    /// Returns January 1 of the next year.
+    pub fn next_year(self) -> Option<Self> {
+        Self::from_ymd(civil::next_year(self.to_ymd()))
+    }
+
+    /// Returns January 1 of the previous year.
+    pub fn previous_year(self) -> Option<Self> {
+        Self::from_ymd(civil::previous_year(self.to_ymd()))
+    }
+
+    fn from_ymd(date: Option<Ymd>) -> Option<Self> {
+        let (year, month, day) = date?;
+        PackedDate::new(year, month, day)
+    }
+
+    fn within_year(self, date: Option<Ymd>) -> Self {
+        Self::from_ymd(date).expect("every day of a packed year can be packed")
+    }
+}
+
+impl From<NaiveDate> for PackedDate {
+    fn from(date: NaiveDate) -> Self {
+        PackedDate::new(date.year(), date.month(), date.day()).expect("chrono's years fit")
+    }
+}
+
+/// Formats the date as `YYYY-MM-DD`.
+impl fmt::Display for PackedDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}-{:02}-{:02}",
+            DisplayYear(self.year()),
+            self.month(),
+            self.day()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    type Boundary = fn(&NaiveDate) -> Option<NaiveDate>;
+    type Packed = fn(PackedDate) -> Option<PackedDate>;
+
+    const BOUNDARIES: [(Boundary, Packed); 16] = [
+        (crate::beginning_of_week, PackedDate::beginning_of_week),
+        (crate::end_of_week, PackedDate::end_of_week),
+        (crate::next_week, PackedDate::next_week),
+        (crate::previous_week, PackedDate::previous_week),
+        (
+            |d| Some(crate::beginning_of_month(d)),
+            |p| Some(p.beginning_of_month()),
+        ),
+        (|d| Some(crate::end_of_month(d)), |p| Some(p.end_of_month())),
+        (crate::next_month, PackedDate::next_month),
+        (crate::previous_month, PackedDate::previous_month),
+        (
+            |d| Some(crate::beginning_of_quarter(d)),
+            |p| Some(p.beginning_of_quarter()),
+        ),
+        (
+            |d| Some(crate::end_of_quarter(d)),
+            |p| Some(p.end_of_quarter()),
+        ),
+        (crate::next_quarter, PackedDate::next_quarter),
+        (crate::previous_quarter, PackedDate::previous_quarter),
+        (
+            |d| Some(crate::beginning_of_year(d)),
+            |p| Some(p.beginning_of_year()),
+        ),
+        (|d| Some(crate::end_of_year(d)), |p| Some(p.end_of_year())),
+        (crate::next_year, PackedDate::next_year),
+        (crate::previous_year, PackedDate::previous_year),
+    ];
+
+    #[quickcheck]
+    fn matches_the_boundary_functions(date: ArbitraryDate) -> bool {
+        let packed = PackedDate::from(date.0);
+
+        BOUNDARIES.iter().all(|(boundary, packed_boundary)| {
+            boundary(&date.0).map(PackedDate::from) == packed_boundary(packed)
+        })
+    }
+
+    #[quickcheck]
+    fn round_trips_and_keeps_order(a: ArbitraryDate, b: ArbitraryDate) -> bool {
+        let (packed_a, packed_b) = (PackedDate::from(a.0), PackedDate::from(b.0));
+
+        packed_a.to_naive_date() == Some(a.0)
+            && PackedDate::from_bits(packed_a.to_bits()) == Some(packed_a)
+            && packed_a.weekday() == a.0.weekday()
+            && packed_a.cmp(&packed_b) == a.0.cmp(&b.0)
+    }
+
+    #[test]
+    fn shifts_by_days_and_months() {
+        let date = PackedDate::new(2024, 1, 31).unwrap();
+
+        assert_eq!(date.checked_add_days(30), PackedDate::new(2024, 3, 1));
+        assert_eq!(date.checked_add_days(-31), PackedDate::new(2023, 12, 31));
+        assert_eq!(date.checked_add_months(1), PackedDate::new(2024, 2, 29));
+        assert_eq!(date.checked_add_months(-13), PackedDate::new(2022, 12, 31));
+    }
+
+    #[test]
+    fn holds_years_beyond_chrono() {
+        let min = PackedDate::new(MIN_YEAR, 1, 1).unwrap();
+        let max = PackedDate::new(MAX_YEAR, 12, 31).unwrap();
+
+        assert!(min < PackedDate::from(NaiveDate::MIN));
+        assert_eq!(max.to_naive_date(), None);
+        assert_eq!(max.next_year(), None);
+        assert_eq!(min.previous_week(), None);
+        assert_eq!(PackedDate::new(MAX_YEAR + 1, 1, 1), None);
+        assert_eq!(PackedDate::from_bits(0), None);
+        assert_eq!(PackedDate::new(2024, u32::MAX, 1), None);
+        assert_eq!(min.to_string(), "-4194304-01-01");
+    }
+}
@@ -0,0 +1,174 @@
+//! Finding the dates that match a pattern of month, day of month, and weekday, such as every
+//! Friday the 13th.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::pattern::DatePattern;
+//! use date_calculations::DateRange;
+//!
+//! let friday_13th = DatePattern {
+//!     day: Some(13),
+//!     weekday: Some(Weekday::Fri),
+//!     ..DatePattern::default()
+//! };
+//! let range = DateRange::new(
+//!     NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+//!     NaiveDate::from_ymd_opt(2026, 12, 31).unwrap(),
+//! )
+//! .unwrap();
+//!
+//! let dates: Vec<_> = friday_13th.dates_in(&range).map(|d| d.to_string()).collect();
+//! assert_eq!(dates, vec!["2026-02-13", "2026-03-13", "2026-11-13"]);
+//! ```
+
+use crate::{next_month, DateRange};
+use chrono::prelude::*;
+use chrono::Days;
+use core::iter::FusedIterator;
+
+/// A pattern over the fields of a date, where each field left as `None` matches any value.
+///
+/// The default pattern matches every date.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DatePattern {
+    /// The month, 1 through 12.
+    pub month: Option<u32>,
+    /// The day of the month, 1 through 31.
+    pub day: Option<u32>,
+    /// The day of the week.
+    pub weekday: Option<Weekday>,
+}
+
+impl DatePattern {
+    /// Returns whether the date matches every field of the pattern.
+    pub fn matches(&self, date: &NaiveDate) -> bool {
+        !matches!(self.month, Some(month) if date.month() != month)
+            && !matches!(self.day, Some(day) if date.day() != day)
+            && !matches!(self.weekday, Some(weekday) if date.weekday() != weekday)
+    }
+
+    /// Returns the matching dates within the range, in order.
+    ///
+    /// The iterator skips ahead by whole months or years when the pattern fixes the day or
+    /// month, rather than checking every date.
+    pub fn dates_in(&self, range: &DateRange) -> PatternMatches {
+        PatternMatches {
+            pattern: *self,
+            next: Some(range.start()),
+            end: range.end(),
+        }
+    }
+
+    // The first date on or after `date`, and no later than `end`, matching the pattern
+    fn first_match(&self, mut date: NaiveDate, end: NaiveDate) -> Option<NaiveDate> {
+        while date <= end {
+            date = match (self.month, self.day, self.weekday) {
+                (Some(month), _, _) if date.month() != month => {
+                    let year = if date.month() < month {
+                        date.year()
+                    } else {
+                        date.year() + 1
+                    };
+                    NaiveDate::from_ymd_opt(year, month, 1)?
+                }
+                (_, Some(day), _) if date.day() < day => {
+                    date.with_day(day).map_or_else(|| next_month(&date), Some)?
+                }
+                (_, Some(day), _) if date.day() > day => next_month(&date)?,
+                (_, Some(_), Some(weekday)) if date.weekday() != weekday => next_month(&date)?,
+                (_, None, Some(weekday)) if date.weekday() != weekday => {
+                    let days = weekday.days_since(date.weekday());
+                    date.checked_add_days(Days::new(days.into()))?
+                }
+                _ => return Some(date),
+            };
+        }
+
+        None
+    }
+}
+
+/// An iterator over the dates matching a [`DatePattern`], from [`DatePattern::dates_in`].
+#[derive(Clone, Debug)]
+pub struct PatternMatches {
+    pattern: DatePattern,
+    next: Option<NaiveDate>,
+    end: NaiveDate,
+}
+
+impl Iterator for PatternMatches {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<NaiveDate> {
+        let found = self.pattern.first_match(self.next?, self.end);
+        self.next = found.and_then(|date| date.succ_opt());
+
+        found
+    }
+}
+
+impl FusedIterator for PatternMatches {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use core::convert::TryFrom;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[quickcheck]
+    fn matches_filtering_every_date(start: ArbitraryDate, span: u16, pick: u8) -> bool {
+        let start = start.0;
+        let range = DateRange::new(start, start + chrono::Duration::days(i64::from(span))).unwrap();
+        let weekday = Weekday::try_from(pick % 7).unwrap();
+        let patterns = [
+            DatePattern {
+                day: Some(1 + u32::from(pick) % 31),
+                weekday: Some(weekday),
+                ..DatePattern::default()
+            },
+            DatePattern {
+                month: Some(1 + u32::from(pick) % 12),
+                weekday: Some(weekday),
+                ..DatePattern::default()
+            },
+            DatePattern {
+                month: Some(2),
+                day: Some(29),
+                weekday: None,
+            },
+            DatePattern::default(),
+        ];
+
+        patterns.iter().all(|pattern| {
+            pattern
+                .dates_in(&range)
+                .eq(range.iter().filter(|d| pattern.matches(d)))
+        })
+    }
+
+    #[test]
+    fn finds_nothing_for_impossible_patterns() {
+        let range = DateRange::new(ymd(2000, 1, 1), ymd(2400, 12, 31)).unwrap();
+        let february_30 = DatePattern {
+            month: Some(2),
+            day: Some(30),
+            weekday: None,
+        };
+
+        assert_eq!(february_30.dates_in(&range).next(), None);
+        assert_eq!(
+            DatePattern {
+                day: Some(31),
+                ..DatePattern::default()
+            }
+            .dates_in(&DateRange::new(NaiveDate::MAX, NaiveDate::MAX).unwrap())
+            .collect::<Vec<_>>(),
+            vec![NaiveDate::MAX]
+        );
+    }
+}
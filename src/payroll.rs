@@ -0,0 +1,158 @@
+//! Payroll workweeks, the fixed, recurring periods of 168 hours over which overtime is counted.
+//!
+//! Under the US Fair Labor Standards Act an employer may begin its workweek on any day and at
+//! any hour, so a workweek can run from Wednesday at 06:00 to the following Wednesday at 06:00
+//! and split calendar days between two workweeks. A [`PayrollWorkweek`] describes when the
+//! employer's workweek begins.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::payroll::PayrollWorkweek;
+//!
+//! let workweek = PayrollWorkweek::new(Weekday::Wed, NaiveTime::from_hms_opt(6, 0, 0).unwrap());
+//! let shift = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap().and_hms_opt(5, 30, 0).unwrap();
+//! let week = workweek.workweek_containing(&shift).unwrap();
+//!
+//! assert_eq!(week.start().to_string(), "2024-05-08 06:00:00");
+//! assert_eq!(week.end().to_string(), "2024-05-15 06:00:00");
+//! ```
+
+use chrono::prelude::*;
+use chrono::Duration;
+
+/// When an employer's workweek begins: a weekday and a time of day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PayrollWorkweek {
+    weekday: Weekday,
+    time: NaiveTime,
+}
+
+impl PayrollWorkweek {
+    /// Describes workweeks beginning each week on `weekday` at `time`.
+    pub fn new(weekday: Weekday, time: NaiveTime) -> Self {
+        PayrollWorkweek { weekday, time }
+    }
+
+    /// Returns the weekday the workweek begins on.
+    pub fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+
+    /// Returns the time of day the workweek begins at.
+    pub fn time(&self) -> NaiveTime {
+        self.time
+    }
+
+    /// Returns the workweek containing the moment.
+    ///
+    /// Returns `None` if the workweek extends beyond the dates chrono can represent.
+    pub fn workweek_containing(&self, moment: &NaiveDateTime) -> Option<Workweek> {
+        let days = moment.weekday().days_since(self.weekday);
+        let mut start = moment
+            .date()
+            .checked_sub_days(chrono::Days::new(days.into()))?
+            .and_time(self.time);
+
+        if start > *moment {
+            start = start.checked_sub_signed(Duration::weeks(1))?;
+        }
+
+        Some(Workweek {
+            start,
+            end: start.checked_add_signed(Duration::weeks(1))?,
+        })
+    }
+}
+
+/// A single workweek, from the moment it begins up to the moment the next begins.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Workweek {
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+}
+
+impl Workweek {
+    /// Returns the moment the workweek begins.
+    pub fn start(&self) -> NaiveDateTime {
+        self.start
+    }
+
+    /// Returns the moment the following workweek begins, which is not part of this one.
+    pub fn end(&self) -> NaiveDateTime {
+        self.end
+    }
+
+    /// Returns whether the moment falls within the workweek.
+    pub fn contains(&self, moment: &NaiveDateTime) -> bool {
+        self.start <= *moment && *moment < self.end
+    }
+
+    /// Returns the following workweek.
+    pub fn next(&self) -> Option<Workweek> {
+        Some(Workweek {
+            start: self.end,
+            end: self.end.checked_add_signed(Duration::weeks(1))?,
+        })
+    }
+
+    /// Returns the preceding workweek.
+    pub fn previous(&self) -> Option<Workweek> {
+        Some(Workweek {
+            start: self.start.checked_sub_signed(Duration::weeks(1))?,
+            end: self.start,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use core::convert::TryFrom;
+    use quickcheck_macros::quickcheck;
+
+    fn at(year: i32, month: u32, day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn splits_days_at_the_start_time() {
+        let workweek =
+            PayrollWorkweek::new(Weekday::Wed, NaiveTime::from_hms_opt(6, 0, 0).unwrap());
+        let before = workweek.workweek_containing(&at(2024, 5, 15, 5)).unwrap();
+        let after = workweek.workweek_containing(&at(2024, 5, 15, 6)).unwrap();
+
+        assert_eq!(before.next(), Some(after));
+        assert_eq!(after.start(), at(2024, 5, 15, 6));
+        assert_eq!(after.previous(), Some(before));
+        assert!(!before.contains(&after.start()));
+    }
+
+    #[test]
+    fn begins_at_midnight_on_the_weekday() {
+        let workweek = PayrollWorkweek::new(Weekday::Sun, NaiveTime::MIN);
+        let week = workweek.workweek_containing(&at(2024, 5, 18, 23)).unwrap();
+
+        assert_eq!(week.start(), at(2024, 5, 12, 0));
+        assert_eq!(week.end(), at(2024, 5, 19, 0));
+        assert_eq!(
+            workweek.workweek_containing(&NaiveDate::MAX.and_hms_opt(0, 0, 0).unwrap()),
+            None
+        );
+    }
+
+    #[quickcheck]
+    fn workweeks_contain_the_moment(date: ArbitraryDate, hour: u8, weekday: u8) -> bool {
+        let moment = date.0.and_hms_opt(u32::from(hour % 24), 0, 0).unwrap();
+        let weekday = Weekday::try_from(weekday % 7).unwrap();
+        let workweek = PayrollWorkweek::new(weekday, NaiveTime::from_hms_opt(9, 30, 0).unwrap());
+        let week = workweek.workweek_containing(&moment).unwrap();
+
+        week.contains(&moment)
+            && week.start().weekday() == weekday
+            && week.start().time() == workweek.time()
+    }
+}
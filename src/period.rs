@@ -0,0 +1,476 @@
+use crate::*;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::str::FromStr;
+
+const EXPECTED: &str = "a period of week, month, quarter, or year";
+
+/// A calendar period supported by this crate's boundary functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Period {
+    /// A week, beginning on Sunday and ending on Saturday.
+    Week,
+    /// A calendar month.
+    Month,
+    /// A calendar quarter, beginning in January, April, July, or October.
+    Quarter,
+    /// A calendar year.
+    Year,
+}
+
+impl Period {
+    /// Returns the beginning of the period containing the provided date.
+    pub fn beginning(self, date: &NaiveDate) -> Option<NaiveDate> {
+        match self {
+            Period::Week => beginning_of_week(date),
+            Period::Month => Some(beginning_of_month(date)),
+            Period::Quarter => Some(beginning_of_quarter(date)),
+            Period::Year => Some(beginning_of_year(date)),
+        }
+    }
+
+    /// Returns the end of the period containing the provided date.
+    pub fn end(self, date: &NaiveDate) -> Option<NaiveDate> {
+        match self {
+            Period::Week => end_of_week(date),
+            Period::Month => Some(end_of_month(date)),
+            Period::Quarter => Some(end_of_quarter(date)),
+            Period::Year => Some(end_of_year(date)),
+        }
+    }
+
+    /// Returns the beginning of the period following the one containing the provided date.
+    pub fn next(self, date: &NaiveDate) -> Option<NaiveDate> {
+        match self {
+            Period::Week => next_week(date),
+            Period::Month => next_month(date),
+            Period::Quarter => next_quarter(date),
+            Period::Year => next_year(date),
+        }
+    }
+
+    /// Returns the beginning of the period preceding the one containing the provided date.
+    pub fn previous(self, date: &NaiveDate) -> Option<NaiveDate> {
+        match self {
+            Period::Week => previous_week(date),
+            Period::Month => previous_month(date),
+            Period::Quarter => previous_quarter(date),
+            Period::Year => previous_year(date),
+        }
+    }
+
+    /// Returns the beginning of the period containing the provided date, resolving a beginning
+    /// before `NaiveDate::MIN` with the policy.
+    pub fn beginning_with(
+        self,
+        date: &NaiveDate,
+        policy: RangePolicy,
+    ) -> Result<NaiveDate, OutOfRange> {
+        policy.resolve(self.beginning(date), NaiveDate::MIN)
+    }
+
+    /// Returns the end of the period containing the provided date, resolving an end after
+    /// `NaiveDate::MAX` with the policy.
+    pub fn end_with(self, date: &NaiveDate, policy: RangePolicy) -> Result<NaiveDate, OutOfRange> {
+        policy.resolve(self.end(date), NaiveDate::MAX)
+    }
+
+    /// Returns the beginning of the following period, resolving one after `NaiveDate::MAX` with
+    /// the policy.
+    pub fn next_with(self, date: &NaiveDate, policy: RangePolicy) -> Result<NaiveDate, OutOfRange> {
+        policy.resolve(self.next(date), NaiveDate::MAX)
+    }
+
+    /// Returns the beginning of the preceding period, resolving one before `NaiveDate::MIN` with
+    /// the policy.
+    pub fn previous_with(
+        self,
+        date: &NaiveDate,
+        policy: RangePolicy,
+    ) -> Result<NaiveDate, OutOfRange> {
+        policy.resolve(self.previous(date), NaiveDate::MIN)
+    }
+
+    /// Returns the number of periods between the periods containing each date.
+    ///
+    /// This counts boundaries crossed rather than elapsed time, so December 31 and January 1 of
+    /// the following year are one year apart. The result is negative when `to` precedes `from`.
+    pub fn count_between(self, from: &NaiveDate, to: &NaiveDate) -> Option<i64> {
+        let months = |date: &NaiveDate| i64::from(date.year()) * 12 + i64::from(date.month0());
+
+        match self {
+            Period::Week => {
+                let from = beginning_of_week(from)?;
+                let to = beginning_of_week(to)?;
+                Some(to.signed_duration_since(from).num_weeks())
+            }
+            Period::Month => Some(months(to) - months(from)),
+            Period::Quarter => Some(months(to).div_euclid(3) - months(from).div_euclid(3)),
+            Period::Year => Some(i64::from(to.year()) - i64::from(from.year())),
+        }
+    }
+
+    /// Returns the range of the `count` whole periods before the one containing the provided
+    /// date, ending the day before it begins.
+    ///
+    /// Returns `None` if `count` is zero or the range begins before `NaiveDate::MIN`.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use date_calculations::Period;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+    /// let range = Period::Month.previous_complete(&date, 3).unwrap();
+    ///
+    /// assert_eq!(range.to_string(), "2024-02-01/2024-04-30");
+    /// ```
+    pub fn previous_complete(self, date: &NaiveDate, count: u32) -> Option<DateRange> {
+        if count == 0 {
+            return None;
+        }
+
+        let beginning = self.beginning(date)?;
+        let months = match self {
+            Period::Week => {
+                let days = chrono::Days::new(u64::from(count) * 7);
+                return DateRange::new(beginning.checked_sub_days(days)?, beginning.pred_opt()?);
+            }
+            Period::Month => 1,
+            Period::Quarter => 3,
+            Period::Year => 12,
+        };
+        let start =
+            duration::shift_months(&beginning, -i64::from(count) * months, MonthOverflow::Clamp)?;
+
+        DateRange::new(start, beginning.pred_opt()?)
+    }
+
+    /// Returns the range from the beginning of the period containing the provided date through
+    /// the date, or through the day before it when `end` is [`EndBound::Exclusive`].
+    ///
+    /// Returns `None` if the exclusive range would be empty because the date begins its period.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use date_calculations::{EndBound, Period};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Period::Quarter.to_date(&date, EndBound::Inclusive).unwrap().to_string(),
+    ///     "2024-04-01/2024-05-17"
+    /// );
+    /// assert_eq!(
+    ///     Period::Quarter.to_date(&date, EndBound::Exclusive).unwrap().to_string(),
+    ///     "2024-04-01/2024-05-16"
+    /// );
+    /// ```
+    pub fn to_date(self, date: &NaiveDate, end: EndBound) -> Option<DateRange> {
+        let through = match end {
+            EndBound::Inclusive => *date,
+            EndBound::Exclusive => date.pred_opt()?,
+        };
+
+        DateRange::new(self.beginning(date)?, through)
+    }
+
+    /// Returns an iterator over the beginnings of consecutive periods, starting with the period
+    /// containing the provided date.
+    ///
+    /// Each step adds the length of the current period, tracked as a year and month, rather than
+    /// recomputing the next boundary from the previous date, and nothing is allocated.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use date_calculations::Period;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 11, 20).unwrap();
+    /// let starts: Vec<_> = Period::Month.starts(&date).take(3).map(|d| d.to_string()).collect();
+    ///
+    /// assert_eq!(starts, ["2024-11-01", "2024-12-01", "2025-01-01"]);
+    /// ```
+    pub fn starts(self, date: &NaiveDate) -> PeriodStarts {
+        let next = self.beginning(date);
+
+        PeriodStarts {
+            period: self,
+            next,
+            year: next.map_or(0, |d| d.year()),
+            month0: next.map_or(0, |d| d.month0()),
+        }
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Period::Week => "week",
+            Period::Month => "month",
+            Period::Quarter => "quarter",
+            Period::Year => "year",
+        }
+    }
+}
+
+/// Whether a range ending at a date includes that date.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EndBound {
+    /// The range ends on the date.
+    Inclusive,
+    /// The range ends the day before the date, as when the date is today and still incomplete.
+    Exclusive,
+}
+
+/// An iterator over the beginnings of consecutive periods, created by [`Period::starts`].
+///
+/// It ends only when the next beginning would fall after `NaiveDate::MAX`.
+#[derive(Clone, Debug)]
+pub struct PeriodStarts {
+    period: Period,
+    next: Option<NaiveDate>,
+    year: i32,
+    month0: u32,
+}
+
+impl Iterator for PeriodStarts {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        let (days, months) = match self.period {
+            Period::Week => (7, 0),
+            Period::Month => (days_in(civil::days_in_month(self.year, self.month0 + 1)), 1),
+            Period::Quarter => (
+                days_in(civil::days_in_quarter(self.year, self.month0 / 3 + 1)),
+                3,
+            ),
+            Period::Year => (civil::days_in_year(self.year), 12),
+        };
+
+        let month0 = self.month0 + months;
+        self.year += (month0 / 12) as i32;
+        self.month0 = month0 % 12;
+        self.next = current.checked_add_days(chrono::Days::new(days.into()));
+
+        Some(current)
+    }
+}
+
+fn days_in(length: Option<u32>) -> u32 {
+    length.expect("the tracked month is valid")
+}
+
+impl FusedIterator for PeriodStarts {}
+
+/// Formats the period as `week`, `month`, `quarter`, or `year`.
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses a period from its name, plural, or a short form (`w`, `wk`, `m`, `mo`, `q`, `qtr`, `y`,
+/// `yr`), ignoring case.
+impl FromStr for Period {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let name = input.trim();
+        let names = [
+            (Period::Week, ["week", "weeks", "w", "wk", "wks"]),
+            (Period::Month, ["month", "months", "m", "mo", "mos"]),
+            (Period::Quarter, ["quarter", "quarters", "q", "qtr", "qtrs"]),
+            (Period::Year, ["year", "years", "y", "yr", "yrs"]),
+        ];
+
+        names
+            .iter()
+            .find(|(_, names)| names.iter().any(|n| n.eq_ignore_ascii_case(name)))
+            .map(|(period, _)| *period)
+            .ok_or_else(|| ParseError::new(input, EXPECTED))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    #[test]
+    fn period_delegates_to_boundary_functions() {
+        let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+
+        assert_eq!(Period::Week.beginning(&date), beginning_of_week(&date));
+        assert_eq!(Period::Month.end(&date), Some(end_of_month(&date)));
+        assert_eq!(Period::Quarter.next(&date), next_quarter(&date));
+        assert_eq!(Period::Year.previous(&date), previous_year(&date));
+    }
+
+    #[quickcheck]
+    fn previous_complete_periods_end_before_the_current_one(
+        date: ArbitraryDate,
+        count: u8,
+    ) -> bool {
+        let date = date.0;
+        let count = u32::from(count % 8) + 1;
+
+        [Period::Week, Period::Month, Period::Quarter, Period::Year]
+            .iter()
+            .all(|period| match period.previous_complete(&date, count) {
+                Some(range) => {
+                    period.next(&range.end()) == period.beginning(&date)
+                        && period.beginning(&range.start()) == Some(range.start())
+                        && period.count_between(&range.start(), &date) == Some(i64::from(count))
+                }
+                None => date.year() - 9 < NaiveDate::MIN.year(),
+            })
+    }
+
+    #[test]
+    fn previous_complete_periods_exclude_the_current_one() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(
+            previous_n_complete_months(&ymd(2024, 3, 1), 1),
+            DateRange::new(ymd(2024, 2, 1), ymd(2024, 2, 29))
+        );
+        assert_eq!(
+            previous_n_complete_quarters(&ymd(2024, 1, 15), 2),
+            DateRange::new(ymd(2023, 7, 1), ymd(2023, 12, 31))
+        );
+        assert_eq!(
+            previous_n_complete_weeks(&ymd(2024, 5, 17), 2),
+            DateRange::new(ymd(2024, 4, 28), ymd(2024, 5, 11))
+        );
+        assert_eq!(
+            previous_n_complete_years(&ymd(2024, 12, 31), 1),
+            DateRange::new(ymd(2023, 1, 1), ymd(2023, 12, 31))
+        );
+        assert_eq!(previous_n_complete_months(&ymd(2024, 3, 1), 0), None);
+        assert_eq!(previous_n_complete_years(&NaiveDate::MIN, 1), None);
+    }
+
+    #[test]
+    fn to_date_ranges_begin_with_the_period() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let date = ymd(2024, 5, 17);
+
+        assert_eq!(
+            year_to_date(&date, EndBound::Inclusive),
+            DateRange::new(ymd(2024, 1, 1), date)
+        );
+        assert_eq!(
+            month_to_date(&date, EndBound::Exclusive),
+            DateRange::new(ymd(2024, 5, 1), ymd(2024, 5, 16))
+        );
+        assert_eq!(
+            week_to_date(&date, EndBound::Inclusive),
+            DateRange::new(ymd(2024, 5, 12), date)
+        );
+        assert_eq!(
+            quarter_to_date(&ymd(2024, 4, 1), EndBound::Inclusive),
+            DateRange::new(ymd(2024, 4, 1), ymd(2024, 4, 1))
+        );
+        assert_eq!(quarter_to_date(&ymd(2024, 4, 1), EndBound::Exclusive), None);
+    }
+
+    #[test]
+    fn range_policies_resolve_the_limits() {
+        let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+
+        for period in &[Period::Week, Period::Month, Period::Quarter, Period::Year] {
+            assert_eq!(
+                period.next_with(&date, RangePolicy::Strict).ok(),
+                period.next(&date)
+            );
+            assert_eq!(
+                period
+                    .previous_with(&NaiveDate::MIN, RangePolicy::Strict)
+                    .map_err(|error| error.limit()),
+                Err(NaiveDate::MIN)
+            );
+            assert_eq!(
+                period.previous_with(&NaiveDate::MIN, RangePolicy::Lenient),
+                Ok(NaiveDate::MIN)
+            );
+            assert_eq!(
+                period.next_with(&NaiveDate::MAX, RangePolicy::Lenient),
+                Ok(NaiveDate::MAX)
+            );
+        }
+
+        assert!(Period::Week
+            .beginning_with(&NaiveDate::MIN, RangePolicy::Strict)
+            .is_err());
+        assert_eq!(
+            Period::Week.end_with(&NaiveDate::MAX, RangePolicy::Lenient),
+            Ok(NaiveDate::MAX)
+        );
+    }
+
+    #[quickcheck]
+    fn starts_match_repeated_steps(date: ArbitraryDate) -> bool {
+        [Period::Week, Period::Month, Period::Quarter, Period::Year]
+            .iter()
+            .all(|period| {
+                let mut expected = period.beginning(&date.0);
+
+                period.starts(&date.0).take(30).all(|start| {
+                    let matches = Some(start) == expected;
+                    expected = period.next(&start);
+                    matches
+                })
+            })
+    }
+
+    #[test]
+    fn starts_end_at_the_limits() {
+        let last_year = NaiveDate::from_ymd_opt(NaiveDate::MAX.year(), 6, 1).unwrap();
+
+        assert_eq!(Period::Year.starts(&last_year).count(), 1);
+        assert_eq!(Period::Month.starts(&last_year).count(), 7);
+        assert_eq!(
+            Period::Week.starts(&NaiveDate::MIN).next(),
+            beginning_of_week(&NaiveDate::MIN)
+        );
+    }
+
+    #[test]
+    fn count_between_counts_boundaries() {
+        let new_years_eve = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+        let new_years_day = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let later = NaiveDate::from_ymd_opt(2024, 8, 15).unwrap();
+
+        assert_eq!(
+            Period::Year.count_between(&new_years_eve, &new_years_day),
+            Some(1)
+        );
+        assert_eq!(
+            Period::Week.count_between(&new_years_eve, &new_years_day),
+            Some(0)
+        );
+        assert_eq!(
+            Period::Quarter.count_between(&new_years_eve, &later),
+            Some(3)
+        );
+        assert_eq!(
+            Period::Month.count_between(&later, &new_years_eve),
+            Some(-8)
+        );
+    }
+
+    #[test]
+    fn parses_names_plurals_and_short_forms() {
+        assert_eq!("week".parse(), Ok(Period::Week));
+        assert_eq!("Months".parse(), Ok(Period::Month));
+        assert_eq!("qtr".parse(), Ok(Period::Quarter));
+        assert_eq!(" y ".parse(), Ok(Period::Year));
+        assert!("fortnight".parse::<Period>().is_err());
+    }
+
+    #[test]
+    fn displays_round_trip() {
+        for period in &[Period::Week, Period::Month, Period::Quarter, Period::Year] {
+            assert_eq!(period.to_string().parse(), Ok(*period));
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use crate::year_month::DisplayYear;
+use crate::{DateRange, IsoYearWeek, ParseError, Period};
+use chrono::prelude::*;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+const EXPECTED: &str = "a period like \"2024\", \"2024Q3\", \"2024M07\", or \"2024W15\"";
+
+/// A period identified by a single ordinal, for partition keys and joins on periods.
+///
+/// The ordinal counts periods of one kind consecutively: years by their number, quarters and
+/// months from the first of year 0, and weeks from the week of January 1, year 1. Weeks are ISO
+/// 8601 weeks, beginning on Monday and numbered within their ISO week-numbering year, rather
+/// than the Sunday weeks of [`Period::Week`]'s boundary functions. Indexes order by kind, weeks
+/// first, and then chronologically.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::{Period, PeriodIndex};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 7, 4).unwrap();
+/// let month = PeriodIndex::containing(Period::Month, &date).unwrap();
+///
+/// assert_eq!(month.to_string(), "2024M07");
+/// assert_eq!(month.next().unwrap().to_string(), "2024M08");
+/// assert_eq!("2024m08".parse(), Ok(month.next().unwrap()));
+/// assert_eq!(PeriodIndex::containing(Period::Week, &date).unwrap().to_string(), "2024W27");
+/// assert_eq!(month.range().to_string(), "2024-07-01/2024-07-31");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PeriodIndex {
+    period: Period,
+    ordinal: i32,
+}
+
+impl PeriodIndex {
+    /// Returns the index of the period containing the date, or `None` for a week extending
+    /// beyond the dates chrono can represent.
+    pub fn containing(period: Period, date: &NaiveDate) -> Option<Self> {
+        let ordinal = match period {
+            Period::Week => (date.num_days_from_ce() - 1).div_euclid(7),
+            Period::Month => date.year() * 12 + date.month0() as i32,
+            Period::Quarter => date.year() * 4 + date.month0() as i32 / 3,
+            Period::Year => date.year(),
+        };
+
+        PeriodIndex::from_ordinal(period, ordinal)
+    }
+
+    /// Builds an index from its kind and ordinal, returning `None` if the period is outside the
+    /// dates chrono can represent.
+    pub fn from_ordinal(period: Period, ordinal: i32) -> Option<Self> {
+        let index = PeriodIndex { period, ordinal };
+        index.bounds()?;
+
+        Some(index)
+    }
+
+    /// Returns the kind of period.
+    pub fn period(&self) -> Period {
+        self.period
+    }
+
+    /// Returns the ordinal, consecutive for consecutive periods of the same kind.
+    pub fn ordinal(&self) -> i32 {
+        self.ordinal
+    }
+
+    /// Returns the index `count` periods later, or earlier when `count` is negative.
+    ///
+    /// Returns `None` if the period is outside the dates chrono can represent.
+    pub fn checked_add(&self, count: i32) -> Option<Self> {
+        PeriodIndex::from_ordinal(self.period, self.ordinal.checked_add(count)?)
+    }
+
+    /// Returns the index of the following period.
+    pub fn next(&self) -> Option<Self> {
+        self.checked_add(1)
+    }
+
+    /// Returns the index of the preceding period.
+    pub fn previous(&self) -> Option<Self> {
+        self.checked_add(-1)
+    }
+
+    /// Returns the range of dates within the period.
+    pub fn range(&self) -> DateRange {
+        let (start, end) = self.bounds().expect("indexes are representable");
+
+        DateRange::new(start, end).expect("periods end after they begin")
+    }
+
+    // The first and last days of the period, which chrono may not be able to represent for an
+    // arbitrary ordinal
+    fn bounds(&self) -> Option<(NaiveDate, NaiveDate)> {
+        let months = |first: i32, count: i32| {
+            let start =
+                NaiveDate::from_ymd_opt(first.div_euclid(12), first.rem_euclid(12) as u32 + 1, 1)?;
+            let last = first.checked_add(count - 1)?;
+            let end =
+                NaiveDate::from_ymd_opt(last.div_euclid(12), last.rem_euclid(12) as u32 + 1, 1)?;
+
+            Some((start, crate::end_of_month(&end)))
+        };
+
+        match self.period {
+            Period::Week => {
+                let monday = i64::from(self.ordinal) * 7 + 1;
+                let start = NaiveDate::from_num_days_from_ce_opt(i32::try_from(monday).ok()?)?;
+
+                Some((start, start.checked_add_days(chrono::Days::new(6))?))
+            }
+            Period::Month => months(self.ordinal, 1),
+            Period::Quarter => months(self.ordinal.checked_mul(3)?, 3),
+            Period::Year => Some((
+                NaiveDate::from_ymd_opt(self.ordinal, 1, 1)?,
+                NaiveDate::from_ymd_opt(self.ordinal, 12, 31)?,
+            )),
+        }
+    }
+}
+
+/// Formats the index as `2024` for years, `2024Q3` for quarters, `2024M07` for months, or
+/// `2024W15` for ISO weeks.
+impl fmt::Display for PeriodIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let start = self.range().start();
+
+        match self.period {
+            Period::Week => {
+                let week = IsoYearWeek::from_date(&start);
+                write!(f, "{}W{:02}", DisplayYear(week.year()), week.week())
+            }
+            Period::Month => write!(f, "{}M{:02}", DisplayYear(start.year()), start.month()),
+            Period::Quarter => write!(
+                f,
+                "{}Q{}",
+                DisplayYear(start.year()),
+                start.month0() / 3 + 1
+            ),
+            Period::Year => write!(f, "{}", DisplayYear(start.year())),
+        }
+    }
+}
+
+/// Parses indexes in the formats they are displayed in, with the period letter in either case.
+impl FromStr for PeriodIndex {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || ParseError::new(input, EXPECTED);
+        let input = input.trim();
+        let split = input
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| c.is_ascii_alphabetic())
+            .map_or(input.len(), |(index, _)| index);
+        let (year, rest) = input.split_at(split);
+
+        let digits = year.strip_prefix(&['+', '-'][..]).unwrap_or(year);
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(error());
+        }
+        let year: i32 = year.parse().map_err(|_| error())?;
+
+        let number = |number: &str| -> Result<u32, ParseError> {
+            if (1..=2).contains(&number.len()) && number.chars().all(|c| c.is_ascii_digit()) {
+                number.parse().map_err(|_| error())
+            } else {
+                Err(error())
+            }
+        };
+
+        let start = match rest.chars().next().map(|c| c.to_ascii_uppercase()) {
+            None => NaiveDate::from_ymd_opt(year, 1, 1).map(|start| (Period::Year, start)),
+            Some('Q') => {
+                let quarter = number(&rest[1..])?;
+                if !(1..=4).contains(&quarter) {
+                    return Err(error());
+                }
+                NaiveDate::from_ymd_opt(year, quarter * 3 - 2, 1)
+                    .map(|start| (Period::Quarter, start))
+            }
+            Some('M') => NaiveDate::from_ymd_opt(year, number(&rest[1..])?, 1)
+                .map(|start| (Period::Month, start)),
+            Some('W') => IsoYearWeek::new(year, number(&rest[1..])?)
+                .and_then(|week| week.first_day())
+                .map(|start| (Period::Week, start)),
+            Some(_) => None,
+        };
+
+        start
+            .and_then(|(period, start)| PeriodIndex::containing(period, &start))
+            .ok_or_else(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    const PERIODS: [Period; 4] = [Period::Week, Period::Month, Period::Quarter, Period::Year];
+
+    #[quickcheck]
+    fn indexes_contain_the_date_and_step_consecutively(date: ArbitraryDate) -> bool {
+        PERIODS.iter().all(|period| {
+            let index = PeriodIndex::containing(*period, &date.0).unwrap();
+            let next = index.next().unwrap();
+
+            index.range().contains(&date.0)
+                && next.range().start().pred_opt() == Some(index.range().end())
+                && next > index
+                && index.to_string().parse() == Ok(index)
+        })
+    }
+
+    #[test]
+    fn formats_each_kind() {
+        let date = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+        let format = |period| PeriodIndex::containing(period, &date).unwrap().to_string();
+
+        assert_eq!(format(Period::Week), "2025W01");
+        assert_eq!(format(Period::Month), "2024M12");
+        assert_eq!(format(Period::Quarter), "2024Q4");
+        assert_eq!(format(Period::Year), "2024");
+        assert_eq!(
+            "-0044M03"
+                .parse::<PeriodIndex>()
+                .map(|index| index.to_string()),
+            Ok("-0044M03".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_indexes() {
+        for input in &[
+            "2024Q5", "2024M13", "2023W53", "2024X1", "Q3", "2024Q", "2024M123",
+        ] {
+            assert!(input.parse::<PeriodIndex>().is_err(), "{}", input);
+        }
+
+        assert_eq!(PeriodIndex::from_ordinal(Period::Year, i32::MAX), None);
+        assert_eq!(
+            PeriodIndex::containing(Period::Month, &NaiveDate::MAX).and_then(|index| index.next()),
+            None
+        );
+        assert_eq!(PeriodIndex::containing(Period::Week, &NaiveDate::MAX), None);
+    }
+}
@@ -0,0 +1,298 @@
+//! Conversion between Gregorian and Persian (Solar Hijri, or Jalali) dates, and Persian month
+//! and year boundaries.
+//!
+//! The year begins at Nowruz, the March equinox. Leap years follow the 33-year cycles with
+//! irregular break years of Borkowski's algorithm, which matches the astronomical calendar used
+//! in Iran and Afghanistan for years 1 through 3177; dates outside that range are not supported.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::persian::{self, PersianDate};
+//!
+//! assert_eq!(persian::nowruz(2025), NaiveDate::from_ymd_opt(2025, 3, 21));
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+//! assert_eq!(PersianDate::from_gregorian(&date), PersianDate::new(1403, persian::ORDIBEHESHT, 28));
+//! ```
+
+use chrono::prelude::*;
+use core::fmt;
+
+/// The first month, beginning at Nowruz.
+pub const FARVARDIN: u32 = 1;
+/// The second month.
+pub const ORDIBEHESHT: u32 = 2;
+/// The third month.
+pub const KHORDAD: u32 = 3;
+/// The fourth month.
+pub const TIR: u32 = 4;
+/// The fifth month.
+pub const MORDAD: u32 = 5;
+/// The sixth month.
+pub const SHAHRIVAR: u32 = 6;
+/// The seventh month.
+pub const MEHR: u32 = 7;
+/// The eighth month.
+pub const ABAN: u32 = 8;
+/// The ninth month.
+pub const AZAR: u32 = 9;
+/// The tenth month.
+pub const DEY: u32 = 10;
+/// The eleventh month.
+pub const BAHMAN: u32 = 11;
+/// The twelfth month, with 30 days in leap years and 29 otherwise.
+pub const ESFAND: u32 = 12;
+
+const LAST_SUPPORTED_YEAR: i32 = 3177;
+const BREAKS: [i32; 20] = [
+    -61, 9, 38, 199, 426, 686, 756, 818, 1111, 1181, 1210, 1635, 2060, 2097, 2192, 2262, 2324,
+    2394, 2456, 3178,
+];
+
+/// A date in the Persian calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PersianDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl PersianDate {
+    /// Builds a Persian date, returning `None` if the year is unsupported or the month or day is
+    /// out of range.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if (1..=LAST_SUPPORTED_YEAR).contains(&year)
+            && (1..=12).contains(&month)
+            && day >= 1
+            && day <= days_in_month(year, month)
+        {
+            Some(PersianDate { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the Persian date falling on the Gregorian date, or `None` if its year is
+    /// unsupported.
+    pub fn from_gregorian(date: &NaiveDate) -> Option<Self> {
+        let year = date.year() - 621;
+        let nowruz = nowruz_of(year)?;
+        let days = date.signed_duration_since(nowruz).num_days();
+
+        let (year, day_of_year) = if days >= 0 {
+            (year, days)
+        } else {
+            let previous = nowruz_of(year - 1)?;
+            (year - 1, date.signed_duration_since(previous).num_days())
+        };
+
+        let (month, day) = if day_of_year < 186 {
+            (1 + day_of_year / 31, day_of_year % 31 + 1)
+        } else {
+            (7 + (day_of_year - 186) / 30, (day_of_year - 186) % 30 + 1)
+        };
+
+        PersianDate::new(year, month as u32, day as u32)
+    }
+
+    /// Returns the Gregorian date falling on the same day.
+    pub fn to_gregorian(&self) -> Option<NaiveDate> {
+        let months_before = self.month - 1;
+        let days_before = 31 * months_before.min(6) + 30 * months_before.saturating_sub(6);
+
+        nowruz_of(self.year)?.checked_add_signed(chrono::Duration::days(i64::from(
+            days_before + self.day - 1,
+        )))
+    }
+
+    /// Returns the year, counted from the Hijra.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, between 1 and 12.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+}
+
+/// Formats the date as `YYYY-MM-DD`.
+impl fmt::Display for PersianDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Returns whether the Persian year is a leap year of 366 days.
+pub fn is_leap_year(year: i32) -> bool {
+    matches!(cycle(year), Some((true, _)))
+}
+
+/// Returns the number of days in the month of the Persian year.
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1..=6 => 31,
+        7..=11 => 30,
+        _ if is_leap_year(year) => 30,
+        _ => 29,
+    }
+}
+
+/// Returns Nowruz, the first day of the Persian year beginning in the Gregorian year.
+pub fn nowruz(gregorian_year: i32) -> Option<NaiveDate> {
+    nowruz_of(gregorian_year.checked_sub(621)?)
+}
+
+/// Returns the beginning of the Persian month containing the provided date.
+pub fn beginning_of_month(date: &NaiveDate) -> Option<NaiveDate> {
+    let persian = PersianDate::from_gregorian(date)?;
+    PersianDate::new(persian.year, persian.month, 1)?.to_gregorian()
+}
+
+/// Returns the end of the Persian month containing the provided date.
+pub fn end_of_month(date: &NaiveDate) -> Option<NaiveDate> {
+    let persian = PersianDate::from_gregorian(date)?;
+    let last_day = days_in_month(persian.year, persian.month);
+    PersianDate::new(persian.year, persian.month, last_day)?.to_gregorian()
+}
+
+/// Returns the beginning of the next Persian month.
+pub fn next_month(date: &NaiveDate) -> Option<NaiveDate> {
+    end_of_month(date)?.succ_opt()
+}
+
+/// Returns the beginning of the previous Persian month.
+pub fn previous_month(date: &NaiveDate) -> Option<NaiveDate> {
+    beginning_of_month(&beginning_of_month(date)?.pred_opt()?)
+}
+
+/// Returns the beginning (Nowruz) of the Persian year containing the provided date.
+pub fn beginning_of_year(date: &NaiveDate) -> Option<NaiveDate> {
+    nowruz_of(PersianDate::from_gregorian(date)?.year)
+}
+
+/// Returns the end of the Persian year containing the provided date.
+pub fn end_of_year(date: &NaiveDate) -> Option<NaiveDate> {
+    next_year(date)?.pred_opt()
+}
+
+/// Returns the beginning of the next Persian year.
+pub fn next_year(date: &NaiveDate) -> Option<NaiveDate> {
+    nowruz_of(PersianDate::from_gregorian(date)?.year + 1)
+}
+
+/// Returns the beginning of the previous Persian year.
+pub fn previous_year(date: &NaiveDate) -> Option<NaiveDate> {
+    let year = PersianDate::from_gregorian(date)?.year - 1;
+    PersianDate::new(year, FARVARDIN, 1)?.to_gregorian()
+}
+
+fn nowruz_of(year: i32) -> Option<NaiveDate> {
+    let (_, march_day) = cycle(year)?;
+    NaiveDate::from_ymd_opt(year + 621, 3, march_day)
+}
+
+// Whether the year is leap, and the day of March on which it begins
+fn cycle(year: i32) -> Option<(bool, u32)> {
+    if year < BREAKS[0] || year >= BREAKS[BREAKS.len() - 1] {
+        return None;
+    }
+
+    let gregorian_year = year + 621;
+    let mut leap_days = -14;
+    let mut previous_break = BREAKS[0];
+    let mut jump = 0;
+
+    for &next_break in &BREAKS[1..] {
+        jump = next_break - previous_break;
+        if year < next_break {
+            break;
+        }
+        leap_days += jump / 33 * 8 + jump % 33 / 4;
+        previous_break = next_break;
+    }
+
+    let mut n = year - previous_break;
+    leap_days += n / 33 * 8 + (n % 33 + 3) / 4;
+    if jump % 33 == 4 && jump - n == 4 {
+        leap_days += 1;
+    }
+
+    let gregorian_leap_days = gregorian_year / 4 - (gregorian_year / 100 + 1) * 3 / 4 - 150;
+    let march_day = 20 + leap_days - gregorian_leap_days;
+
+    if jump - n < 6 {
+        n = n - jump + (jump + 4) / 33 * 33;
+    }
+    let leap = ((n + 1) % 33 - 1) % 4 == 0;
+
+    Some((leap, march_day as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn converts_known_dates() {
+        assert_eq!(
+            PersianDate::from_gregorian(&ymd(1979, 2, 11)),
+            PersianDate::new(1357, BAHMAN, 22)
+        );
+        assert_eq!(
+            PersianDate::new(1403, ESFAND, 30).unwrap().to_gregorian(),
+            Some(ymd(2025, 3, 20))
+        );
+        assert_eq!(
+            PersianDate::from_gregorian(&ymd(2025, 3, 21)),
+            PersianDate::new(1404, FARVARDIN, 1)
+        );
+    }
+
+    #[test]
+    fn computes_year_structure() {
+        assert!(is_leap_year(1399));
+        assert!(is_leap_year(1403));
+        assert!(!is_leap_year(1402));
+        assert!(!is_leap_year(1404));
+        assert_eq!(PersianDate::new(1404, ESFAND, 30), None);
+        assert_eq!(nowruz(2020), Some(ymd(2020, 3, 20)));
+        assert_eq!(nowruz(2024), Some(ymd(2024, 3, 20)));
+        assert_eq!(nowruz(i32::MIN), None);
+        assert_eq!(nowruz(i32::MAX), None);
+    }
+
+    #[test]
+    fn computes_persian_boundaries() {
+        // Ordibehesht 28, 1403
+        let date = ymd(2024, 5, 17);
+
+        assert_eq!(beginning_of_month(&date), Some(ymd(2024, 4, 20)));
+        assert_eq!(end_of_month(&date), Some(ymd(2024, 5, 20)));
+        assert_eq!(next_month(&date), Some(ymd(2024, 5, 21)));
+        assert_eq!(previous_month(&date), Some(ymd(2024, 3, 20)));
+        assert_eq!(beginning_of_year(&date), Some(ymd(2024, 3, 20)));
+        assert_eq!(end_of_year(&date), Some(ymd(2025, 3, 20)));
+        assert_eq!(next_year(&date), Some(ymd(2025, 3, 21)));
+        assert_eq!(previous_year(&date), Some(ymd(2023, 3, 21)));
+    }
+
+    #[quickcheck]
+    fn round_trips_through_persian_dates(date: ArbitraryDate) -> bool {
+        let persian = PersianDate::from_gregorian(&date.0).unwrap();
+
+        PersianDate::new(persian.year(), persian.month(), persian.day()) == Some(persian)
+            && persian.to_gregorian() == Some(date.0)
+    }
+}
@@ -0,0 +1,102 @@
+use chrono::prelude::*;
+use core::fmt;
+
+/// How to resolve a boundary that falls outside the dates chrono can represent.
+///
+/// Only week boundaries and shifts to the next or previous period can leave
+/// `NaiveDate::MIN..=NaiveDate::MAX`. A validator should reject such a date, while something
+/// like a dashboard may prefer to stop at the last representable day.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::{Period, RangePolicy};
+///
+/// assert!(Period::Year.next_with(&NaiveDate::MAX, RangePolicy::Strict).is_err());
+/// assert_eq!(
+///     Period::Year.next_with(&NaiveDate::MAX, RangePolicy::Lenient),
+///     Ok(NaiveDate::MAX)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RangePolicy {
+    /// Return an [`OutOfRange`] error.
+    Strict,
+    /// Saturate at `NaiveDate::MIN` or `NaiveDate::MAX`, whichever limit was crossed.
+    Lenient,
+}
+
+impl RangePolicy {
+    // Resolves a boundary that is `None` only when it lies beyond `limit`
+    pub(crate) fn resolve(
+        self,
+        boundary: Option<NaiveDate>,
+        limit: NaiveDate,
+    ) -> Result<NaiveDate, OutOfRange> {
+        match (boundary, self) {
+            (Some(date), _) => Ok(date),
+            (None, RangePolicy::Strict) => Err(OutOfRange { limit }),
+            (None, RangePolicy::Lenient) => Ok(limit),
+        }
+    }
+}
+
+/// An error returned when a boundary falls outside `NaiveDate::MIN..=NaiveDate::MAX`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfRange {
+    limit: NaiveDate,
+}
+
+impl OutOfRange {
+    /// Returns the limit that was crossed: `NaiveDate::MIN` or `NaiveDate::MAX`.
+    pub fn limit(&self) -> NaiveDate {
+        self.limit
+    }
+}
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let side = if self.limit == NaiveDate::MIN {
+            "before"
+        } else {
+            "after"
+        };
+
+        write!(
+            f,
+            "date falls {} the representable limit {}",
+            side, self.limit
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfRange {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strict_reports_the_crossed_limit() {
+        let error = RangePolicy::Strict
+            .resolve(None, NaiveDate::MIN)
+            .unwrap_err();
+
+        assert_eq!(error.limit(), NaiveDate::MIN);
+        assert!(error.to_string().contains("before"));
+    }
+
+    #[test]
+    fn lenient_saturates_at_the_limit() {
+        let date = NaiveDate::from_ymd_opt(2024, 5, 17);
+
+        assert_eq!(
+            RangePolicy::Lenient.resolve(date, NaiveDate::MAX),
+            Ok(date.unwrap())
+        );
+        assert_eq!(
+            RangePolicy::Lenient.resolve(None, NaiveDate::MAX),
+            Ok(NaiveDate::MAX)
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::year_month::DisplayYear;
+use crate::{end_of_quarter, DateRange, ParseError};
+use chrono::prelude::*;
+use core::fmt;
+use core::str::FromStr;
+
+const EXPECTED: &str = "a quarter like \"2024-Q3\", \"2024Q3\", or \"Q3 2024\"";
+
+/// A calendar quarter of a specific year.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::Quarter;
+///
+/// let quarter: Quarter = "Q3 2024".parse().unwrap();
+///
+/// assert_eq!(quarter.to_string(), "2024-Q3");
+/// assert_eq!(quarter.first_day(), NaiveDate::from_ymd_opt(2024, 7, 1));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Quarter {
+    year: i32,
+    quarter: u32,
+}
+
+impl Quarter {
+    /// Builds a quarter from a year and a quarter number between 1 and 4.
+    pub fn new(year: i32, quarter: u32) -> Option<Self> {
+        if (1..=4).contains(&quarter) {
+            Some(Quarter { year, quarter })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the quarter containing the provided date.
+    pub fn from_date(date: &NaiveDate) -> Self {
+        Quarter {
+            year: date.year(),
+            quarter: 1 + date.month0() / 3,
+        }
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the quarter number, between 1 and 4.
+    pub fn quarter(&self) -> u32 {
+        self.quarter
+    }
+
+    /// Returns the first day of the quarter.
+    pub fn first_day(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year, 3 * self.quarter - 2, 1)
+    }
+
+    /// Returns the last day of the quarter.
+    pub fn last_day(&self) -> Option<NaiveDate> {
+        Some(end_of_quarter(&self.first_day()?))
+    }
+
+    /// Returns the range of dates within the quarter.
+    pub fn range(&self) -> Option<DateRange> {
+        DateRange::new(self.first_day()?, self.last_day()?)
+    }
+}
+
+/// Formats the quarter as `2024-Q3`.
+impl fmt::Display for Quarter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-Q{}", DisplayYear(self.year), self.quarter)
+    }
+}
+
+/// Parses quarters written as `2024-Q3`, `2024Q3`, or `Q3 2024`, with years beyond 0 through
+/// 9999 signed as `Display` writes them: `-0001-Q1`, `+10000-Q1`.
+impl FromStr for Quarter {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || ParseError::new(input, EXPECTED);
+        let normalized = input.trim();
+
+        let (year, quarter) = if let Some(rest) = normalized.strip_prefix(['Q', 'q']) {
+            let mut parts = rest.splitn(2, ' ');
+            let quarter = parts.next().ok_or_else(error)?;
+            let year = parts.next().ok_or_else(error)?;
+            (year, quarter)
+        } else {
+            let position = normalized.rfind(['Q', 'q']).ok_or_else(error)?;
+            let year = &normalized[..position];
+            (
+                year.strip_suffix('-').unwrap_or(year),
+                &normalized[position + 1..],
+            )
+        };
+
+        let digits = year.strip_prefix(&['+', '-'][..]).unwrap_or(year);
+        if !is_digits(digits) || !is_digits(quarter) {
+            return Err(error());
+        }
+
+        let year = year.parse().map_err(|_| error())?;
+        let quarter = quarter.parse().map_err(|_| error())?;
+
+        Quarter::new(year, quarter).ok_or_else(error)
+    }
+}
+
+fn is_digits(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_quarter_labels() {
+        let expected = Quarter::new(2024, 3);
+
+        assert_eq!("2024-Q3".parse().ok(), expected);
+        assert_eq!("2024Q3".parse().ok(), expected);
+        assert_eq!("Q3 2024".parse().ok(), expected);
+        assert_eq!(" 2024-q3 ".parse().ok(), expected);
+    }
+
+    #[test]
+    fn rejects_invalid_quarters() {
+        for input in &[
+            "2024-Q5", "2024-Q0", "Q3", "2024", "Q3-2024", "2024--Q3", "-Q1", "+-2024Q1", "2024+Q1",
+        ] {
+            assert!(input.parse::<Quarter>().is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn displays_round_trip() {
+        let quarter = Quarter::new(1999, 4).unwrap();
+
+        assert_eq!(quarter.to_string(), "1999-Q4");
+        assert_eq!(quarter.to_string().parse(), Ok(quarter));
+    }
+
+    #[test]
+    fn displays_expanded_years_round_trip() {
+        for year in &[-1, 0, 10000, -10000, i32::MIN, i32::MAX] {
+            let quarter = Quarter::new(*year, 1).unwrap();
+
+            assert_eq!(quarter.to_string().parse(), Ok(quarter), "{}", quarter);
+        }
+
+        assert_eq!("-0001-Q1".parse::<Quarter>().unwrap().year(), -1);
+        assert_eq!("Q2 +10000".parse(), Ok(Quarter::new(10000, 2).unwrap()));
+    }
+
+    #[test]
+    fn builds_from_dates() {
+        let date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+        let quarter = Quarter::from_date(&date);
+
+        assert_eq!(quarter, Quarter::new(2024, 4).unwrap());
+        assert_eq!(quarter.first_day(), NaiveDate::from_ymd_opt(2024, 10, 1));
+        assert_eq!(quarter.last_day(), Some(date));
+    }
+}
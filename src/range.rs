@@ -0,0 +1,371 @@
+use crate::{days_in_month, days_in_quarter, days_in_year, end_of_month};
+use crate::{CalendarDuration, MonthOverflow, ParseError, Period};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use chrono::prelude::*;
+use core::fmt;
+use core::str::FromStr;
+
+const EXPECTED: &str =
+    "an ISO 8601 interval like \"2024-01-01/2024-03-31\", \"2024-01-01/P3M\", or \"P3M/2024-03-31\"";
+
+/// An inclusive range of dates.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::DateRange;
+///
+/// let range: DateRange = "2024-01-01/P3M".parse().unwrap();
+///
+/// assert_eq!(range.start(), NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+/// assert_eq!(range.end(), NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+/// assert_eq!(range.num_days(), 91);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateRange {
+    start: NaiveDate,
+    end: NaiveDate,
+}
+
+impl DateRange {
+    /// Builds a range from `start` through `end`, inclusive.
+    ///
+    /// Returns `None` if `end` precedes `start`.
+    pub fn new(start: NaiveDate, end: NaiveDate) -> Option<Self> {
+        if end < start {
+            None
+        } else {
+            Some(DateRange { start, end })
+        }
+    }
+
+    /// Returns the first date in the range.
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    /// Returns the last date in the range.
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    /// Returns whether the provided date falls within the range.
+    pub fn contains(&self, date: &NaiveDate) -> bool {
+        self.start <= *date && *date <= self.end
+    }
+
+    /// Returns the number of days in the range, counting both ends.
+    pub fn num_days(&self) -> i64 {
+        self.end.signed_duration_since(self.start).num_days() + 1
+    }
+
+    /// Splits the range at period boundaries, returning one range per period it overlaps.
+    ///
+    /// The first and last ranges are clipped to the range itself, so they may cover only part of
+    /// their period.
+    #[cfg(feature = "alloc")]
+    pub fn split_by_period(&self, period: Period) -> Vec<DateRange> {
+        self.pieces(period).collect()
+    }
+
+    /// Returns how many periods the range covers, counting the part of each period it overlaps
+    /// as a fraction of that period.
+    ///
+    /// A range within a single period returns the share of it to prorate a charge by. Weeks are
+    /// counted in actual days under either convention.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use date_calculations::{DateRange, DayCount, Period};
+    ///
+    /// let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    /// let february = DateRange::new(ymd(2024, 2, 15), ymd(2024, 2, 29)).unwrap();
+    ///
+    /// assert_eq!(february.prorate(Period::Month, DayCount::Actual), 15.0 / 29.0);
+    /// assert_eq!(february.prorate(Period::Month, DayCount::Thirty360), 16.0 / 30.0);
+    /// ```
+    pub fn prorate(&self, period: Period, basis: DayCount) -> f64 {
+        self.pieces(period)
+            .map(|piece| {
+                let (days, length) = match (period, basis) {
+                    (Period::Week, _) => (piece.num_days(), 7),
+                    (_, DayCount::Actual) => {
+                        let length = match period {
+                            Period::Month => days_in_month(&piece.start),
+                            Period::Quarter => days_in_quarter(&piece.start),
+                            _ => days_in_year(&piece.start),
+                        };
+                        (piece.num_days(), i64::from(length))
+                    }
+                    (_, DayCount::Thirty360) => {
+                        let length = match period {
+                            Period::Month => 30,
+                            Period::Quarter => 90,
+                            _ => 360,
+                        };
+                        (piece.num_days_360(), length)
+                    }
+                };
+
+                days as f64 / length as f64
+            })
+            .sum()
+    }
+
+    // The range clipped to each period it overlaps, up to the last period chrono can end
+    fn pieces(&self, period: Period) -> Pieces {
+        Pieces {
+            next: Some(self.start),
+            end: self.end,
+            period,
+        }
+    }
+
+    // The days in the range when every month has 30, counting the 31st as the 30th and the end
+    // of February as its 30th
+    fn num_days_360(&self) -> i64 {
+        let day = |date: &NaiveDate, is_end: bool| {
+            if is_end && *date == end_of_month(date) {
+                30
+            } else {
+                i64::from(date.day().min(30))
+            }
+        };
+
+        360 * i64::from(self.end.year() - self.start.year())
+            + 30 * (i64::from(self.end.month()) - i64::from(self.start.month()))
+            + day(&self.end, true)
+            - day(&self.start, false)
+            + 1
+    }
+
+    /// Returns an iterator over every date in the range.
+    pub fn iter(&self) -> DateRangeIter {
+        DateRangeIter {
+            next: Some(self.start),
+            end: self.end,
+        }
+    }
+}
+
+/// How to count the days of a period when prorating by [`DateRange::prorate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DayCount {
+    /// Counts the actual days, so a day of February is a larger share of its month than a day
+    /// of March.
+    Actual,
+    /// Counts every month as 30 days (30/360), so each whole month is the same share of its
+    /// quarter or year.
+    Thirty360,
+}
+
+struct Pieces {
+    next: Option<NaiveDate>,
+    end: NaiveDate,
+    period: Period,
+}
+
+impl Iterator for Pieces {
+    type Item = DateRange;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.next.take()?;
+        let end = self.period.end(&start)?;
+
+        if end >= self.end {
+            return Some(DateRange {
+                start,
+                end: self.end,
+            });
+        }
+
+        self.next = end.succ_opt();
+        Some(DateRange { start, end })
+    }
+}
+
+impl IntoIterator for DateRange {
+    type Item = NaiveDate;
+    type IntoIter = DateRangeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the dates in a [`DateRange`].
+#[derive(Clone, Debug)]
+pub struct DateRangeIter {
+    next: Option<NaiveDate>,
+    end: NaiveDate,
+}
+
+impl Iterator for DateRangeIter {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.filter(|d| *d <= self.end)?;
+        self.next = current.succ_opt();
+        Some(current)
+    }
+}
+
+/// Parses an ISO 8601 interval of dates.
+///
+/// Intervals may be written as `start/end`, `start/duration`, or `duration/end`. Both dates in
+/// `start/end` are included in the range. A duration describes the length of the range, so
+/// `2024-01-01/P3M` ends the day before April 1 and `P1M/2024-02-29` begins on February 1.
+impl FromStr for DateRange {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || ParseError::new(input, EXPECTED);
+        let mut parts = input.splitn(2, '/');
+        let first = parts.next().ok_or_else(error)?;
+        let second = parts.next().ok_or_else(error)?;
+
+        let range = if first.starts_with('P') {
+            let duration = first.parse::<CalendarDuration>().map_err(|_| error())?;
+            let end = second.parse::<NaiveDate>().map_err(|_| error())?;
+            duration
+                .subtract_from(&end.succ_opt().ok_or_else(error)?, MonthOverflow::Clamp)
+                .and_then(|start| DateRange::new(start, end))
+        } else if second.starts_with('P') {
+            let start = first.parse::<NaiveDate>().map_err(|_| error())?;
+            let duration = second.parse::<CalendarDuration>().map_err(|_| error())?;
+            duration
+                .add_to(&start, MonthOverflow::Clamp)
+                .and_then(|end| end.pred_opt())
+                .and_then(|end| DateRange::new(start, end))
+        } else {
+            let start = first.parse::<NaiveDate>().map_err(|_| error())?;
+            let end = second.parse::<NaiveDate>().map_err(|_| error())?;
+            DateRange::new(start, end)
+        };
+
+        range.ok_or_else(error)
+    }
+}
+
+/// Formats the range as an ISO 8601 `start/end` interval.
+impl fmt::Display for DateRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn range(start: NaiveDate, end: NaiveDate) -> DateRange {
+        DateRange::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn rejects_inverted_ranges() {
+        assert_eq!(DateRange::new(ymd(2024, 1, 2), ymd(2024, 1, 1)), None);
+    }
+
+    #[test]
+    fn iterates_inclusively() {
+        let dates: Vec<_> = range(ymd(2024, 2, 28), ymd(2024, 3, 1)).iter().collect();
+
+        assert_eq!(
+            dates,
+            vec![ymd(2024, 2, 28), ymd(2024, 2, 29), ymd(2024, 3, 1)]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn splits_by_period() {
+        let split = range(ymd(2024, 1, 15), ymd(2024, 3, 10)).split_by_period(Period::Month);
+
+        assert_eq!(
+            split,
+            vec![
+                range(ymd(2024, 1, 15), ymd(2024, 1, 31)),
+                range(ymd(2024, 2, 1), ymd(2024, 2, 29)),
+                range(ymd(2024, 3, 1), ymd(2024, 3, 10)),
+            ]
+        );
+        assert_eq!(
+            range(ymd(2024, 5, 1), ymd(2024, 5, 2)).split_by_period(Period::Year),
+            vec![range(ymd(2024, 5, 1), ymd(2024, 5, 2))]
+        );
+    }
+
+    #[test]
+    fn prorates_across_periods() {
+        let january = range(ymd(2024, 1, 1), ymd(2024, 1, 31));
+        let partial = range(ymd(2024, 1, 15), ymd(2024, 3, 10));
+
+        assert_eq!(january.prorate(Period::Month, DayCount::Actual), 1.0);
+        assert_eq!(
+            january.prorate(Period::Quarter, DayCount::Thirty360),
+            1.0 / 3.0
+        );
+        assert_eq!(
+            partial.prorate(Period::Month, DayCount::Actual),
+            17.0 / 31.0 + 1.0 + 10.0 / 31.0
+        );
+        assert_eq!(
+            partial.prorate(Period::Month, DayCount::Thirty360),
+            16.0 / 30.0 + 1.0 + 10.0 / 30.0
+        );
+        assert_eq!(
+            range(ymd(2023, 2, 1), ymd(2023, 2, 28)).prorate(Period::Year, DayCount::Thirty360),
+            30.0 / 360.0
+        );
+        assert_eq!(
+            range(ymd(2024, 5, 17), ymd(2024, 5, 20)).prorate(Period::Week, DayCount::Thirty360),
+            4.0 / 7.0
+        );
+    }
+
+    #[test]
+    fn parses_intervals() {
+        assert_eq!(
+            "2024-01-01/2024-12-31".parse(),
+            Ok(range(ymd(2024, 1, 1), ymd(2024, 12, 31)))
+        );
+        assert_eq!(
+            "2024-01-01/P3M".parse(),
+            Ok(range(ymd(2024, 1, 1), ymd(2024, 3, 31)))
+        );
+        assert_eq!(
+            "P1M/2024-02-29".parse(),
+            Ok(range(ymd(2024, 2, 1), ymd(2024, 2, 29)))
+        );
+        assert_eq!(
+            "2024-01-31/P1D".parse(),
+            Ok(range(ymd(2024, 1, 31), ymd(2024, 1, 31)))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_intervals() {
+        for input in &[
+            "2024-01-01",
+            "2024-02-01/2024-01-01",
+            "P1M/P2M",
+            "2024-01-01/P0D",
+            "2024-13-01/P1D",
+        ] {
+            assert!(input.parse::<DateRange>().is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn displays_as_interval() {
+        assert_eq!(
+            range(ymd(2024, 1, 1), ymd(2024, 3, 31)).to_string(),
+            "2024-01-01/2024-03-31"
+        );
+    }
+}
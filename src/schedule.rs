@@ -0,0 +1,498 @@
+//! Periodic schedules of dates, such as payment or reporting dates, with business-day
+//! adjustment.
+//!
+//! [`ScheduleEntries`] generates the entries without allocating; the collected `Schedule` is
+//! enabled with the `alloc` feature.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::business::{BusinessDayConvention, WeekendsOnly};
+//! use date_calculations::schedule::ScheduleEntries;
+//! use date_calculations::Period;
+//!
+//! let start = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+//! let end = NaiveDate::from_ymd_opt(2024, 4, 15).unwrap();
+//! let entries = ScheduleEntries::new(
+//!     start,
+//!     end,
+//!     Period::Month,
+//!     BusinessDayConvention::ModifiedFollowing,
+//!     &WeekendsOnly,
+//! )
+//! .unwrap();
+//!
+//! let adjusted: Vec<_> = entries.map(|e| e.adjusted().to_string()).collect();
+//! assert_eq!(adjusted, vec!["2024-01-31", "2024-02-29", "2024-03-29", "2024-04-15"]);
+//! ```
+
+use crate::business::{adjust, BusinessDayConvention, HolidayCalendar};
+use crate::duration::shift_months;
+use crate::{MonthOverflow, Period};
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, vec::Vec};
+use chrono::prelude::*;
+use core::iter::FusedIterator;
+
+/// A single date within a [`Schedule`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ScheduleEntry {
+    index: usize,
+    unadjusted: NaiveDate,
+    adjusted: NaiveDate,
+    stub: bool,
+}
+
+impl ScheduleEntry {
+    /// Returns the position of the entry within the schedule, starting at zero.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the date generated by the schedule's frequency, before business-day adjustment.
+    pub fn unadjusted(&self) -> NaiveDate {
+        self.unadjusted
+    }
+
+    /// Returns the date after business-day adjustment.
+    pub fn adjusted(&self) -> NaiveDate {
+        self.adjusted
+    }
+
+    /// Returns whether business-day adjustment moved the date.
+    pub fn is_adjusted(&self) -> bool {
+        self.adjusted != self.unadjusted
+    }
+
+    /// Returns whether this entry ends a period shorter than the schedule's frequency.
+    ///
+    /// Only the final entry can be a stub, when the schedule's end does not fall on a regular
+    /// date.
+    pub fn is_stub(&self) -> bool {
+        self.stub
+    }
+}
+
+/// Dates spaced one period apart from a start date through an end date.
+///
+/// Regular dates are computed from the start date rather than from each other, so a schedule
+/// starting January 31 stays on the last day of shorter months and returns to the 31st where it
+/// exists. When the end date does not fall on a regular date it is included as a final, short
+/// stub.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schedule {
+    frequency: Period,
+    convention: BusinessDayConvention,
+    entries: Vec<ScheduleEntry>,
+}
+
+#[cfg(feature = "alloc")]
+impl Schedule {
+    /// Generates a schedule from `start` through `end`, adjusting every date with the
+    /// convention and holiday calendar.
+    ///
+    /// Returns `None` if `end` precedes `start` or a date cannot be represented.
+    pub fn new<C: HolidayCalendar + ?Sized>(
+        start: NaiveDate,
+        end: NaiveDate,
+        frequency: Period,
+        convention: BusinessDayConvention,
+        calendar: &C,
+    ) -> Option<Self> {
+        let entries: Vec<_> =
+            ScheduleEntries::new(start, end, frequency, convention, calendar)?.collect();
+        if entries.last()?.unadjusted != end {
+            return None;
+        }
+
+        Some(Schedule {
+            frequency,
+            convention,
+            entries,
+        })
+    }
+
+    /// Returns the period between regular dates.
+    pub fn frequency(&self) -> Period {
+        self.frequency
+    }
+
+    /// Returns the business-day convention applied to each date.
+    pub fn convention(&self) -> BusinessDayConvention {
+        self.convention
+    }
+
+    /// Returns the dates in the schedule, in order.
+    pub fn entries(&self) -> &[ScheduleEntry] {
+        &self.entries
+    }
+
+    /// Serializes the schedule as JSON.
+    ///
+    /// The output is a single object with the schedule's `frequency` (`"week"`, `"month"`,
+    /// `"quarter"`, or `"year"`), its business-day `convention` (`"unadjusted"`, `"following"`,
+    /// `"modified_following"`, `"preceding"`, or `"modified_preceding"`), and an ordered array of
+    /// `entries`. Each entry has an integer `index`, `unadjusted` and `adjusted` dates formatted
+    /// as `YYYY-MM-DD`, and the booleans `is_adjusted` and `is_stub`:
+    ///
+    /// ```json
+    /// {"frequency":"month","convention":"following","entries":[
+    ///   {"index":0,"unadjusted":"2024-08-31","adjusted":"2024-09-03","is_adjusted":true,"is_stub":false}
+    /// ]}
+    /// ```
+    ///
+    /// The output contains no insignificant whitespace.
+    pub fn to_json(&self) -> String {
+        let entries: Vec<String> = self
+            .entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    "{{\"index\":{},\"unadjusted\":\"{}\",\"adjusted\":\"{}\",\"is_adjusted\":{},\"is_stub\":{}}}",
+                    entry.index,
+                    entry.unadjusted,
+                    entry.adjusted,
+                    entry.is_adjusted(),
+                    entry.stub
+                )
+            })
+            .collect();
+
+        format!(
+            "{{\"frequency\":\"{}\",\"convention\":\"{}\",\"entries\":[{}]}}",
+            self.frequency,
+            self.convention.name(),
+            entries.join(",")
+        )
+    }
+}
+
+/// The entries of a schedule, generated one at a time without allocating.
+///
+/// This yields the same entries as [`Schedule::new`] for hot paths that walk the dates once, and
+/// [`fill`](Self::fill) writes them into a caller-provided buffer instead, such as a fixed-size
+/// array on an embedded target. Iteration stops early if a date cannot be represented, so the
+/// last entry's unadjusted date is `end` only when the schedule is complete.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::business::{BusinessDayConvention, WeekendsOnly};
+/// use date_calculations::schedule::{ScheduleEntries, ScheduleEntry};
+/// use date_calculations::Period;
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 4, 15).unwrap();
+/// let mut entries =
+///     ScheduleEntries::new(start, end, Period::Month, BusinessDayConvention::Following, &WeekendsOnly)
+///         .unwrap();
+///
+/// let mut buffer = [None::<ScheduleEntry>; 3];
+/// assert_eq!(entries.fill(&mut buffer), 3);
+/// assert_eq!(buffer[2].unwrap().adjusted(), NaiveDate::from_ymd_opt(2024, 4, 1).unwrap());
+/// assert_eq!(entries.fill(&mut buffer), 1);
+/// assert!(buffer[0].unwrap().is_stub());
+/// ```
+#[derive(Debug)]
+pub struct ScheduleEntries<'a, C: ?Sized> {
+    start: NaiveDate,
+    end: NaiveDate,
+    frequency: Period,
+    convention: BusinessDayConvention,
+    calendar: &'a C,
+    step: i64,
+    done: bool,
+}
+
+impl<'a, C: HolidayCalendar + ?Sized> ScheduleEntries<'a, C> {
+    /// Starts generating a schedule from `start` through `end`, adjusting every date with the
+    /// convention and holiday calendar.
+    ///
+    /// Returns `None` if `end` precedes `start`.
+    pub fn new(
+        start: NaiveDate,
+        end: NaiveDate,
+        frequency: Period,
+        convention: BusinessDayConvention,
+        calendar: &'a C,
+    ) -> Option<Self> {
+        if end < start {
+            return None;
+        }
+
+        Some(ScheduleEntries {
+            start,
+            end,
+            frequency,
+            convention,
+            calendar,
+            step: 0,
+            done: false,
+        })
+    }
+
+    /// Writes the next entries into the buffer, returning how many were written.
+    ///
+    /// Slots past the returned count are left untouched, and a return of zero means the
+    /// schedule is exhausted.
+    pub fn fill(&mut self, buffer: &mut [Option<ScheduleEntry>]) -> usize {
+        let mut written = 0;
+
+        for (slot, entry) in buffer.iter_mut().zip(self) {
+            *slot = Some(entry);
+            written += 1;
+        }
+
+        written
+    }
+
+    fn entry(&self) -> Option<ScheduleEntry> {
+        let regular = regular_date(&self.start, self.frequency, self.step)?;
+        let (unadjusted, stub) = if regular >= self.end {
+            (self.end, regular > self.end)
+        } else {
+            (regular, false)
+        };
+
+        Some(ScheduleEntry {
+            index: self.step as usize,
+            unadjusted,
+            adjusted: adjust(&unadjusted, self.convention, self.calendar)?,
+            stub,
+        })
+    }
+}
+
+impl<'a, C: HolidayCalendar + ?Sized> Iterator for ScheduleEntries<'a, C> {
+    type Item = ScheduleEntry;
+
+    fn next(&mut self) -> Option<ScheduleEntry> {
+        if self.done {
+            return None;
+        }
+
+        let entry = self.entry();
+        match entry {
+            Some(entry) if entry.unadjusted != self.end => self.step += 1,
+            _ => self.done = true,
+        }
+
+        entry
+    }
+}
+
+impl<'a, C: HolidayCalendar + ?Sized> FusedIterator for ScheduleEntries<'a, C> {}
+
+fn regular_date(start: &NaiveDate, frequency: Period, step: i64) -> Option<NaiveDate> {
+    match frequency {
+        Period::Week => start.checked_add_signed(chrono::Duration::weeks(step)),
+        Period::Month => shift_months(start, step, MonthOverflow::Clamp),
+        Period::Quarter => shift_months(start, step * 3, MonthOverflow::Clamp),
+        Period::Year => shift_months(start, step * 12, MonthOverflow::Clamp),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::business::{UsFederalHolidays, WeekendsOnly};
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn unadjusted(schedule: &Schedule) -> Vec<NaiveDate> {
+        schedule.entries().iter().map(|e| e.unadjusted()).collect()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn generates_regular_dates_from_the_start() {
+        let schedule = Schedule::new(
+            ymd(2024, 1, 31),
+            ymd(2024, 5, 31),
+            Period::Month,
+            BusinessDayConvention::Unadjusted,
+            &WeekendsOnly,
+        )
+        .unwrap();
+
+        assert_eq!(
+            unadjusted(&schedule),
+            vec![
+                ymd(2024, 1, 31),
+                ymd(2024, 2, 29),
+                ymd(2024, 3, 31),
+                ymd(2024, 4, 30),
+                ymd(2024, 5, 31),
+            ]
+        );
+        assert!(schedule.entries().iter().all(|e| !e.is_stub()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn flags_a_final_stub() {
+        let schedule = Schedule::new(
+            ymd(2024, 1, 1),
+            ymd(2024, 8, 15),
+            Period::Quarter,
+            BusinessDayConvention::Unadjusted,
+            &WeekendsOnly,
+        )
+        .unwrap();
+
+        assert_eq!(
+            unadjusted(&schedule),
+            vec![
+                ymd(2024, 1, 1),
+                ymd(2024, 4, 1),
+                ymd(2024, 7, 1),
+                ymd(2024, 8, 15),
+            ]
+        );
+        assert!(schedule.entries()[3].is_stub());
+        assert_eq!(schedule.entries()[3].index(), 3);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn adjusts_each_date() {
+        let schedule = Schedule::new(
+            ymd(2024, 8, 31),
+            ymd(2024, 8, 31),
+            Period::Month,
+            BusinessDayConvention::Following,
+            &UsFederalHolidays,
+        )
+        .unwrap();
+
+        let entry = schedule.entries()[0];
+        assert_eq!(entry.adjusted(), ymd(2024, 9, 3));
+        assert!(entry.is_adjusted());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fills_buffers_with_the_schedule() {
+        let (start, end) = (ymd(2024, 1, 1), ymd(2024, 12, 20));
+        let schedule = Schedule::new(
+            start,
+            end,
+            Period::Month,
+            BusinessDayConvention::Following,
+            &UsFederalHolidays,
+        )
+        .unwrap();
+        let mut entries = ScheduleEntries::new(
+            start,
+            end,
+            Period::Month,
+            BusinessDayConvention::Following,
+            &UsFederalHolidays,
+        )
+        .unwrap();
+
+        let mut buffer = [None; 5];
+        let mut filled = vec![];
+        loop {
+            let written = entries.fill(&mut buffer);
+            if written == 0 {
+                break;
+            }
+            filled.extend(buffer[..written].iter().map(|entry| entry.unwrap()));
+        }
+
+        assert_eq!(filled, schedule.entries());
+        assert_eq!(filled[0].adjusted(), ymd(2024, 1, 2));
+        assert_eq!(entries.next(), None);
+    }
+
+    #[test]
+    fn fills_fixed_buffers() {
+        let mut entries = ScheduleEntries::new(
+            ymd(2024, 8, 31),
+            ymd(2024, 9, 10),
+            Period::Week,
+            BusinessDayConvention::Following,
+            &UsFederalHolidays,
+        )
+        .unwrap();
+        let adjusted = |entry: Option<ScheduleEntry>| entry.map(|e| e.adjusted());
+
+        let mut buffer = [None; 2];
+        assert_eq!(entries.fill(&mut buffer), 2);
+        assert_eq!(adjusted(buffer[0]), Some(ymd(2024, 9, 3)));
+        assert_eq!(adjusted(buffer[1]), Some(ymd(2024, 9, 9)));
+
+        assert_eq!(entries.fill(&mut buffer), 1);
+        assert!(buffer[0].unwrap().is_stub());
+        assert_eq!(adjusted(buffer[1]), Some(ymd(2024, 9, 9)));
+        assert_eq!(entries.fill(&mut buffer), 0);
+    }
+
+    #[test]
+    fn stops_at_unrepresentable_dates() {
+        let start = NaiveDate::MAX - chrono::Duration::days(40);
+        let entries = ScheduleEntries::new(
+            start,
+            NaiveDate::MAX,
+            Period::Week,
+            BusinessDayConvention::Following,
+            &WeekendsOnly,
+        )
+        .unwrap();
+
+        assert!(entries.last().unwrap().unadjusted() < NaiveDate::MAX);
+        #[cfg(feature = "alloc")]
+        assert_eq!(
+            Schedule::new(
+                start,
+                NaiveDate::MAX,
+                Period::Week,
+                BusinessDayConvention::Following,
+                &WeekendsOnly,
+            ),
+            None
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rejects_inverted_schedules() {
+        assert_eq!(
+            Schedule::new(
+                ymd(2024, 2, 1),
+                ymd(2024, 1, 1),
+                Period::Week,
+                BusinessDayConvention::Unadjusted,
+                &WeekendsOnly,
+            ),
+            None
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn serializes_to_json() {
+        let schedule = Schedule::new(
+            ymd(2024, 8, 31),
+            ymd(2024, 9, 10),
+            Period::Week,
+            BusinessDayConvention::Following,
+            &UsFederalHolidays,
+        )
+        .unwrap();
+
+        assert_eq!(
+            schedule.to_json(),
+            concat!(
+                r#"{"frequency":"week","convention":"following","entries":["#,
+                r#"{"index":0,"unadjusted":"2024-08-31","adjusted":"2024-09-03","is_adjusted":true,"is_stub":false},"#,
+                r#"{"index":1,"unadjusted":"2024-09-07","adjusted":"2024-09-09","is_adjusted":true,"is_stub":false},"#,
+                r#"{"index":2,"unadjusted":"2024-09-10","adjusted":"2024-09-10","is_adjusted":false,"is_stub":true}"#,
+                r#"]}"#
+            )
+        );
+    }
+}
@@ -0,0 +1,241 @@
+//! Seasons of the year, by meteorological or astronomical reckoning in either hemisphere.
+//!
+//! Meteorological seasons are whole months: spring in the northern hemisphere runs March
+//! through May. Astronomical seasons begin on the day of an equinox or solstice, reckoned in
+//! Universal Time from a solar position accurate to a few minutes for the years 1900 through
+//! 2100, so an equinox within minutes of midnight UTC may be assigned to the neighbouring day.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::season::{self, Definition, Hemisphere, Season};
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
+//!
+//! assert_eq!(
+//!     season::season(&date, Definition::Meteorological, Hemisphere::Northern),
+//!     Some(Season::Summer)
+//! );
+//! assert_eq!(
+//!     season::season(&date, Definition::Astronomical, Hemisphere::Northern),
+//!     Some(Season::Spring)
+//! );
+//! assert_eq!(
+//!     season::next_season(&date, Definition::Astronomical, Hemisphere::Southern),
+//!     NaiveDate::from_ymd_opt(2024, 6, 20)
+//! );
+//! ```
+
+use crate::astronomy::solar_longitude_after;
+use crate::day_number::{from_rata_die, to_rata_die};
+use chrono::prelude::*;
+use core::fmt;
+
+/// A season of the year.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Season {
+    /// Spring.
+    Spring,
+    /// Summer.
+    Summer,
+    /// Autumn, or fall.
+    Autumn,
+    /// Winter.
+    Winter,
+}
+
+/// Formats the season's name, e.g. `Autumn`.
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Season::Spring => "Spring",
+            Season::Summer => "Summer",
+            Season::Autumn => "Autumn",
+            Season::Winter => "Winter",
+        })
+    }
+}
+
+/// How season boundaries are determined.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Definition {
+    /// Seasons begin on the first of March, June, September, and December.
+    Meteorological,
+    /// Seasons begin on the days of the equinoxes and solstices.
+    Astronomical,
+}
+
+/// The hemisphere whose seasons are reckoned; the southern hemisphere's seasons are opposite
+/// the northern's.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Hemisphere {
+    /// North of the equator.
+    Northern,
+    /// South of the equator.
+    Southern,
+}
+
+/// Returns the season containing the provided date.
+pub fn season(date: &NaiveDate, definition: Definition, hemisphere: Hemisphere) -> Option<Season> {
+    Some(season_span(date, definition, hemisphere)?.0)
+}
+
+/// Returns the first day of the season containing the provided date.
+pub fn beginning_of_season(
+    date: &NaiveDate,
+    definition: Definition,
+    hemisphere: Hemisphere,
+) -> Option<NaiveDate> {
+    Some(season_span(date, definition, hemisphere)?.1)
+}
+
+/// Returns the last day of the season containing the provided date.
+pub fn end_of_season(
+    date: &NaiveDate,
+    definition: Definition,
+    hemisphere: Hemisphere,
+) -> Option<NaiveDate> {
+    next_season(date, definition, hemisphere)?.pred_opt()
+}
+
+/// Returns the first day of the next season.
+pub fn next_season(
+    date: &NaiveDate,
+    definition: Definition,
+    hemisphere: Hemisphere,
+) -> Option<NaiveDate> {
+    Some(season_span(date, definition, hemisphere)?.2)
+}
+
+/// Returns the first day of the previous season.
+pub fn previous_season(
+    date: &NaiveDate,
+    definition: Definition,
+    hemisphere: Hemisphere,
+) -> Option<NaiveDate> {
+    let beginning = beginning_of_season(date, definition, hemisphere)?;
+    beginning_of_season(&beginning.pred_opt()?, definition, hemisphere)
+}
+
+// The season containing the date, its first day, and the first day of the following season
+fn season_span(
+    date: &NaiveDate,
+    definition: Definition,
+    hemisphere: Hemisphere,
+) -> Option<(Season, NaiveDate, NaiveDate)> {
+    let mut starts = Vec::with_capacity(12);
+    for year in date.year() - 1..=date.year() + 1 {
+        starts.extend_from_slice(&season_starts(year, definition)?);
+    }
+
+    let index = starts.iter().rposition(|start| start <= date)?;
+    let next = *starts.get(index + 1)?;
+    let northern = [
+        Season::Spring,
+        Season::Summer,
+        Season::Autumn,
+        Season::Winter,
+    ][index % 4];
+
+    Some((in_hemisphere(northern, hemisphere), starts[index], next))
+}
+
+// The starts of northern spring, summer, autumn, and winter in the year
+fn season_starts(year: i32, definition: Definition) -> Option<[NaiveDate; 4]> {
+    match definition {
+        Definition::Meteorological => Some([
+            NaiveDate::from_ymd_opt(year, 3, 1)?,
+            NaiveDate::from_ymd_opt(year, 6, 1)?,
+            NaiveDate::from_ymd_opt(year, 9, 1)?,
+            NaiveDate::from_ymd_opt(year, 12, 1)?,
+        ]),
+        Definition::Astronomical => {
+            let new_year = to_rata_die(&NaiveDate::from_ymd_opt(year, 1, 1)?) as f64;
+            let day = |longitude: f64| {
+                from_rata_die(solar_longitude_after(longitude, new_year).floor() as i64)
+            };
+
+            Some([day(0.0)?, day(90.0)?, day(180.0)?, day(270.0)?])
+        }
+    }
+}
+
+fn in_hemisphere(northern: Season, hemisphere: Hemisphere) -> Season {
+    match (hemisphere, northern) {
+        (Hemisphere::Northern, season) => season,
+        (Hemisphere::Southern, Season::Spring) => Season::Autumn,
+        (Hemisphere::Southern, Season::Summer) => Season::Winter,
+        (Hemisphere::Southern, Season::Autumn) => Season::Spring,
+        (Hemisphere::Southern, Season::Winter) => Season::Summer,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Definition::*;
+    use Hemisphere::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn computes_meteorological_seasons() {
+        let date = ymd(2024, 1, 15);
+
+        assert_eq!(
+            season(&date, Meteorological, Northern),
+            Some(Season::Winter)
+        );
+        assert_eq!(
+            season(&date, Meteorological, Southern),
+            Some(Season::Summer)
+        );
+        assert_eq!(
+            beginning_of_season(&date, Meteorological, Northern),
+            Some(ymd(2023, 12, 1))
+        );
+        assert_eq!(
+            end_of_season(&date, Meteorological, Northern),
+            Some(ymd(2024, 2, 29))
+        );
+        assert_eq!(
+            previous_season(&date, Meteorological, Southern),
+            Some(ymd(2023, 9, 1))
+        );
+    }
+
+    #[test]
+    fn computes_astronomical_seasons() {
+        // Published UTC equinoxes and solstices
+        for &(year, month, day) in &[
+            (2024, 3, 20),
+            (2024, 6, 20),
+            (2024, 9, 22),
+            (2024, 12, 21),
+            (2025, 3, 20),
+            (2025, 6, 21),
+            (2025, 9, 22),
+            (2025, 12, 21),
+        ] {
+            let date = ymd(year, month, day);
+            assert_eq!(
+                beginning_of_season(&date, Astronomical, Northern),
+                Some(date)
+            );
+        }
+
+        let date = ymd(2024, 12, 20);
+        assert_eq!(season(&date, Astronomical, Northern), Some(Season::Autumn));
+        assert_eq!(season(&date, Astronomical, Southern), Some(Season::Spring));
+        assert_eq!(
+            next_season(&date, Astronomical, Northern),
+            Some(ymd(2024, 12, 21))
+        );
+        assert_eq!(
+            end_of_season(&ymd(2024, 12, 21), Astronomical, Northern),
+            Some(ymd(2025, 3, 19))
+        );
+        assert_eq!(Season::Autumn.to_string(), "Autumn");
+    }
+}
@@ -0,0 +1,170 @@
+//! Due dates of the annual (10-K) and quarterly (10-Q) reports United States public companies file
+//! with the SEC, enabled with the `alloc` feature.
+//!
+//! A report is due a number of days after the end of the fiscal period it covers, depending on
+//! the company's [`FilerCategory`], and a due date falling on a weekend or holiday moves to the
+//! following business day.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::business::UsFederalHolidays;
+//! use date_calculations::fiscal::{FiscalCalendar, FiscalYearLabel};
+//! use date_calculations::sec::*;
+//!
+//! let calendar = FiscalCalendar::new(1, FiscalYearLabel::StartYear).unwrap();
+//! let category = FilerCategory::LargeAccelerated;
+//!
+//! // 60 days after December 31 is Saturday, March 1
+//! assert_eq!(
+//!     annual_report_deadline(&calendar, 2024, category, &UsFederalHolidays),
+//!     NaiveDate::from_ymd_opt(2025, 3, 3)
+//! );
+//! assert_eq!(
+//!     quarterly_report_deadline(&calendar, 2024, 1, category, &UsFederalHolidays),
+//!     NaiveDate::from_ymd_opt(2024, 5, 10)
+//! );
+//! ```
+
+use crate::business::{adjust, BusinessDayConvention, HolidayCalendar};
+use crate::fiscal::{FiscalCalendar, FiscalLabel};
+use chrono::prelude::*;
+use chrono::Days;
+
+/// The category of an SEC filer, determining how long it has to file its reports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FilerCategory {
+    /// A large accelerated filer, with a public float of $700 million or more.
+    LargeAccelerated,
+    /// An accelerated filer, with a public float between $75 million and $700 million.
+    Accelerated,
+    /// Any other filer.
+    NonAccelerated,
+}
+
+impl FilerCategory {
+    /// Returns the number of days after the fiscal year's end the annual report is due: 60, 75,
+    /// or 90.
+    pub fn annual_report_days(self) -> u32 {
+        match self {
+            FilerCategory::LargeAccelerated => 60,
+            FilerCategory::Accelerated => 75,
+            FilerCategory::NonAccelerated => 90,
+        }
+    }
+
+    /// Returns the number of days after a fiscal quarter's end the quarterly report is due: 40
+    /// for accelerated filers, large or not, and 45 otherwise.
+    pub fn quarterly_report_days(self) -> u32 {
+        match self {
+            FilerCategory::LargeAccelerated | FilerCategory::Accelerated => 40,
+            FilerCategory::NonAccelerated => 45,
+        }
+    }
+}
+
+/// Returns the due date of the annual report (10-K) for the labeled fiscal year, moved onto the
+/// following business day of the calendar.
+///
+/// Returns `None` if the due date falls outside chrono's range.
+pub fn annual_report_deadline<C: HolidayCalendar + ?Sized>(
+    fiscal: &FiscalCalendar,
+    fiscal_year: i32,
+    category: FilerCategory,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    deadline(
+        fiscal,
+        &FiscalLabel::Year(fiscal_year),
+        category.annual_report_days(),
+        calendar,
+    )
+}
+
+/// Returns the due date of the quarterly report (10-Q) for a fiscal quarter of the labeled fiscal
+/// year, moved onto the following business day of the calendar.
+///
+/// Returns `None` for the fourth quarter, which the annual report covers instead, for quarters
+/// outside 1 through 3, or if the due date falls outside chrono's range.
+pub fn quarterly_report_deadline<C: HolidayCalendar + ?Sized>(
+    fiscal: &FiscalCalendar,
+    fiscal_year: i32,
+    quarter: u32,
+    category: FilerCategory,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    if !(1..=3).contains(&quarter) {
+        return None;
+    }
+
+    deadline(
+        fiscal,
+        &FiscalLabel::Quarter(fiscal_year, quarter),
+        category.quarterly_report_days(),
+        calendar,
+    )
+}
+
+fn deadline<C: HolidayCalendar + ?Sized>(
+    fiscal: &FiscalCalendar,
+    label: &FiscalLabel,
+    days: u32,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    let due = fiscal
+        .range(label)?
+        .end()
+        .checked_add_days(Days::new(days.into()))?;
+
+    adjust(&due, BusinessDayConvention::Following, calendar)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::business::UsFederalHolidays;
+    use crate::fiscal::FiscalYearLabel;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn files_annual_reports_by_category() {
+        let june = FiscalCalendar::new(7, FiscalYearLabel::EndYear).unwrap();
+        let deadline = |category| annual_report_deadline(&june, 2024, category, &UsFederalHolidays);
+
+        assert_eq!(
+            deadline(FilerCategory::LargeAccelerated),
+            Some(ymd(2024, 8, 29))
+        );
+        assert_eq!(deadline(FilerCategory::Accelerated), Some(ymd(2024, 9, 13)));
+        assert_eq!(
+            deadline(FilerCategory::NonAccelerated),
+            Some(ymd(2024, 9, 30))
+        );
+    }
+
+    #[test]
+    fn files_quarterly_reports_for_the_first_three_quarters() {
+        let calendar = FiscalCalendar::new(1, FiscalYearLabel::StartYear).unwrap();
+        let deadline = |quarter, category| {
+            quarterly_report_deadline(&calendar, 2024, quarter, category, &UsFederalHolidays)
+        };
+
+        // 40 days after September 30 is a Saturday, followed by Veterans Day
+        assert_eq!(
+            deadline(3, FilerCategory::Accelerated),
+            Some(ymd(2024, 11, 12))
+        );
+        assert_eq!(
+            deadline(3, FilerCategory::NonAccelerated),
+            Some(ymd(2024, 11, 14))
+        );
+        assert_eq!(
+            deadline(2, FilerCategory::LargeAccelerated),
+            Some(ymd(2024, 8, 9))
+        );
+        assert_eq!(deadline(4, FilerCategory::NonAccelerated), None);
+        assert_eq!(deadline(0, FilerCategory::NonAccelerated), None);
+    }
+}
@@ -0,0 +1,144 @@
+//! A terse, deterministic syntax for dates relative to a reference date, suited to configuration
+//! files.
+//!
+//! An expression is an optional anchor followed by any number of signed offsets:
+//!
+//! * anchors: `today` (or `t`), `bow`/`eow`, `bom`/`eom`, `boq`/`eoq`, and `boy`/`eoy` for the
+//!   beginning and end of the current week, month, quarter, and year
+//! * offsets: `+` or `-`, a count, and a unit of `d`, `w`, `m`, `q`, or `y`
+//!
+//! Offsets are applied left to right to the anchored date. Month, quarter, and year offsets keep
+//! the day of month, clamping to the end of shorter months, so `eom+1m` evaluated in January is
+//! the last day of February but evaluated in April is May 30.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::shorthand::evaluate;
+//!
+//! let reference = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+//!
+//! assert_eq!(evaluate("boq+1d", &reference).unwrap().day(), 2);
+//! assert_eq!(
+//!     evaluate("eoy-2w", &reference).unwrap(),
+//!     NaiveDate::from_ymd_opt(2024, 12, 17).unwrap()
+//! );
+//! ```
+
+use crate::duration::shift_months;
+use crate::{MonthOverflow, ParseError, Period};
+use alloc::string::String;
+use chrono::prelude::*;
+
+const EXPECTED: &str = "a date expression like \"eom\", \"boq+1d\", or \"eoy-2w\"";
+
+/// Evaluates a shorthand date expression against the reference date.
+///
+/// Whitespace is ignored and anchors and units are case-insensitive.
+pub fn evaluate(input: &str, reference: &NaiveDate) -> Result<NaiveDate, ParseError> {
+    let error = || ParseError::new(input, EXPECTED);
+    let expression: String = input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase();
+
+    let anchor_length = expression.find(['+', '-']).unwrap_or(expression.len());
+    let (anchor, mut offsets) = expression.split_at(anchor_length);
+
+    if anchor.is_empty() && offsets.is_empty() {
+        return Err(error());
+    }
+
+    let mut date = resolve_anchor(anchor, reference).ok_or_else(error)?;
+
+    while !offsets.is_empty() {
+        let sign = if offsets.starts_with('-') { -1 } else { 1 };
+        let rest = &offsets[1..];
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).ok_or_else(error)?;
+        let count: i64 = rest[..digits].parse().map_err(|_| error())?;
+        let unit = rest[digits..].chars().next().ok_or_else(error)?;
+
+        date = apply_offset(&date, sign * count, unit).ok_or_else(error)?;
+        offsets = &rest[digits + unit.len_utf8()..];
+    }
+
+    Ok(date)
+}
+
+fn resolve_anchor(anchor: &str, reference: &NaiveDate) -> Option<NaiveDate> {
+    match anchor {
+        "" | "t" | "today" => Some(*reference),
+        "bow" => Period::Week.beginning(reference),
+        "eow" => Period::Week.end(reference),
+        "bom" => Period::Month.beginning(reference),
+        "eom" => Period::Month.end(reference),
+        "boq" => Period::Quarter.beginning(reference),
+        "eoq" => Period::Quarter.end(reference),
+        "boy" => Period::Year.beginning(reference),
+        "eoy" => Period::Year.end(reference),
+        _ => None,
+    }
+}
+
+fn apply_offset(date: &NaiveDate, count: i64, unit: char) -> Option<NaiveDate> {
+    match unit {
+        'd' => date.checked_add_signed(chrono::Duration::try_days(count)?),
+        'w' => date.checked_add_signed(chrono::Duration::try_weeks(count)?),
+        'm' => shift_months(date, count, MonthOverflow::Clamp),
+        'q' => shift_months(date, count.checked_mul(3)?, MonthOverflow::Clamp),
+        'y' => shift_months(date, count.checked_mul(12)?, MonthOverflow::Clamp),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn evaluates_anchors() {
+        let reference = ymd(2024, 5, 17);
+
+        assert_eq!(evaluate("today", &reference), Ok(reference));
+        assert_eq!(evaluate("bow", &reference), Ok(ymd(2024, 5, 12)));
+        assert_eq!(evaluate("eom", &reference), Ok(ymd(2024, 5, 31)));
+        assert_eq!(evaluate("EOQ", &reference), Ok(ymd(2024, 6, 30)));
+        assert_eq!(evaluate("boy", &reference), Ok(ymd(2024, 1, 1)));
+    }
+
+    #[test]
+    fn evaluates_offsets() {
+        let reference = ymd(2024, 5, 17);
+
+        assert_eq!(evaluate("boq+1d", &reference), Ok(ymd(2024, 4, 2)));
+        assert_eq!(evaluate("eoy-2w", &reference), Ok(ymd(2024, 12, 17)));
+        assert_eq!(evaluate("bom+3m", &reference), Ok(ymd(2024, 8, 1)));
+        assert_eq!(evaluate("+10d", &reference), Ok(ymd(2024, 5, 27)));
+        assert_eq!(evaluate("bom - 1d + 1y", &reference), Ok(ymd(2025, 4, 30)));
+        assert_eq!(evaluate("eom-3m", &ymd(2024, 5, 31)), Ok(ymd(2024, 2, 29)));
+    }
+
+    #[test]
+    fn rejects_invalid_expressions() {
+        let reference = ymd(2024, 5, 17);
+
+        for input in &[
+            "",
+            "eod",
+            "bom+",
+            "bom+1",
+            "bom+d",
+            "bom+1x",
+            "bom++1d",
+            "+1000000000000d",
+            "+9223372036854775807w",
+            "-9223372036854775807d",
+        ] {
+            assert!(evaluate(input, &reference).is_err(), "{}", input);
+        }
+    }
+}
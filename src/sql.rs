@@ -0,0 +1,158 @@
+//! Generation of SQL predicates and truncation expressions that agree with this crate's period
+//! boundaries, including its Sunday-based weeks.
+//!
+//! Column names are inserted verbatim and must be trusted identifiers; dates are rendered as
+//! `'YYYY-MM-DD'` literals.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::sql::{period_predicate, truncate, Dialect};
+//! use date_calculations::Period;
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+//!
+//! assert_eq!(
+//!     period_predicate("created_on", Period::Quarter, &date).unwrap(),
+//!     "created_on >= '2024-04-01' AND created_on < '2024-07-01'"
+//! );
+//! assert_eq!(
+//!     truncate("created_on", Period::Month, Dialect::Postgres),
+//!     "CAST(date_trunc('month', created_on) AS date)"
+//! );
+//! ```
+
+use crate::{DateRange, Period};
+use alloc::{format, string::String};
+use chrono::prelude::*;
+
+/// A SQL dialect for generated expressions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    /// PostgreSQL.
+    Postgres,
+    /// MySQL and MariaDB.
+    MySql,
+    /// SQLite, with dates stored as `YYYY-MM-DD` text.
+    Sqlite,
+}
+
+/// Returns a half-open predicate selecting rows whose column falls within the period containing
+/// the provided date.
+///
+/// The predicate compares against the first day of the following period, so it behaves the same
+/// for `date` and timestamp columns.
+pub fn period_predicate(column: &str, period: Period, date: &NaiveDate) -> Option<String> {
+    Some(predicate(
+        column,
+        &period.beginning(date)?,
+        &period.next(date)?,
+    ))
+}
+
+/// Returns a half-open predicate selecting rows whose column falls within the inclusive range.
+pub fn range_predicate(column: &str, range: &DateRange) -> Option<String> {
+    Some(predicate(column, &range.start(), &range.end().succ_opt()?))
+}
+
+/// Returns an expression truncating the column to the beginning of its period, as a date.
+///
+/// Weeks begin on Sunday, matching [`crate::beginning_of_week`] rather than the ISO weeks used by
+/// PostgreSQL's `date_trunc('week', …)`.
+pub fn truncate(column: &str, period: Period, dialect: Dialect) -> String {
+    match (dialect, period) {
+        (Dialect::Postgres, Period::Week) => format!(
+            "CAST(date_trunc('week', {c} + interval '1 day') - interval '1 day' AS date)",
+            c = column
+        ),
+        (Dialect::Postgres, period) => {
+            format!("CAST(date_trunc('{}', {}) AS date)", period, column)
+        }
+        (Dialect::MySql, Period::Week) => {
+            format!("DATE_SUB(DATE({c}), INTERVAL DAYOFWEEK({c}) - 1 DAY)", c = column)
+        }
+        (Dialect::MySql, Period::Month) => {
+            format!("DATE_SUB(DATE({c}), INTERVAL DAYOFMONTH({c}) - 1 DAY)", c = column)
+        }
+        (Dialect::MySql, Period::Quarter) => format!(
+            "MAKEDATE(YEAR({c}), 1) + INTERVAL (QUARTER({c}) - 1) * 3 MONTH",
+            c = column
+        ),
+        (Dialect::MySql, Period::Year) => format!("MAKEDATE(YEAR({}), 1)", column),
+        (Dialect::Sqlite, Period::Week) => format!(
+            "date({c}, '-' || strftime('%w', {c}) || ' days')",
+            c = column
+        ),
+        (Dialect::Sqlite, Period::Month) => format!("date({}, 'start of month')", column),
+        (Dialect::Sqlite, Period::Quarter) => format!(
+            "date({c}, 'start of month', '-' || ((CAST(strftime('%m', {c}) AS integer) - 1) % 3) || ' months')",
+            c = column
+        ),
+        (Dialect::Sqlite, Period::Year) => format!("date({}, 'start of year')", column),
+    }
+}
+
+fn predicate(column: &str, start: &NaiveDate, exclusive_end: &NaiveDate) -> String {
+    format!(
+        "{c} >= '{}' AND {c} < '{}'",
+        start,
+        exclusive_end,
+        c = column
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn builds_period_predicates() {
+        let date = ymd(2024, 12, 17);
+
+        assert_eq!(
+            period_predicate("d", Period::Week, &date).unwrap(),
+            "d >= '2024-12-15' AND d < '2024-12-22'"
+        );
+        assert_eq!(
+            period_predicate("d", Period::Year, &date).unwrap(),
+            "d >= '2024-01-01' AND d < '2025-01-01'"
+        );
+    }
+
+    #[test]
+    fn builds_range_predicates() {
+        let range = DateRange::new(ymd(2024, 1, 1), ymd(2024, 1, 31)).unwrap();
+
+        assert_eq!(
+            range_predicate("d", &range).unwrap(),
+            "d >= '2024-01-01' AND d < '2024-02-01'"
+        );
+    }
+
+    #[test]
+    fn truncates_per_dialect() {
+        assert_eq!(
+            truncate("d", Period::Week, Dialect::Postgres),
+            "CAST(date_trunc('week', d + interval '1 day') - interval '1 day' AS date)"
+        );
+        assert_eq!(
+            truncate("d", Period::Quarter, Dialect::Postgres),
+            "CAST(date_trunc('quarter', d) AS date)"
+        );
+        assert_eq!(
+            truncate("d", Period::Year, Dialect::MySql),
+            "MAKEDATE(YEAR(d), 1)"
+        );
+        assert_eq!(
+            truncate("d", Period::Month, Dialect::Sqlite),
+            "date(d, 'start of month')"
+        );
+        assert_eq!(
+            truncate("d", Period::Week, Dialect::Sqlite),
+            "date(d, '-' || strftime('%w', d) || ' days')"
+        );
+    }
+}
@@ -0,0 +1,195 @@
+//! Payment terms and the due dates they give invoices.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::business::{BusinessDayConvention, UsFederalHolidays};
+//! use date_calculations::terms::{due_date, Terms};
+//!
+//! let invoice = NaiveDate::from_ymd_opt(2024, 10, 18).unwrap();
+//! let terms: Terms = "EOM+15".parse().unwrap();
+//!
+//! assert_eq!(terms.due_date(&invoice), NaiveDate::from_ymd_opt(2024, 11, 15));
+//! assert_eq!(
+//!     due_date(&invoice, Terms::Net(30), BusinessDayConvention::Following, &UsFederalHolidays),
+//!     NaiveDate::from_ymd_opt(2024, 11, 18)
+//! );
+//! ```
+
+use crate::business::{adjust, BusinessDayConvention, HolidayCalendar};
+use crate::{end_of_month, ParseError};
+use chrono::prelude::*;
+use chrono::Days;
+use core::fmt;
+use core::str::FromStr;
+
+const EXPECTED: &str = "payment terms like \"Net 30\", \"EOM\", or \"EOM+15\"";
+
+/// When an invoice falls due, relative to its date.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Terms {
+    /// Due a number of days after the invoice date, as in Net 30.
+    Net(u32),
+    /// Due a number of days after the end of the invoice's month, as in EOM+15; `EndOfMonth(0)`
+    /// is due on the last day of the month.
+    EndOfMonth(u32),
+}
+
+impl Terms {
+    /// Returns the date an invoice dated `invoice` falls due, before any business-day
+    /// adjustment.
+    ///
+    /// Returns `None` if the due date is after `NaiveDate::MAX`.
+    pub fn due_date(&self, invoice: &NaiveDate) -> Option<NaiveDate> {
+        match *self {
+            Terms::Net(days) => invoice.checked_add_days(Days::new(days.into())),
+            Terms::EndOfMonth(days) => {
+                end_of_month(invoice).checked_add_days(Days::new(days.into()))
+            }
+        }
+    }
+}
+
+/// Returns the date an invoice dated `invoice` falls due under the terms, moved onto a business
+/// day by the convention and holiday calendar.
+///
+/// Returns `None` if the due date cannot be represented.
+pub fn due_date<C: HolidayCalendar + ?Sized>(
+    invoice: &NaiveDate,
+    terms: Terms,
+    convention: BusinessDayConvention,
+    calendar: &C,
+) -> Option<NaiveDate> {
+    adjust(&terms.due_date(invoice)?, convention, calendar)
+}
+
+/// Formats the terms as `Net 30`, `EOM`, or `EOM+15`.
+impl fmt::Display for Terms {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Terms::Net(days) => write!(f, "Net {}", days),
+            Terms::EndOfMonth(0) => write!(f, "EOM"),
+            Terms::EndOfMonth(days) => write!(f, "EOM+{}", days),
+        }
+    }
+}
+
+/// Parses terms like `Net 30`, `net30`, `EOM`, or `EOM+15`, ignoring case and surrounding
+/// spaces.
+impl FromStr for Terms {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || ParseError::new(input, EXPECTED);
+        let days = |digits: &str| {
+            let digits = digits.trim();
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(error());
+            }
+            digits.parse().map_err(|_| error())
+        };
+        let normalized = input.trim();
+
+        if let Some(rest) = strip_prefix_ignoring_case(normalized, "NET") {
+            Ok(Terms::Net(days(rest)?))
+        } else if let Some(rest) = strip_prefix_ignoring_case(normalized, "EOM") {
+            match rest.trim_start().strip_prefix('+') {
+                Some(digits) => Ok(Terms::EndOfMonth(days(digits)?)),
+                None if rest.trim().is_empty() => Ok(Terms::EndOfMonth(0)),
+                None => Err(error()),
+            }
+        } else {
+            Err(error())
+        }
+    }
+}
+
+fn strip_prefix_ignoring_case<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = input.get(..prefix.len())?;
+
+    if head.eq_ignore_ascii_case(prefix) {
+        Some(&input[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::business::WeekendsOnly;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn computes_due_dates() {
+        let invoice = ymd(2024, 1, 31);
+
+        assert_eq!(Terms::Net(30).due_date(&invoice), Some(ymd(2024, 3, 1)));
+        assert_eq!(Terms::EndOfMonth(0).due_date(&invoice), Some(invoice));
+        assert_eq!(
+            Terms::EndOfMonth(15).due_date(&ymd(2024, 2, 2)),
+            Some(ymd(2024, 3, 15))
+        );
+        assert_eq!(Terms::Net(1).due_date(&NaiveDate::MAX), None);
+    }
+
+    #[test]
+    fn rolls_due_dates_onto_business_days() {
+        // Net 60 from 2024-08-02 falls on Tuesday, October 1; EOM+1 on Sunday, September 1
+        let invoice = ymd(2024, 8, 2);
+
+        assert_eq!(
+            due_date(
+                &invoice,
+                Terms::Net(60),
+                BusinessDayConvention::Following,
+                &WeekendsOnly
+            ),
+            Some(ymd(2024, 10, 1))
+        );
+        assert_eq!(
+            due_date(
+                &invoice,
+                Terms::EndOfMonth(1),
+                BusinessDayConvention::Following,
+                &WeekendsOnly
+            ),
+            Some(ymd(2024, 9, 2))
+        );
+        assert_eq!(
+            due_date(
+                &invoice,
+                Terms::EndOfMonth(1),
+                BusinessDayConvention::ModifiedFollowing,
+                &WeekendsOnly
+            ),
+            Some(ymd(2024, 9, 2))
+        );
+        assert_eq!(
+            due_date(
+                &invoice,
+                Terms::EndOfMonth(1),
+                BusinessDayConvention::Preceding,
+                &WeekendsOnly
+            ),
+            Some(ymd(2024, 8, 30))
+        );
+    }
+
+    #[test]
+    fn parses_and_displays_terms() {
+        assert_eq!("Net 30".parse(), Ok(Terms::Net(30)));
+        assert_eq!("net60".parse(), Ok(Terms::Net(60)));
+        assert_eq!(" eom ".parse(), Ok(Terms::EndOfMonth(0)));
+        assert_eq!("EOM + 15".parse(), Ok(Terms::EndOfMonth(15)));
+
+        for input in &["", "Net", "Net +5", "Net -5", "EOM15", "EOM+", "Due 30"] {
+            assert!(input.parse::<Terms>().is_err(), "{}", input);
+        }
+        for input in &["Net 30", "EOM", "EOM+15"] {
+            assert_eq!(input.parse::<Terms>().unwrap().to_string(), *input);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Quickcheck generators for valid Gregorian dates, enabled with the `testing` feature.
+//!
+//! These are the generators this crate uses to validate its own functions, so downstream
+//! property tests can exercise their date handling with the same distribution.
+//!
+//! ```
+//! use date_calculations::testing::ArbitraryDate;
+//! use date_calculations::end_of_month;
+//! use quickcheck::quickcheck;
+//!
+//! fn end_of_month_is_idempotent(date: ArbitraryDate) -> bool {
+//!     let end = end_of_month(&date.0);
+//!     end_of_month(&end) == end
+//! }
+//!
+//! quickcheck(end_of_month_is_idempotent as fn(ArbitraryDate) -> bool);
+//! ```
+
+use crate::end_of_month;
+use chrono::prelude::*;
+use quickcheck::{Arbitrary, Gen};
+
+/// Generates Gregorian dates within a range of years.
+///
+/// Days of the month are drawn from 1 through 31 and clamped to the last day of shorter months,
+/// which already favors month ends; an additional end-of-month bias can force a percentage of
+/// dates onto the last day of their month.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateGenerator {
+    min_year: i32,
+    max_year: i32,
+    end_of_month_bias: u32,
+}
+
+impl DateGenerator {
+    /// Builds a generator for dates from January 1 of `min_year` through December 31 of
+    /// `max_year`.
+    ///
+    /// Returns `None` if `max_year` precedes `min_year` or either year cannot be represented.
+    pub fn new(min_year: i32, max_year: i32) -> Option<Self> {
+        NaiveDate::from_ymd_opt(min_year, 1, 1)?;
+        NaiveDate::from_ymd_opt(max_year, 12, 31)?;
+
+        if max_year < min_year {
+            None
+        } else {
+            Some(DateGenerator {
+                min_year,
+                max_year,
+                end_of_month_bias: 0,
+            })
+        }
+    }
+
+    /// Forces the given percentage (0 through 100) of generated dates onto the last day of their
+    /// month.
+    pub fn with_end_of_month_bias(self, percent: u32) -> Self {
+        DateGenerator {
+            end_of_month_bias: percent.min(100),
+            ..self
+        }
+    }
+
+    /// Generates a date.
+    pub fn generate<G: Gen>(&self, g: &mut G) -> NaiveDate {
+        let span = (i64::from(self.max_year) - i64::from(self.min_year) + 1) as u64;
+        let year = self.min_year + (g.next_u64() % span) as i32;
+        let month = 1 + g.next_u32() % 12;
+        let first = NaiveDate::from_ymd_opt(year, month, 1).expect("year is within range");
+        let last_day = end_of_month(&first).day();
+
+        let day = if g.next_u32() % 100 < self.end_of_month_bias {
+            last_day
+        } else {
+            (1 + g.next_u32() % 31).min(last_day)
+        };
+
+        first.with_day(day).unwrap_or(first)
+    }
+}
+
+/// Generates dates from 1584 through 2800, the default range for this crate's property tests.
+impl Default for DateGenerator {
+    fn default() -> Self {
+        DateGenerator {
+            min_year: 1584,
+            max_year: 2800,
+            end_of_month_bias: 0,
+        }
+    }
+}
+
+/// A date generated by the default [`DateGenerator`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ArbitraryDate(pub NaiveDate);
+
+impl Arbitrary for ArbitraryDate {
+    fn arbitrary<G: Gen>(g: &mut G) -> ArbitraryDate {
+        ArbitraryDate(DateGenerator::default().generate(g))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::StdThreadGen;
+
+    #[test]
+    fn generates_within_the_year_range() {
+        let generator = DateGenerator::new(1999, 2001).unwrap();
+        let mut g = StdThreadGen::new(100);
+
+        for _ in 0..1000 {
+            let year = generator.generate(&mut g).year();
+            assert!((1999..=2001).contains(&year));
+        }
+    }
+
+    #[test]
+    fn applies_end_of_month_bias() {
+        let generator = DateGenerator::default().with_end_of_month_bias(100);
+        let mut g = StdThreadGen::new(100);
+
+        for _ in 0..1000 {
+            let date = generator.generate(&mut g);
+            assert_eq!(end_of_month(&date), date);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_ranges() {
+        assert_eq!(DateGenerator::new(2001, 1999), None);
+        assert_eq!(DateGenerator::new(1, i32::MAX), None);
+    }
+}
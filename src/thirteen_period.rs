@@ -0,0 +1,381 @@
+//! Accounting calendars of thirteen four-week periods.
+//!
+//! Each year begins on a fixed weekday anchored to a calendar date, so years run 52 weeks, or 53
+//! when the anchor drifts far enough; a [`LeapWeek`] policy decides which period absorbs the
+//! extra week. Years are named after the calendar year of their anchor date.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::thirteen_period::*;
+//!
+//! // Years begin on the Sunday nearest January 1
+//! let calendar =
+//!     ThirteenPeriodCalendar::new(1, 1, Weekday::Sun, Anchor::Nearest, LeapWeek::LastPeriod)
+//!         .unwrap();
+//! let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+//!
+//! assert_eq!(calendar.year(&date), 2024);
+//! assert_eq!(calendar.period(&date), 5);
+//! assert_eq!(calendar.beginning_of_period(&date), NaiveDate::from_ymd_opt(2024, 4, 21));
+//! assert_eq!(calendar.end_of_period(&date), NaiveDate::from_ymd_opt(2024, 5, 18));
+//! ```
+
+use crate::DateRange;
+use chrono::prelude::*;
+use chrono::Duration;
+
+/// How a year's first day is chosen relative to its anchor date.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Anchor {
+    /// The weekday on or before the anchor date.
+    OnOrBefore,
+    /// The weekday on or after the anchor date.
+    OnOrAfter,
+    /// The weekday nearest to the anchor date, up to three days either side.
+    Nearest,
+}
+
+/// Which period absorbs the extra week of a 53-week year.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LeapWeek {
+    /// The first period runs five weeks.
+    FirstPeriod,
+    /// The thirteenth period runs five weeks.
+    LastPeriod,
+}
+
+/// A calendar of thirteen four-week periods per year.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ThirteenPeriodCalendar {
+    month: u32,
+    day: u32,
+    weekday: Weekday,
+    anchor: Anchor,
+    leap_week: LeapWeek,
+}
+
+impl ThirteenPeriodCalendar {
+    /// Builds a calendar whose years begin on `weekday`, placed relative to `month` and `day`
+    /// of each year by `anchor`. Returns `None` if the month and day do not fall in every year.
+    pub fn new(
+        month: u32,
+        day: u32,
+        weekday: Weekday,
+        anchor: Anchor,
+        leap_week: LeapWeek,
+    ) -> Option<Self> {
+        NaiveDate::from_ymd_opt(2001, month, day).map(|_| ThirteenPeriodCalendar {
+            month,
+            day,
+            weekday,
+            anchor,
+            leap_week,
+        })
+    }
+
+    /// Returns the year containing the provided date.
+    pub fn year(&self, date: &NaiveDate) -> i32 {
+        let year = date.year();
+
+        if matches!(self.start_of_year(year), Some(start) if start > *date) {
+            year - 1
+        } else if matches!(self.start_of_year(year + 1), Some(start) if start <= *date) {
+            year + 1
+        } else {
+            year
+        }
+    }
+
+    /// Returns the period (1 through 13) containing the provided date.
+    pub fn period(&self, date: &NaiveDate) -> u32 {
+        let weeks = self.start_of_year(self.year(date)).map_or(0, |start| {
+            (date.signed_duration_since(start).num_days() / 7) as u32
+        });
+
+        match self.leap_week {
+            LeapWeek::FirstPeriod if self.is_leap_year(self.year(date)) => {
+                1 + weeks.saturating_sub(1) / 4
+            }
+            _ => 1 + (weeks / 4).min(12),
+        }
+    }
+
+    /// Returns whether the year runs 53 weeks.
+    pub fn is_leap_year(&self, year: i32) -> bool {
+        let next = year
+            .checked_add(1)
+            .and_then(|next| self.start_of_year(next));
+
+        match (self.start_of_year(year), next) {
+            (Some(start), Some(end)) => end.signed_duration_since(start).num_days() == 371,
+            _ => false,
+        }
+    }
+
+    /// Returns the range of dates within the period of the year, or `None` if the period is not
+    /// between 1 and 13.
+    pub fn period_range(&self, year: i32, period: u32) -> Option<DateRange> {
+        if !(1..=13).contains(&period) {
+            return None;
+        }
+
+        let start = self.start_of_year(year)?;
+        let extra = match self.leap_week {
+            LeapWeek::FirstPeriod if self.is_leap_year(year) => 1,
+            _ => 0,
+        };
+        let weeks = |period: u32| i64::from(4 * (period - 1) + if period > 1 { extra } else { 0 });
+
+        let first = start.checked_add_signed(Duration::weeks(weeks(period)))?;
+        let last = if period == 13 {
+            self.start_of_year(year.checked_add(1)?)?.pred_opt()?
+        } else {
+            start
+                .checked_add_signed(Duration::weeks(weeks(period + 1)))?
+                .pred_opt()?
+        };
+
+        DateRange::new(first, last)
+    }
+
+    /// Returns the week (1 through 52, or 53 in a leap year) containing the provided date.
+    ///
+    /// Years begin on the calendar's weekday, so every week is whole; retail reports keyed by
+    /// "week 37" count these weeks.
+    pub fn week(&self, date: &NaiveDate) -> u32 {
+        let weeks = self.start_of_year(self.year(date)).map_or(0, |start| {
+            (date.signed_duration_since(start).num_days() / 7) as u32
+        });
+
+        1 + weeks
+    }
+
+    /// Returns the range of dates within the week of the year, or `None` if the year has no such
+    /// week.
+    pub fn week_range(&self, year: i32, week: u32) -> Option<DateRange> {
+        let weeks = if self.is_leap_year(year) { 53 } else { 52 };
+        if !(1..=weeks).contains(&week) {
+            return None;
+        }
+
+        let first = self
+            .start_of_year(year)?
+            .checked_add_signed(Duration::weeks(i64::from(week - 1)))?;
+        DateRange::new(first, first.checked_add_signed(Duration::days(6))?)
+    }
+
+    /// Returns the first day of the year containing the provided date.
+    pub fn beginning_of_year(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        self.start_of_year(self.year(date))
+    }
+
+    /// Returns the last day of the year containing the provided date.
+    pub fn end_of_year(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        self.next_year(date)?.pred_opt()
+    }
+
+    /// Returns the first day of the next year.
+    pub fn next_year(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        self.start_of_year(self.year(date) + 1)
+    }
+
+    /// Returns the first day of the previous year.
+    pub fn previous_year(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        self.start_of_year(self.year(date) - 1)
+    }
+
+    /// Returns the first day of the period containing the provided date.
+    pub fn beginning_of_period(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        Some(
+            self.period_range(self.year(date), self.period(date))?
+                .start(),
+        )
+    }
+
+    /// Returns the last day of the period containing the provided date.
+    pub fn end_of_period(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        Some(self.period_range(self.year(date), self.period(date))?.end())
+    }
+
+    /// Returns the first day of the next period.
+    pub fn next_period(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        self.end_of_period(date)?.succ_opt()
+    }
+
+    /// Returns the first day of the previous period.
+    pub fn previous_period(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        self.beginning_of_period(&self.beginning_of_period(date)?.pred_opt()?)
+    }
+
+    fn start_of_year(&self, year: i32) -> Option<NaiveDate> {
+        let anchor = NaiveDate::from_ymd_opt(year, self.month, self.day)?;
+        let back =
+            (7 + anchor.weekday().num_days_from_monday() - self.weekday.num_days_from_monday()) % 7;
+        let forward = (7 - back) % 7;
+
+        let offset = match self.anchor {
+            Anchor::OnOrBefore => -i64::from(back),
+            Anchor::OnOrAfter => i64::from(forward),
+            Anchor::Nearest if back <= 3 => -i64::from(back),
+            Anchor::Nearest => i64::from(forward),
+        };
+
+        anchor.checked_add_signed(Duration::days(offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn nearest_sunday(leap_week: LeapWeek) -> ThirteenPeriodCalendar {
+        ThirteenPeriodCalendar::new(1, 1, Weekday::Sun, Anchor::Nearest, leap_week).unwrap()
+    }
+
+    #[test]
+    fn anchors_years() {
+        let calendar = nearest_sunday(LeapWeek::LastPeriod);
+
+        assert_eq!(
+            calendar.beginning_of_year(&ymd(2024, 1, 1)),
+            Some(ymd(2023, 12, 31))
+        );
+        assert_eq!(calendar.year(&ymd(2023, 12, 31)), 2024);
+        assert_eq!(
+            calendar.end_of_year(&ymd(2024, 6, 1)),
+            Some(ymd(2024, 12, 28))
+        );
+        assert_eq!(calendar.year(&ymd(2026, 1, 3)), 2025);
+        assert!(calendar.is_leap_year(2025));
+        assert!(!calendar.is_leap_year(2024));
+
+        let before = ThirteenPeriodCalendar::new(
+            7,
+            1,
+            Weekday::Mon,
+            Anchor::OnOrBefore,
+            LeapWeek::LastPeriod,
+        )
+        .unwrap();
+        assert_eq!(
+            before.beginning_of_year(&ymd(2024, 8, 1)),
+            Some(ymd(2024, 7, 1))
+        );
+        assert_eq!(before.next_year(&ymd(2024, 8, 1)), Some(ymd(2025, 6, 30)));
+
+        let after = ThirteenPeriodCalendar::new(
+            7,
+            1,
+            Weekday::Mon,
+            Anchor::OnOrAfter,
+            LeapWeek::LastPeriod,
+        )
+        .unwrap();
+        assert_eq!(after.next_year(&ymd(2024, 8, 1)), Some(ymd(2025, 7, 7)));
+        assert_eq!(
+            ThirteenPeriodCalendar::new(2, 29, Weekday::Sun, Anchor::Nearest, LeapWeek::LastPeriod),
+            None
+        );
+    }
+
+    #[test]
+    fn places_the_leap_week() {
+        let last = nearest_sunday(LeapWeek::LastPeriod);
+        let first = nearest_sunday(LeapWeek::FirstPeriod);
+
+        assert_eq!(
+            last.period_range(2025, 13),
+            DateRange::new(ymd(2025, 11, 30), ymd(2026, 1, 3))
+        );
+        assert_eq!(
+            last.period_range(2025, 1),
+            DateRange::new(ymd(2024, 12, 29), ymd(2025, 1, 25))
+        );
+        assert_eq!(
+            first.period_range(2025, 1),
+            DateRange::new(ymd(2024, 12, 29), ymd(2025, 2, 1))
+        );
+        assert_eq!(
+            first.period_range(2025, 13),
+            DateRange::new(ymd(2025, 12, 7), ymd(2026, 1, 3))
+        );
+        assert_eq!(last.period(&ymd(2026, 1, 3)), 13);
+        assert_eq!(first.period(&ymd(2025, 1, 30)), 1);
+        assert_eq!(last.period(&ymd(2025, 1, 30)), 2);
+        assert_eq!(last.period_range(2025, 14), None);
+    }
+
+    #[test]
+    fn stops_at_chrono_limits() {
+        let calendar = ThirteenPeriodCalendar::new(
+            12,
+            31,
+            Weekday::Sun,
+            Anchor::OnOrBefore,
+            LeapWeek::LastPeriod,
+        )
+        .unwrap();
+        let last_year = NaiveDate::MAX.year();
+
+        assert_eq!(calendar.period_range(last_year, 2), None);
+        assert_eq!(calendar.period_range(last_year, 13), None);
+        assert!(!calendar.is_leap_year(i32::MAX));
+        assert_eq!(calendar.period_range(i32::MAX, 1), None);
+        assert_eq!(calendar.week_range(last_year, 2), None);
+        assert_eq!(calendar.week_range(last_year, 1), None);
+    }
+
+    #[test]
+    fn numbers_weeks() {
+        // US retail years begin on the Sunday nearest February 1
+        let retail =
+            ThirteenPeriodCalendar::new(2, 1, Weekday::Sun, Anchor::Nearest, LeapWeek::LastPeriod)
+                .unwrap();
+
+        assert_eq!(retail.week(&ymd(2024, 2, 4)), 1);
+        assert_eq!(retail.week(&ymd(2024, 10, 19)), 37);
+        assert_eq!(
+            retail.week_range(2024, 37),
+            DateRange::new(ymd(2024, 10, 13), ymd(2024, 10, 19))
+        );
+        assert!(retail.is_leap_year(2023));
+        assert_eq!(retail.week(&ymd(2024, 2, 3)), 53);
+        assert_eq!(retail.week_range(2024, 53), None);
+        assert_eq!(retail.week_range(2024, 0), None);
+    }
+
+    #[test]
+    fn navigates_periods() {
+        let calendar = nearest_sunday(LeapWeek::LastPeriod);
+        let date = ymd(2024, 12, 20);
+
+        assert_eq!(calendar.period(&date), 13);
+        assert_eq!(calendar.next_period(&date), Some(ymd(2024, 12, 29)));
+        assert_eq!(calendar.previous_period(&date), Some(ymd(2024, 11, 3)));
+        assert_eq!(
+            calendar.previous_period(&ymd(2024, 1, 5)),
+            Some(ymd(2023, 12, 3))
+        );
+        assert_eq!(calendar.previous_year(&date), Some(ymd(2023, 1, 1)));
+    }
+
+    #[quickcheck]
+    fn periods_contain_their_dates(date: ArbitraryDate) -> bool {
+        [LeapWeek::FirstPeriod, LeapWeek::LastPeriod]
+            .iter()
+            .all(|&leap_week| {
+                let calendar = nearest_sunday(leap_week);
+                let range = calendar
+                    .period_range(calendar.year(&date.0), calendar.period(&date.0))
+                    .unwrap();
+
+                range.contains(&date.0) && range.start().weekday() == Weekday::Sun
+            })
+    }
+}
@@ -0,0 +1,243 @@
+//! Date ticks for chart axes, enabled with the `alloc` feature.
+//!
+//! [`nice_ticks`] picks the finest step, from single days through multiples of years, that fits
+//! the range in the requested number of ticks, and places the ticks on that step's boundaries:
+//! Sundays for weeks, the first of the month for months, and January 1 for years, with multiples
+//! aligned to their unit (quarters begin in January, April, July, and October).
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::ticks::{nice_ticks, TickStep};
+//! use date_calculations::DateRange;
+//!
+//! let range: DateRange = "2024-01-15/2024-12-31".parse().unwrap();
+//! let ticks = nice_ticks(&range, 5);
+//!
+//! assert_eq!(ticks.step, TickStep::Months(3));
+//! assert_eq!(
+//!     ticks.ticks.iter().map(|tick| tick.label.as_str()).collect::<Vec<_>>(),
+//!     ["Q2 2024", "Q3 2024", "Q4 2024"]
+//! );
+//! ```
+
+use crate::duration::shift_months;
+use crate::year_month::{DisplayYear, MONTH_NAMES};
+use crate::{DateRange, MonthOverflow};
+use alloc::{format, string::String, vec::Vec};
+use chrono::prelude::*;
+use core::convert::TryFrom;
+
+// The candidate steps finer than a year, from finest to coarsest
+static FINE_STEPS: [TickStep; 7] = [
+    TickStep::Days(1),
+    TickStep::Days(2),
+    TickStep::Weeks(1),
+    TickStep::Weeks(2),
+    TickStep::Months(1),
+    TickStep::Months(3),
+    TickStep::Months(6),
+];
+
+/// The distance between consecutive ticks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TickStep {
+    /// A number of days, counted from the beginning of the range.
+    Days(u32),
+    /// A number of weeks, beginning on Sunday.
+    Weeks(u32),
+    /// A number of months, 1, 3, or 6, beginning at multiples of the step from January.
+    Months(u32),
+    /// A number of years, beginning in years divisible by the step.
+    Years(u32),
+}
+
+/// A tick on a date axis.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Tick {
+    /// The date the tick marks.
+    pub date: NaiveDate,
+    /// A label for the tick suited to its step, like `Mar 5`, `Mar 2024`, `Q1 2024`, or `2024`.
+    pub label: String,
+}
+
+/// The ticks chosen for a range by [`nice_ticks`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ticks {
+    /// The step between the ticks.
+    pub step: TickStep,
+    /// The ticks within the range, in order.
+    pub ticks: Vec<Tick>,
+}
+
+/// Chooses ticks for the range, at most `max_ticks` of them, at the finest step that fits.
+///
+/// A `max_ticks` of zero is treated as one.
+pub fn nice_ticks(range: &DateRange, max_ticks: usize) -> Ticks {
+    let max_ticks = max_ticks.max(1);
+    let approximate_days = |step: TickStep| match step {
+        TickStep::Days(days) => f64::from(days),
+        TickStep::Weeks(weeks) => f64::from(weeks) * 7.0,
+        TickStep::Months(months) => f64::from(months) * 30.436875,
+        TickStep::Years(years) => f64::from(years) * 365.2425,
+    };
+
+    let mut steps = steps();
+    loop {
+        let step = steps.next().expect("steps continue past every range");
+        // skip generating ticks for steps that clearly produce too many
+        if range.num_days() as f64 / approximate_days(step) > (max_ticks + 1) as f64 {
+            continue;
+        }
+
+        let ticks: Vec<_> = tick_dates(range, step).take(max_ticks + 1).collect();
+        if ticks.len() <= max_ticks {
+            return Ticks {
+                step,
+                ticks: ticks
+                    .into_iter()
+                    .map(|date| Tick {
+                        label: label(&date, step),
+                        date,
+                    })
+                    .collect(),
+            };
+        }
+    }
+}
+
+// The candidate steps from finest to coarsest, with years growing by 1, 2, and 5 times powers
+// of ten beyond any span chrono can represent
+fn steps() -> impl Iterator<Item = TickStep> {
+    let years = (0..7).flat_map(|power| {
+        let scale = 10u32.pow(power);
+        [1, 2, 5].iter().map(move |n| TickStep::Years(n * scale))
+    });
+
+    FINE_STEPS.iter().copied().chain(years)
+}
+
+fn tick_dates(range: &DateRange, step: TickStep) -> impl Iterator<Item = NaiveDate> {
+    let start = range.start();
+    let end = range.end();
+    let (first, months, days) = match step {
+        TickStep::Days(days) => (Some(start), 0, days),
+        TickStep::Weeks(weeks) => {
+            let sunday = u64::from(start.weekday().num_days_from_sunday());
+            let first = match sunday {
+                0 => Some(start),
+                _ => start.checked_add_days(chrono::Days::new(7 - sunday)),
+            };
+            (first, 0, weeks * 7)
+        }
+        TickStep::Months(months) => {
+            // the first month beginning on or after the start, rounded up to the step
+            let index = i64::from(start.year()) * 12
+                + i64::from(start.month0())
+                + i64::from(start.day() > 1);
+            let index = round_up(index, i64::from(months));
+            let first = i32::try_from(index.div_euclid(12))
+                .ok()
+                .and_then(|year| NaiveDate::from_ymd_opt(year, index.rem_euclid(12) as u32 + 1, 1));
+            (first, months, 0)
+        }
+        TickStep::Years(years) => {
+            let year = i64::from(start.year()) + i64::from(start.ordinal() > 1);
+            let first = i32::try_from(round_up(year, i64::from(years)))
+                .ok()
+                .and_then(|year| NaiveDate::from_ymd_opt(year, 1, 1));
+            (first, years.saturating_mul(12), 0)
+        }
+    };
+
+    core::iter::successors(first, move |date| match months {
+        0 => date.checked_add_days(chrono::Days::new(u64::from(days))),
+        _ => shift_months(date, i64::from(months), MonthOverflow::Clamp),
+    })
+    .take_while(move |date| *date <= end)
+}
+
+fn round_up(value: i64, step: i64) -> i64 {
+    value + (step - value.rem_euclid(step)) % step
+}
+
+fn label(date: &NaiveDate, step: TickStep) -> String {
+    let month = &MONTH_NAMES[date.month0() as usize][..3];
+
+    match step {
+        TickStep::Days(_) | TickStep::Weeks(_) => format!("{} {}", month, date.day()),
+        TickStep::Months(3) => format!("Q{} {}", date.month0() / 3 + 1, DisplayYear(date.year())),
+        TickStep::Months(_) => format!("{} {}", month, DisplayYear(date.year())),
+        TickStep::Years(_) => format!("{}", DisplayYear(date.year())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn labels(start: NaiveDate, end: NaiveDate, max_ticks: usize) -> (TickStep, Vec<String>) {
+        let ticks = nice_ticks(&DateRange::new(start, end).unwrap(), max_ticks);
+
+        (
+            ticks.step,
+            ticks.ticks.into_iter().map(|tick| tick.label).collect(),
+        )
+    }
+
+    #[test]
+    fn steps_up_as_ranges_grow() {
+        assert_eq!(
+            labels(ymd(2024, 5, 1), ymd(2024, 5, 3), 10),
+            (
+                TickStep::Days(1),
+                vec!["May 1".into(), "May 2".into(), "May 3".into()]
+            )
+        );
+        assert_eq!(
+            labels(ymd(2024, 5, 1), ymd(2024, 5, 31), 5),
+            (
+                TickStep::Weeks(1),
+                vec![
+                    "May 5".into(),
+                    "May 12".into(),
+                    "May 19".into(),
+                    "May 26".into()
+                ]
+            )
+        );
+        assert_eq!(
+            labels(ymd(2023, 11, 2), ymd(2024, 2, 1), 4),
+            (
+                TickStep::Months(1),
+                vec!["Dec 2023".into(), "Jan 2024".into(), "Feb 2024".into()]
+            )
+        );
+        assert_eq!(
+            labels(ymd(1901, 6, 1), ymd(2024, 1, 1), 3),
+            (TickStep::Years(50), vec!["1950".into(), "2000".into()])
+        );
+    }
+
+    #[quickcheck]
+    fn ticks_fit_and_fall_within_the_range(start: ArbitraryDate, span: u16, max_ticks: u8) -> bool {
+        let end = match start.0.checked_add_days(chrono::Days::new(span.into())) {
+            Some(end) => end,
+            None => return true,
+        };
+        let range = DateRange::new(start.0, end).unwrap();
+        let ticks = nice_ticks(&range, max_ticks.into());
+
+        ticks.ticks.len() <= usize::from(max_ticks).max(1)
+            && ticks.ticks.iter().all(|tick| range.contains(&tick.date))
+            && ticks
+                .ticks
+                .windows(2)
+                .all(|pair| pair[0].date < pair[1].date)
+    }
+}
@@ -0,0 +1,111 @@
+//! Trimesters, the thirds of a calendar year: January–April, May–August, and
+//! September–December.
+//!
+//! The functions mirror the crate's quarter boundaries, for academic and fiscal systems that
+//! divide the year in three.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::trimester::*;
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+//!
+//! assert_eq!(trimester_of_year(&date), 2);
+//! assert_eq!(beginning_of_trimester(&date), NaiveDate::from_ymd_opt(2024, 5, 1).unwrap());
+//! assert_eq!(end_of_trimester(&date), NaiveDate::from_ymd_opt(2024, 8, 31).unwrap());
+//! assert_eq!(previous_trimester(&date), NaiveDate::from_ymd_opt(2024, 1, 1));
+//! ```
+
+use crate::{end_of_month, DateRange};
+use chrono::prelude::*;
+
+/// Returns the trimester (1 through 3) of the year containing the provided date.
+pub fn trimester_of_year(date: &NaiveDate) -> u32 {
+    1 + date.month0() / 4
+}
+
+/// Returns the first day of the current trimester and year.
+///
+/// This will either be January 1, May 1, or September 1 of the current year.
+pub fn beginning_of_trimester(date: &NaiveDate) -> NaiveDate {
+    first_day(date.year(), trimester_of_year(date)).expect("trimesters begin within the year")
+}
+
+/// Returns the last day of the current trimester and year.
+///
+/// This will either be April 30, August 31, or December 31 of the current year.
+pub fn end_of_trimester(date: &NaiveDate) -> NaiveDate {
+    let last_month = NaiveDate::from_ymd_opt(date.year(), trimester_of_year(date) * 4, 1)
+        .expect("trimesters end within the year");
+
+    end_of_month(&last_month)
+}
+
+/// Returns the first day of the next trimester.
+///
+/// If the current date falls in the last trimester of the year, this will shift to the first
+/// trimester of the next year. Returns `None` if the next trimester begins after
+/// `NaiveDate::MAX`.
+pub fn next_trimester(date: &NaiveDate) -> Option<NaiveDate> {
+    end_of_trimester(date).succ_opt()
+}
+
+/// Returns the first day of the previous trimester.
+///
+/// If the current date falls in the first trimester of the year, this will shift to the last
+/// trimester of the previous year. Returns `None` if the previous trimester begins before
+/// `NaiveDate::MIN`.
+pub fn previous_trimester(date: &NaiveDate) -> Option<NaiveDate> {
+    Some(beginning_of_trimester(
+        &beginning_of_trimester(date).pred_opt()?,
+    ))
+}
+
+/// Returns the range of the trimester (1 through 3) of the year.
+pub fn trimester(year: i32, number: u32) -> Option<DateRange> {
+    if !(1..=3).contains(&number) {
+        return None;
+    }
+
+    let start = first_day(year, number)?;
+
+    DateRange::new(start, end_of_trimester(&start))
+}
+
+fn first_day(year: i32, trimester: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, (trimester - 1) * 4 + 1, 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[quickcheck]
+    fn trimesters_contain_their_dates(date: ArbitraryDate) -> bool {
+        let date = date.0;
+        let range = trimester(date.year(), trimester_of_year(&date)).unwrap();
+
+        range.start() == beginning_of_trimester(&date)
+            && range.end() == end_of_trimester(&date)
+            && range.contains(&date)
+            && previous_trimester(&next_trimester(&date).unwrap())
+                == Some(beginning_of_trimester(&date))
+    }
+
+    #[test]
+    fn divides_years_in_three() {
+        assert_eq!(end_of_trimester(&ymd(2023, 1, 1)), ymd(2023, 4, 30));
+        assert_eq!(beginning_of_trimester(&ymd(2023, 12, 31)), ymd(2023, 9, 1));
+        assert_eq!(next_trimester(&ymd(2023, 10, 15)), Some(ymd(2024, 1, 1)));
+        assert_eq!(previous_trimester(&ymd(2024, 2, 29)), Some(ymd(2023, 9, 1)));
+        assert_eq!(trimester(2024, 4), None);
+        assert_eq!(previous_trimester(&NaiveDate::MIN), None);
+        assert_eq!(next_trimester(&NaiveDate::MAX), None);
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{end_of_month, DateRange, ParseError};
+use chrono::prelude::*;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+const EXPECTED: &str = "a month like \"2024-03\", \"Mar 2024\", or \"2024 March\"";
+
+pub(crate) const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// A calendar month of a specific year.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use date_calculations::YearMonth;
+///
+/// let month: YearMonth = "Jan 2024".parse().unwrap();
+///
+/// assert_eq!(month.to_string(), "2024-01");
+/// assert_eq!("2024 january".parse(), Ok(month));
+/// assert_eq!(month.last_day(), NaiveDate::from_ymd_opt(2024, 1, 31));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonth {
+    year: i32,
+    month: u32,
+}
+
+impl YearMonth {
+    /// Builds a month from a year and a month number between 1 and 12.
+    pub fn new(year: i32, month: u32) -> Option<Self> {
+        if (1..=12).contains(&month) {
+            Some(YearMonth { year, month })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the month containing the provided date.
+    pub fn from_date(date: &NaiveDate) -> Self {
+        YearMonth {
+            year: date.year(),
+            month: date.month(),
+        }
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month number, between 1 and 12.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the first day of the month.
+    pub fn first_day(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year, self.month, 1)
+    }
+
+    /// Returns the last day of the month.
+    pub fn last_day(&self) -> Option<NaiveDate> {
+        Some(end_of_month(&self.first_day()?))
+    }
+
+    /// Returns the range of dates within the month.
+    pub fn range(&self) -> Option<DateRange> {
+        DateRange::new(self.first_day()?, self.last_day()?)
+    }
+}
+
+/// Formats the month as `2024-03`.
+impl fmt::Display for YearMonth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{:02}", DisplayYear(self.year), self.month)
+    }
+}
+
+/// Parses months leniently, in the layouts spreadsheet exports commonly use.
+///
+/// The input holds a four-digit year and a month in either order, separated by spaces, dashes,
+/// slashes, periods, or commas: `2024-03`, `03/2024`, `Jan 2024`, `January, 2024`, `2024 Mar`.
+/// English month names are case-insensitive and may be abbreviated to any unambiguous prefix
+/// of at least three letters. A signed year of four or more digits, as `Display` writes years
+/// outside 0 through 9999, must come first: `-0001-03`, `+10000-03`.
+impl FromStr for YearMonth {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_with_names(input, EXPECTED, &MONTH_NAMES)
+    }
+}
+
+/// Parses a year and month using the provided month names, as described for [`YearMonth`]'s
+/// `FromStr` implementation; names are matched case- and accent-insensitively.
+pub(crate) fn parse_with_names(
+    input: &str,
+    expected: &'static str,
+    names: &[&str; 12],
+) -> Result<YearMonth, ParseError> {
+    let error = || ParseError::new(input, expected);
+    // a leading sign belongs to an expanded year, as `Display` writes for years beyond 0-9999
+    let input = input.trim();
+    let (negative, unsigned) = match input.strip_prefix(&['+', '-'][..]) {
+        Some(rest) => (input.starts_with('-'), Some(rest)),
+        None => (false, None),
+    };
+    let mut parts = unsigned
+        .unwrap_or(input)
+        .split(|c: char| c.is_whitespace() || ['-', '/', '.', ','].contains(&c))
+        .filter(|part| !part.is_empty());
+
+    let (year, month) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(first), Some(second), None) if unsigned.is_some() && is_expanded_year(first) => {
+            (first, second)
+        }
+        (Some(first), Some(second), None) if unsigned.is_none() && is_year(first) => {
+            (first, second)
+        }
+        (Some(first), Some(second), None) if unsigned.is_none() && is_year(second) => {
+            (second, first)
+        }
+        _ => return Err(error()),
+    };
+
+    let month = if month.chars().all(|c| c.is_ascii_digit()) && month.len() <= 2 {
+        month.parse().map_err(|_| error())?
+    } else {
+        month_from_name(month, names).ok_or_else(error)?
+    };
+
+    let year: i64 = year.parse().map_err(|_| error())?;
+    let year = i32::try_from(if negative { -year } else { year }).map_err(|_| error())?;
+
+    YearMonth::new(year, month).ok_or_else(error)
+}
+
+fn is_year(value: &str) -> bool {
+    value.len() == 4 && value.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_expanded_year(value: &str) -> bool {
+    value.len() >= 4 && value.chars().all(|c| c.is_ascii_digit())
+}
+
+fn month_from_name(value: &str, names: &[&str; 12]) -> Option<u32> {
+    if folded(value).count() < 3 {
+        return None;
+    }
+
+    let mut matches = names.iter().enumerate().filter(|(_, name)| {
+        let mut name = folded(name);
+        folded(value).all(|c| name.next() == Some(c))
+    });
+
+    match (matches.next(), matches.next()) {
+        (Some((index, _)), None) => Some(index as u32 + 1),
+        _ => None,
+    }
+}
+
+// Lowercases the name and strips accents, for matching names case- and accent-insensitively
+fn folded(name: &str) -> impl Iterator<Item = char> + '_ {
+    name.chars().flat_map(char::to_lowercase).map(fold_accent)
+}
+
+pub(crate) fn fold_accent(c: char) -> char {
+    match c {
+        'á' | 'à' | 'â' | 'ä' => 'a',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'í' | 'î' | 'ï' => 'i',
+        'ó' | 'ô' | 'ö' => 'o',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        'ñ' => 'n',
+        'ç' => 'c',
+        '’' => '\'',
+        other => other,
+    }
+}
+
+// Formats a year as chrono does: four digits from 0 through 9999, and otherwise signed and
+// padded, so 2 BCE is `-0001` and the year after 9999 is `+10000`
+pub(crate) struct DisplayYear(pub(crate) i32);
+
+impl fmt::Display for DisplayYear {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if (0..=9999).contains(&self.0) {
+            write!(f, "{:04}", self.0)
+        } else {
+            write!(f, "{:+05}", self.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_spreadsheet_layouts() {
+        let expected = YearMonth::new(2024, 3);
+
+        for input in &[
+            "2024-03",
+            "2024-3",
+            "03/2024",
+            "Mar 2024",
+            "MARCH 2024",
+            "March, 2024",
+            "2024 Mar",
+            "mar-2024",
+            "Mar. 2024",
+        ] {
+            assert_eq!(input.parse().ok(), expected, "{}", input);
+        }
+
+        assert_eq!("Sept 2024".parse().ok(), YearMonth::new(2024, 9));
+    }
+
+    #[test]
+    fn rejects_invalid_months() {
+        for input in &[
+            "2024-13",
+            "Ju 2024",
+            "Jun",
+            "2024",
+            "Jan 24",
+            "Marc 2024 1",
+            "Smarch 2024",
+            "",
+            "-03-2024",
+            "+24-03",
+            "-2147483649-01",
+        ] {
+            assert!(input.parse::<YearMonth>().is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn displays_round_trip() {
+        let month = YearMonth::new(1999, 7).unwrap();
+
+        assert_eq!(month.to_string(), "1999-07");
+        assert_eq!(month.to_string().parse(), Ok(month));
+    }
+
+    #[test]
+    fn displays_expanded_years_round_trip() {
+        for year in &[-1, 0, 10000, -10000, 123456, i32::MIN, i32::MAX] {
+            let month = YearMonth::new(*year, 3).unwrap();
+
+            assert_eq!(month.to_string().parse(), Ok(month), "{}", month);
+        }
+
+        assert_eq!("-2024-03".parse(), Ok(YearMonth::new(-2024, 3).unwrap()));
+        assert_eq!("-0001-03".parse::<YearMonth>().unwrap().year(), -1);
+    }
+
+    #[test]
+    fn builds_from_dates() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+        let month = YearMonth::from_date(&date);
+
+        assert_eq!(month, YearMonth::new(2024, 2).unwrap());
+        assert_eq!(month.first_day(), NaiveDate::from_ymd_opt(2024, 2, 1));
+        assert_eq!(month.last_day(), NaiveDate::from_ymd_opt(2024, 2, 29));
+    }
+}
@@ -0,0 +1,142 @@
+//! The comparable dates and ranges one year earlier, for year-over-year comparisons.
+//!
+//! Comparing by calendar date keeps month ends and holidays like July 4 aligned but lets the
+//! weekday drift, so a Saturday is compared with a Friday. Comparing by ISO week keeps the
+//! weekday and the number of each weekday in a range, which is what retail comparisons want,
+//! at the cost of the dates drifting by up to a week.
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use date_calculations::year_over_year::{same_date_last_year, YearAlignment};
+//!
+//! let saturday = NaiveDate::from_ymd_opt(2024, 11, 30).unwrap();
+//!
+//! assert_eq!(
+//!     same_date_last_year(&saturday, YearAlignment::Calendar),
+//!     NaiveDate::from_ymd_opt(2023, 11, 30)
+//! );
+//! assert_eq!(
+//!     same_date_last_year(&saturday, YearAlignment::IsoWeek),
+//!     NaiveDate::from_ymd_opt(2023, 12, 2)
+//! );
+//! ```
+
+use crate::duration::shift_months;
+use crate::{DateRange, IsoYearWeek, MonthOverflow};
+use chrono::prelude::*;
+
+/// How to find the date one year earlier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum YearAlignment {
+    /// The same month and day of the previous year, with February 29 mapping to February 28.
+    Calendar,
+    /// The same weekday of the same ISO 8601 week of the previous ISO week-numbering year, with
+    /// week 53 mapping to week 52 when the previous year has no week 53.
+    IsoWeek,
+}
+
+/// Returns the date comparable to `date` one year earlier.
+///
+/// Returns `None` if the comparable date is before `NaiveDate::MIN`.
+pub fn same_date_last_year(date: &NaiveDate, alignment: YearAlignment) -> Option<NaiveDate> {
+    match alignment {
+        YearAlignment::Calendar => shift_months(date, -12, MonthOverflow::Clamp),
+        YearAlignment::IsoWeek => {
+            let week = IsoYearWeek::from_date(date);
+            let year = week.year().checked_sub(1)?;
+            let previous = IsoYearWeek::new(year, week.week())
+                .or_else(|| IsoYearWeek::new(year, week.week() - 1))?;
+
+            previous.day(date.weekday())
+        }
+    }
+}
+
+/// Returns the range comparable to `range` one year earlier.
+///
+/// Calendar alignment maps each end to its comparable date, so month ends stay month ends. ISO
+/// week alignment shifts the whole range by the offset of its start, keeping its length and
+/// weekdays even when it crosses into week 53.
+///
+/// Returns `None` if the comparable range begins before `NaiveDate::MIN`.
+pub fn same_period_last_year(range: &DateRange, alignment: YearAlignment) -> Option<DateRange> {
+    let start = same_date_last_year(&range.start(), alignment)?;
+    let end = match alignment {
+        YearAlignment::Calendar => same_date_last_year(&range.end(), alignment)?,
+        YearAlignment::IsoWeek => range
+            .end()
+            .checked_sub_signed(range.start().signed_duration_since(start))?,
+    };
+
+    DateRange::new(start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ArbitraryDate;
+    use quickcheck_macros::quickcheck;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[quickcheck]
+    fn iso_week_alignment_keeps_the_weekday(date: ArbitraryDate) -> bool {
+        match same_date_last_year(&date.0, YearAlignment::IsoWeek) {
+            Some(previous) => {
+                let days = date.0.signed_duration_since(previous).num_days();
+
+                previous.weekday() == date.0.weekday() && (days == 364 || days == 371)
+            }
+            None => date.0.year() - 1 <= NaiveDate::MIN.year(),
+        }
+    }
+
+    #[test]
+    fn maps_leap_days_and_long_weeks() {
+        assert_eq!(
+            same_date_last_year(&ymd(2024, 2, 29), YearAlignment::Calendar),
+            Some(ymd(2023, 2, 28))
+        );
+        // 2020-W53 has no counterpart in 2019, so it maps to 2019-W52
+        assert_eq!(
+            same_date_last_year(&ymd(2020, 12, 31), YearAlignment::IsoWeek),
+            Some(ymd(2019, 12, 26))
+        );
+        // and 2021-W01 follows 2020-W53, so it reaches back 53 weeks
+        assert_eq!(
+            same_date_last_year(&ymd(2021, 1, 7), YearAlignment::IsoWeek),
+            Some(ymd(2020, 1, 2))
+        );
+    }
+
+    #[test]
+    fn shifts_iso_week_ranges_through_week_53() {
+        let end_of_2020 = DateRange::new(ymd(2020, 12, 27), ymd(2020, 12, 31)).unwrap();
+        let december = DateRange::new(ymd(2020, 12, 1), ymd(2020, 12, 31)).unwrap();
+
+        assert_eq!(
+            same_period_last_year(&end_of_2020, YearAlignment::IsoWeek),
+            DateRange::new(ymd(2019, 12, 29), ymd(2020, 1, 2))
+        );
+        assert_eq!(
+            same_period_last_year(&december, YearAlignment::IsoWeek),
+            DateRange::new(ymd(2019, 12, 3), ymd(2020, 1, 2))
+        );
+    }
+
+    #[test]
+    fn maps_ranges_by_their_ends() {
+        let december = DateRange::new(ymd(2024, 12, 1), ymd(2024, 12, 31)).unwrap();
+
+        assert_eq!(
+            same_period_last_year(&december, YearAlignment::Calendar),
+            DateRange::new(ymd(2023, 12, 1), ymd(2023, 12, 31))
+        );
+        assert_eq!(
+            same_period_last_year(&december, YearAlignment::IsoWeek),
+            DateRange::new(ymd(2023, 12, 3), ymd(2024, 1, 2))
+        );
+    }
+}